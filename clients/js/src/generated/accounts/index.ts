@@ -6,4 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./concentratedPool";
+export * from "./globalState";
 export * from "./liquidityPool";
+export * from "./multiAssetPool";
+export * from "./poolStats";
+export * from "./position";
+export * from "./tickArray";
@@ -17,12 +17,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -41,44 +47,348 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 
-export const LIQUIDITY_POOL_DISCRIMINATOR = new Uint8Array([1]);
+export const LIQUIDITY_POOL_DISCRIMINATOR = new Uint8Array([
+  1, 0, 0, 0, 0, 0, 0, 0,
+]);
 
 export function getLiquidityPoolDiscriminatorBytes() {
-  return fixEncoderSize(getBytesEncoder(), 1).encode(
+  return fixEncoderSize(getBytesEncoder(), 8).encode(
     LIQUIDITY_POOL_DISCRIMINATOR,
   );
 }
 
+/**
+ * A two-token pool, loaded zero-copy through `AccountLoader`.
+ *
+ * The discriminator is padded to 8 bytes so the fields stay aligned, and the
+ * fields are grouped by size so the layout has no implicit padding.
+ */
 export type LiquidityPool = {
   discriminator: ReadonlyUint8Array;
+  /** `SwapCurve::invariant` after the last liquidity event */
+  kLast: bigint;
+  /** Raw token B units per raw token A unit (WAD) as of the last PMM oracle refresh */
+  pmmOraclePrice: bigint;
   tokenAMint: Address;
   tokenBMint: Address;
+  /** Allowed to ramp the amplification coefficient and set the price band */
+  admin: Address;
+  /**
+   * Pyth-style price account quoting token A in token B: the pricing oracle of PMM pools
+   * or the reference oracle guarding swaps on other pools; default for pools without one
+   */
+  oracle: Address;
+  /**
+   * Receives `creator_fee_bps` of swap fees and is the only depositor of liquidity
+   * bootstrapping pools; can hand the role to another key. Default for PMM pools,
+   * which earn no creator fees
+   */
+  creator: Address;
+  /** Paid the rent of the pool's accounts, refunded by `close_pool` */
+  payer: Address;
+  /** LP token mint, the PDA `["lp_token_mint", token_a_mint, token_b_mint]` */
+  lpMint: Address;
+  /** Pool-owned token account holding the token A reserves */
+  tokenAVault: Address;
+  /** Pool-owned token account holding the token B reserves */
+  tokenBVault: Address;
+  /** Protocol fee LP token account owned by the pool PDA */
+  feeLpTokenAccount: Address;
   tokenAReserves: bigint;
   tokenBReserves: bigint;
-  kLast: bigint;
+  /** StableSwap amplification at `amp_ramp_start_ts`; zero for other pools */
+  ampInitial: bigint;
+  /** StableSwap amplification reached at `amp_ramp_stop_ts` */
+  ampTarget: bigint;
+  ampRampStartTs: bigint;
+  ampRampStopTs: bigint;
+  /** Scales token A amounts up to the larger of the two mint precisions */
+  tokenAPrecisionMultiplier: bigint;
+  /** Scales token B amounts up to the larger of the two mint precisions */
+  tokenBPrecisionMultiplier: bigint;
+  /** Largest accepted age of an oracle price in seconds */
+  oracleMaxAge: bigint;
+  /** PMM liquidity parameter (WAD); zero for other pools */
+  pmmK: bigint;
+  /** PMM equilibrium reserves of token A */
+  tokenATarget: bigint;
+  /** PMM equilibrium reserves of token B */
+  tokenBTarget: bigint;
+  /** Seconds it takes the volatility accumulator to halve */
+  volatilityHalfLife: bigint;
+  /** Recent spot price moves in basis points, summed per swap and decayed over time */
+  volatilityAccumulator: bigint;
+  volatilityUpdatedAt: bigint;
+  /** Swaps fail before this unix timestamp */
+  tradingStartsAt: bigint;
+  launchFeeDuration: bigint;
+  /** Largest output of a single swap while the launch fee decays; zero for no limit */
+  launchMaxBuy: bigint;
+  /** Liquidity bootstrapping weights start moving at this unix timestamp */
+  lbpStartTs: bigint;
+  /** Liquidity bootstrapping weights stop moving at this unix timestamp */
+  lbpEndTs: bigint;
+  /** Creator fee LP tokens held in the fee account and not yet claimed */
+  creatorFeeLpTokens: bigint;
+  /**
+   * Weighted pool share of token A in basis points; zero for other pools.
+   * Liquidity bootstrapping pools keep their starting weights here.
+   */
+  weightA: number;
+  /** Weighted pool share of token B in basis points; zero for other pools */
+  weightB: number;
+  /** Constant-sum pool band around an even split of the reserves, in basis points; zero disables it */
+  priceBandBps: number;
+  /** Largest accepted oracle confidence interval as a share of the price, in basis points */
+  oracleMaxConfidenceBps: number;
+  /**
+   * Largest accepted gap between the post-swap spot price and the reference oracle,
+   * in basis points; zero disables the guard
+   */
+  maxOracleDeviationBps: number;
+  /**
+   * Share of the volatility accumulator charged on top of `SWAP_FEE_BPS`, in basis points;
+   * zero disables dynamic fees
+   */
+  volatilityFeeFactorBps: number;
+  /** Cap on the dynamic swap fee in basis points */
+  maxSwapFeeBps: number;
+  /**
+   * Swap fee at `trading_starts_at`, decaying linearly to the regular fee over
+   * `launch_fee_duration`; zero for no launch fee
+   */
+  launchFeeBps: number;
+  /** Liquidity bootstrapping pool weight of token A at `lbp_end_ts` */
+  lbpEndWeightA: number;
+  /** Creator's share of swap fees in basis points, minted alongside the protocol fee */
+  creatorFeeBps: number;
+  /** Share of the LP tokens minted by a deposit that the depositor doesn't receive */
+  depositFeeBps: number;
   bump: number;
+  /** Bump of `lp_mint`, so instructions check and sign for it without searching */
+  lpMintBump: number;
+  /** `CurveType` discriminant */
+  curveType: number;
+  /**
+   * Non-zero if withheld deposit fees are minted to the fee account rather than left
+   * unminted, which spreads them over existing LPs
+   */
+  depositFeeToProtocol: number;
+  /**
+   * `PAUSE_*` flags other than `PAUSE_WITHDRAW` set by the admin, applied on top of
+   * `GlobalState::paused`
+   */
+  paused: number;
+  /** Layout version, `LIQUIDITY_POOL_VERSION` once written by this program */
+  version: number;
+  padding: Array<number>;
+  /** Non-zero while an instruction that moves tokens is changing the pool, see `is_locked` */
+  locked: number;
+  padding1: Array<number>;
+  /** Largest `slot_price_move_bps` the swaps of a slot may reach; zero disables the limit */
+  maxSlotPriceMoveBps: number;
+  padding2: Array<number>;
+  /** Spot price moves in basis points summed over the swaps of `price_move_slot` */
+  slotPriceMoveBps: bigint;
+  priceMoveSlot: bigint;
+  /** Zeroed space for future fields, so they don't need a `migrate_pool` realloc */
+  reserved: Array<number>;
 };
 
 export type LiquidityPoolArgs = {
+  /** `SwapCurve::invariant` after the last liquidity event */
+  kLast: number | bigint;
+  /** Raw token B units per raw token A unit (WAD) as of the last PMM oracle refresh */
+  pmmOraclePrice: number | bigint;
   tokenAMint: Address;
   tokenBMint: Address;
+  /** Allowed to ramp the amplification coefficient and set the price band */
+  admin: Address;
+  /**
+   * Pyth-style price account quoting token A in token B: the pricing oracle of PMM pools
+   * or the reference oracle guarding swaps on other pools; default for pools without one
+   */
+  oracle: Address;
+  /**
+   * Receives `creator_fee_bps` of swap fees and is the only depositor of liquidity
+   * bootstrapping pools; can hand the role to another key. Default for PMM pools,
+   * which earn no creator fees
+   */
+  creator: Address;
+  /** Paid the rent of the pool's accounts, refunded by `close_pool` */
+  payer: Address;
+  /** LP token mint, the PDA `["lp_token_mint", token_a_mint, token_b_mint]` */
+  lpMint: Address;
+  /** Pool-owned token account holding the token A reserves */
+  tokenAVault: Address;
+  /** Pool-owned token account holding the token B reserves */
+  tokenBVault: Address;
+  /** Protocol fee LP token account owned by the pool PDA */
+  feeLpTokenAccount: Address;
   tokenAReserves: number | bigint;
   tokenBReserves: number | bigint;
-  kLast: number | bigint;
+  /** StableSwap amplification at `amp_ramp_start_ts`; zero for other pools */
+  ampInitial: number | bigint;
+  /** StableSwap amplification reached at `amp_ramp_stop_ts` */
+  ampTarget: number | bigint;
+  ampRampStartTs: number | bigint;
+  ampRampStopTs: number | bigint;
+  /** Scales token A amounts up to the larger of the two mint precisions */
+  tokenAPrecisionMultiplier: number | bigint;
+  /** Scales token B amounts up to the larger of the two mint precisions */
+  tokenBPrecisionMultiplier: number | bigint;
+  /** Largest accepted age of an oracle price in seconds */
+  oracleMaxAge: number | bigint;
+  /** PMM liquidity parameter (WAD); zero for other pools */
+  pmmK: number | bigint;
+  /** PMM equilibrium reserves of token A */
+  tokenATarget: number | bigint;
+  /** PMM equilibrium reserves of token B */
+  tokenBTarget: number | bigint;
+  /** Seconds it takes the volatility accumulator to halve */
+  volatilityHalfLife: number | bigint;
+  /** Recent spot price moves in basis points, summed per swap and decayed over time */
+  volatilityAccumulator: number | bigint;
+  volatilityUpdatedAt: number | bigint;
+  /** Swaps fail before this unix timestamp */
+  tradingStartsAt: number | bigint;
+  launchFeeDuration: number | bigint;
+  /** Largest output of a single swap while the launch fee decays; zero for no limit */
+  launchMaxBuy: number | bigint;
+  /** Liquidity bootstrapping weights start moving at this unix timestamp */
+  lbpStartTs: number | bigint;
+  /** Liquidity bootstrapping weights stop moving at this unix timestamp */
+  lbpEndTs: number | bigint;
+  /** Creator fee LP tokens held in the fee account and not yet claimed */
+  creatorFeeLpTokens: number | bigint;
+  /**
+   * Weighted pool share of token A in basis points; zero for other pools.
+   * Liquidity bootstrapping pools keep their starting weights here.
+   */
+  weightA: number;
+  /** Weighted pool share of token B in basis points; zero for other pools */
+  weightB: number;
+  /** Constant-sum pool band around an even split of the reserves, in basis points; zero disables it */
+  priceBandBps: number;
+  /** Largest accepted oracle confidence interval as a share of the price, in basis points */
+  oracleMaxConfidenceBps: number;
+  /**
+   * Largest accepted gap between the post-swap spot price and the reference oracle,
+   * in basis points; zero disables the guard
+   */
+  maxOracleDeviationBps: number;
+  /**
+   * Share of the volatility accumulator charged on top of `SWAP_FEE_BPS`, in basis points;
+   * zero disables dynamic fees
+   */
+  volatilityFeeFactorBps: number;
+  /** Cap on the dynamic swap fee in basis points */
+  maxSwapFeeBps: number;
+  /**
+   * Swap fee at `trading_starts_at`, decaying linearly to the regular fee over
+   * `launch_fee_duration`; zero for no launch fee
+   */
+  launchFeeBps: number;
+  /** Liquidity bootstrapping pool weight of token A at `lbp_end_ts` */
+  lbpEndWeightA: number;
+  /** Creator's share of swap fees in basis points, minted alongside the protocol fee */
+  creatorFeeBps: number;
+  /** Share of the LP tokens minted by a deposit that the depositor doesn't receive */
+  depositFeeBps: number;
   bump: number;
+  /** Bump of `lp_mint`, so instructions check and sign for it without searching */
+  lpMintBump: number;
+  /** `CurveType` discriminant */
+  curveType: number;
+  /**
+   * Non-zero if withheld deposit fees are minted to the fee account rather than left
+   * unminted, which spreads them over existing LPs
+   */
+  depositFeeToProtocol: number;
+  /**
+   * `PAUSE_*` flags other than `PAUSE_WITHDRAW` set by the admin, applied on top of
+   * `GlobalState::paused`
+   */
+  paused: number;
+  /** Layout version, `LIQUIDITY_POOL_VERSION` once written by this program */
+  version: number;
+  padding: Array<number>;
+  /** Non-zero while an instruction that moves tokens is changing the pool, see `is_locked` */
+  locked: number;
+  padding1: Array<number>;
+  /** Largest `slot_price_move_bps` the swaps of a slot may reach; zero disables the limit */
+  maxSlotPriceMoveBps: number;
+  padding2: Array<number>;
+  /** Spot price moves in basis points summed over the swaps of `price_move_slot` */
+  slotPriceMoveBps: number | bigint;
+  priceMoveSlot: number | bigint;
+  /** Zeroed space for future fields, so they don't need a `migrate_pool` realloc */
+  reserved: Array<number>;
 };
 
 /** Gets the encoder for {@link LiquidityPoolArgs} account data. */
 export function getLiquidityPoolEncoder(): FixedSizeEncoder<LiquidityPoolArgs> {
   return transformEncoder(
     getStructEncoder([
-      ["discriminator", fixEncoderSize(getBytesEncoder(), 1)],
+      ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+      ["kLast", getU128Encoder()],
+      ["pmmOraclePrice", getU128Encoder()],
       ["tokenAMint", getAddressEncoder()],
       ["tokenBMint", getAddressEncoder()],
+      ["admin", getAddressEncoder()],
+      ["oracle", getAddressEncoder()],
+      ["creator", getAddressEncoder()],
+      ["payer", getAddressEncoder()],
+      ["lpMint", getAddressEncoder()],
+      ["tokenAVault", getAddressEncoder()],
+      ["tokenBVault", getAddressEncoder()],
+      ["feeLpTokenAccount", getAddressEncoder()],
       ["tokenAReserves", getU64Encoder()],
       ["tokenBReserves", getU64Encoder()],
-      ["kLast", getU128Encoder()],
+      ["ampInitial", getU64Encoder()],
+      ["ampTarget", getU64Encoder()],
+      ["ampRampStartTs", getI64Encoder()],
+      ["ampRampStopTs", getI64Encoder()],
+      ["tokenAPrecisionMultiplier", getU64Encoder()],
+      ["tokenBPrecisionMultiplier", getU64Encoder()],
+      ["oracleMaxAge", getU64Encoder()],
+      ["pmmK", getU64Encoder()],
+      ["tokenATarget", getU64Encoder()],
+      ["tokenBTarget", getU64Encoder()],
+      ["volatilityHalfLife", getI64Encoder()],
+      ["volatilityAccumulator", getU64Encoder()],
+      ["volatilityUpdatedAt", getI64Encoder()],
+      ["tradingStartsAt", getI64Encoder()],
+      ["launchFeeDuration", getI64Encoder()],
+      ["launchMaxBuy", getU64Encoder()],
+      ["lbpStartTs", getI64Encoder()],
+      ["lbpEndTs", getI64Encoder()],
+      ["creatorFeeLpTokens", getU64Encoder()],
+      ["weightA", getU16Encoder()],
+      ["weightB", getU16Encoder()],
+      ["priceBandBps", getU16Encoder()],
+      ["oracleMaxConfidenceBps", getU16Encoder()],
+      ["maxOracleDeviationBps", getU16Encoder()],
+      ["volatilityFeeFactorBps", getU16Encoder()],
+      ["maxSwapFeeBps", getU16Encoder()],
+      ["launchFeeBps", getU16Encoder()],
+      ["lbpEndWeightA", getU16Encoder()],
+      ["creatorFeeBps", getU16Encoder()],
+      ["depositFeeBps", getU16Encoder()],
       ["bump", getU8Encoder()],
+      ["lpMintBump", getU8Encoder()],
+      ["curveType", getU8Encoder()],
+      ["depositFeeToProtocol", getU8Encoder()],
+      ["paused", getU8Encoder()],
+      ["version", getU8Encoder()],
+      ["padding", getArrayEncoder(getU8Encoder(), { size: 12 })],
+      ["locked", getU8Encoder()],
+      ["padding1", getArrayEncoder(getU8Encoder(), { size: 1 })],
+      ["maxSlotPriceMoveBps", getU16Encoder()],
+      ["padding2", getArrayEncoder(getU8Encoder(), { size: 4 })],
+      ["slotPriceMoveBps", getU64Encoder()],
+      ["priceMoveSlot", getU64Encoder()],
+      ["reserved", getArrayEncoder(getU8Encoder(), { size: 40 })],
     ]),
     (value) => ({ ...value, discriminator: LIQUIDITY_POOL_DISCRIMINATOR }),
   );
@@ -87,13 +397,65 @@ export function getLiquidityPoolEncoder(): FixedSizeEncoder<LiquidityPoolArgs> {
 /** Gets the decoder for {@link LiquidityPool} account data. */
 export function getLiquidityPoolDecoder(): FixedSizeDecoder<LiquidityPool> {
   return getStructDecoder([
-    ["discriminator", fixDecoderSize(getBytesDecoder(), 1)],
+    ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
+    ["kLast", getU128Decoder()],
+    ["pmmOraclePrice", getU128Decoder()],
     ["tokenAMint", getAddressDecoder()],
     ["tokenBMint", getAddressDecoder()],
+    ["admin", getAddressDecoder()],
+    ["oracle", getAddressDecoder()],
+    ["creator", getAddressDecoder()],
+    ["payer", getAddressDecoder()],
+    ["lpMint", getAddressDecoder()],
+    ["tokenAVault", getAddressDecoder()],
+    ["tokenBVault", getAddressDecoder()],
+    ["feeLpTokenAccount", getAddressDecoder()],
     ["tokenAReserves", getU64Decoder()],
     ["tokenBReserves", getU64Decoder()],
-    ["kLast", getU128Decoder()],
+    ["ampInitial", getU64Decoder()],
+    ["ampTarget", getU64Decoder()],
+    ["ampRampStartTs", getI64Decoder()],
+    ["ampRampStopTs", getI64Decoder()],
+    ["tokenAPrecisionMultiplier", getU64Decoder()],
+    ["tokenBPrecisionMultiplier", getU64Decoder()],
+    ["oracleMaxAge", getU64Decoder()],
+    ["pmmK", getU64Decoder()],
+    ["tokenATarget", getU64Decoder()],
+    ["tokenBTarget", getU64Decoder()],
+    ["volatilityHalfLife", getI64Decoder()],
+    ["volatilityAccumulator", getU64Decoder()],
+    ["volatilityUpdatedAt", getI64Decoder()],
+    ["tradingStartsAt", getI64Decoder()],
+    ["launchFeeDuration", getI64Decoder()],
+    ["launchMaxBuy", getU64Decoder()],
+    ["lbpStartTs", getI64Decoder()],
+    ["lbpEndTs", getI64Decoder()],
+    ["creatorFeeLpTokens", getU64Decoder()],
+    ["weightA", getU16Decoder()],
+    ["weightB", getU16Decoder()],
+    ["priceBandBps", getU16Decoder()],
+    ["oracleMaxConfidenceBps", getU16Decoder()],
+    ["maxOracleDeviationBps", getU16Decoder()],
+    ["volatilityFeeFactorBps", getU16Decoder()],
+    ["maxSwapFeeBps", getU16Decoder()],
+    ["launchFeeBps", getU16Decoder()],
+    ["lbpEndWeightA", getU16Decoder()],
+    ["creatorFeeBps", getU16Decoder()],
+    ["depositFeeBps", getU16Decoder()],
     ["bump", getU8Decoder()],
+    ["lpMintBump", getU8Decoder()],
+    ["curveType", getU8Decoder()],
+    ["depositFeeToProtocol", getU8Decoder()],
+    ["paused", getU8Decoder()],
+    ["version", getU8Decoder()],
+    ["padding", getArrayDecoder(getU8Decoder(), { size: 12 })],
+    ["locked", getU8Decoder()],
+    ["padding1", getArrayDecoder(getU8Decoder(), { size: 1 })],
+    ["maxSlotPriceMoveBps", getU16Decoder()],
+    ["padding2", getArrayDecoder(getU8Decoder(), { size: 4 })],
+    ["slotPriceMoveBps", getU64Decoder()],
+    ["priceMoveSlot", getU64Decoder()],
+    ["reserved", getArrayDecoder(getU8Decoder(), { size: 40 })],
   ]);
 }
 
@@ -163,5 +525,5 @@ export async function fetchAllMaybeLiquidityPool(
 }
 
 export function getLiquidityPoolSize(): number {
-  return 98;
+  return 632;
 }
@@ -32,16 +32,154 @@ export const ANCHOR_AMM_ERROR__MINIMUM_LIQUIDITY_LOCKED = 0x1776; // 6006
 export const ANCHOR_AMM_ERROR__SLIPPAGE_EXCEEDED = 0x1777; // 6007
 /** NoExcessTokens: No excess tokens in vault to skim */
 export const ANCHOR_AMM_ERROR__NO_EXCESS_TOKENS = 0x1778; // 6008
+/** InvalidTickSpacing: Tick spacing must be between 1 and 16384 */
+export const ANCHOR_AMM_ERROR__INVALID_TICK_SPACING = 0x1779; // 6009
+/** InvalidFeeRate: Fee rate must be below 10000 bps */
+export const ANCHOR_AMM_ERROR__INVALID_FEE_RATE = 0x177a; // 6010
+/** InvalidTickIndex: Tick index is out of bounds or not a multiple of the tick spacing */
+export const ANCHOR_AMM_ERROR__INVALID_TICK_INDEX = 0x177b; // 6011
+/** InvalidTickRange: Lower tick must be below upper tick */
+export const ANCHOR_AMM_ERROR__INVALID_TICK_RANGE = 0x177c; // 6012
+/** InvalidTickArray: Tick array does not belong to this pool or does not contain the tick */
+export const ANCHOR_AMM_ERROR__INVALID_TICK_ARRAY = 0x177d; // 6013
+/** SqrtPriceOutOfBounds: Sqrt price is out of bounds */
+export const ANCHOR_AMM_ERROR__SQRT_PRICE_OUT_OF_BOUNDS = 0x177e; // 6014
+/** InvalidAmp: Amplification coefficient must be between 1 and MAX_AMP */
+export const ANCHOR_AMM_ERROR__INVALID_AMP = 0x177f; // 6015
+/** InvalidAmpRamp: Amplification ramp is too short, too steep or started too recently */
+export const ANCHOR_AMM_ERROR__INVALID_AMP_RAMP = 0x1780; // 6016
+/** NotStablePool: Operation is only supported on StableSwap pools */
+export const ANCHOR_AMM_ERROR__NOT_STABLE_POOL = 0x1781; // 6017
+/** InvariantDidNotConverge: StableSwap invariant did not converge */
+export const ANCHOR_AMM_ERROR__INVARIANT_DID_NOT_CONVERGE = 0x1782; // 6018
+/** InvalidWeights: Weights must each be at least MIN_WEIGHT_BPS and sum to WEIGHT_DENOMINATOR */
+export const ANCHOR_AMM_ERROR__INVALID_WEIGHTS = 0x1783; // 6019
+/** MaxInRatioExceeded: Swap input exceeds the maximum share of the input reserve */
+export const ANCHOR_AMM_ERROR__MAX_IN_RATIO_EXCEEDED = 0x1784; // 6020
+/** MaxOutRatioExceeded: Swap output exceeds the maximum share of the output reserve */
+export const ANCHOR_AMM_ERROR__MAX_OUT_RATIO_EXCEEDED = 0x1785; // 6021
+/** InvalidLbpSchedule: Liquidity bootstrapping schedule must end after it starts */
+export const ANCHOR_AMM_ERROR__INVALID_LBP_SCHEDULE = 0x1786; // 6022
+/** LbpNotEnded: Liquidity can only be withdrawn once the bootstrapping period has ended */
+export const ANCHOR_AMM_ERROR__LBP_NOT_ENDED = 0x1787; // 6023
+/** InvalidPriceBand: Price band must be below half of PRICE_BAND_DENOMINATOR */
+export const ANCHOR_AMM_ERROR__INVALID_PRICE_BAND = 0x1788; // 6024
+/** PriceBandExceeded: Swap would move the pool outside its price band */
+export const ANCHOR_AMM_ERROR__PRICE_BAND_EXCEEDED = 0x1789; // 6025
+/** NotConstantSumPool: Operation is only supported on constant-sum pools */
+export const ANCHOR_AMM_ERROR__NOT_CONSTANT_SUM_POOL = 0x178a; // 6026
+/** InvalidOracle: Oracle account is missing or not a valid price account */
+export const ANCHOR_AMM_ERROR__INVALID_ORACLE = 0x178b; // 6027
+/** StaleOracle: Oracle price is older than the pool's maximum age */
+export const ANCHOR_AMM_ERROR__STALE_ORACLE = 0x178c; // 6028
+/** OracleConfidenceTooWide: Oracle confidence interval is wider than the pool allows */
+export const ANCHOR_AMM_ERROR__ORACLE_CONFIDENCE_TOO_WIDE = 0x178d; // 6029
+/** OraclePriceDeviation: Pool price after the swap deviates too far from the reference oracle */
+export const ANCHOR_AMM_ERROR__ORACLE_PRICE_DEVIATION = 0x178e; // 6030
+/** InvalidOracleDeviation: Maximum oracle deviation must be at most 10000 bps */
+export const ANCHOR_AMM_ERROR__INVALID_ORACLE_DEVIATION = 0x178f; // 6031
+/** InvalidPmmK: PMM liquidity parameter k must be at most 1 (WAD) */
+export const ANCHOR_AMM_ERROR__INVALID_PMM_K = 0x1790; // 6032
+/** UnsupportedCurveOperation: Operation is not supported by this pool's curve */
+export const ANCHOR_AMM_ERROR__UNSUPPORTED_CURVE_OPERATION = 0x1791; // 6033
+/** InvalidDynamicFee: Dynamic fee cap must be between SWAP_FEE_BPS and MAX_SWAP_FEE_BPS with a positive half-life */
+export const ANCHOR_AMM_ERROR__INVALID_DYNAMIC_FEE = 0x1792; // 6034
+/** InvalidReferralFee: Referral fee exceeds MAX_REFERRAL_FEE_BPS or has no referrer token account */
+export const ANCHOR_AMM_ERROR__INVALID_REFERRAL_FEE = 0x1793; // 6035
+/** InvalidCreatorFee: Creator fee exceeds MAX_CREATOR_FEE_BPS */
+export const ANCHOR_AMM_ERROR__INVALID_CREATOR_FEE = 0x1794; // 6036
+/** NoCreatorFees: No creator fees to claim */
+export const ANCHOR_AMM_ERROR__NO_CREATOR_FEES = 0x1795; // 6037
+/** InvalidDepositFee: Deposit fee exceeds MAX_DEPOSIT_FEE_BPS */
+export const ANCHOR_AMM_ERROR__INVALID_DEPOSIT_FEE = 0x1796; // 6038
+/** InvalidPoolLayout: Account is not a liquidity pool in a layout migrate_pool can upgrade */
+export const ANCHOR_AMM_ERROR__INVALID_POOL_LAYOUT = 0x1797; // 6039
+/** PoolNotEmpty: Pool still has liquidity beyond the locked minimum */
+export const ANCHOR_AMM_ERROR__POOL_NOT_EMPTY = 0x1798; // 6040
+/** PoolDustExceeded: Vaults hold more than the dust a closing pool may burn */
+export const ANCHOR_AMM_ERROR__POOL_DUST_EXCEEDED = 0x1799; // 6041
+/** Paused: This operation is paused */
+export const ANCHOR_AMM_ERROR__PAUSED = 0x179a; // 6042
+/** InvalidPauseFlags: Pause flags must be a combination of PAUSE_* flags */
+export const ANCHOR_AMM_ERROR__INVALID_PAUSE_FLAGS = 0x179b; // 6043
+/** PoolWithdrawPause: Withdrawals can only be paused globally */
+export const ANCHOR_AMM_ERROR__POOL_WITHDRAW_PAUSE = 0x179c; // 6044
+/** PoolLocked: Pool is locked by an instruction in progress */
+export const ANCHOR_AMM_ERROR__POOL_LOCKED = 0x179d; // 6045
+/** OppositeSwapInTransaction: Transaction also swaps this pool in the opposite direction */
+export const ANCHOR_AMM_ERROR__OPPOSITE_SWAP_IN_TRANSACTION = 0x179e; // 6046
+/** SlotPriceMoveExceeded: Swaps in this slot moved the price more than max_slot_price_move_bps */
+export const ANCHOR_AMM_ERROR__SLOT_PRICE_MOVE_EXCEEDED = 0x179f; // 6047
+/** TradingNotStarted: Trading has not started on this pool yet */
+export const ANCHOR_AMM_ERROR__TRADING_NOT_STARTED = 0x17a0; // 6048
+/** LaunchMaxBuyExceeded: Swap output exceeds the launch window's maximum buy */
+export const ANCHOR_AMM_ERROR__LAUNCH_MAX_BUY_EXCEEDED = 0x17a1; // 6049
+/** InvalidLaunchParams: Launch fee must be at most MAX_LAUNCH_FEE_BPS with a non-negative duration */
+export const ANCHOR_AMM_ERROR__INVALID_LAUNCH_PARAMS = 0x17a2; // 6050
+/** LaunchAlreadyStarted: Launch parameters can only be set before the pool holds liquidity */
+export const ANCHOR_AMM_ERROR__LAUNCH_ALREADY_STARTED = 0x17a3; // 6051
+/** InvalidTokenCount: Multi-asset pools hold between 3 and MAX_MULTI_ASSET_TOKENS tokens */
+export const ANCHOR_AMM_ERROR__INVALID_TOKEN_COUNT = 0x17a4; // 6052
+/** InvalidRemainingAccounts: Remaining accounts don't match the pool's tokens */
+export const ANCHOR_AMM_ERROR__INVALID_REMAINING_ACCOUNTS = 0x17a5; // 6053
+/** Unauthorized: Signer is not the pool admin */
+export const ANCHOR_AMM_ERROR__UNAUTHORIZED = 0x17a6; // 6054
 
 export type AnchorAmmError =
   | typeof ANCHOR_AMM_ERROR__DEADLINE_EXCEEDED
   | typeof ANCHOR_AMM_ERROR__IDENTICAL_MINTS
   | typeof ANCHOR_AMM_ERROR__INSUFFICIENT_INITIAL_LIQUIDITY
   | typeof ANCHOR_AMM_ERROR__INSUFFICIENT_LIQUIDITY
+  | typeof ANCHOR_AMM_ERROR__INVALID_AMP
+  | typeof ANCHOR_AMM_ERROR__INVALID_AMP_RAMP
+  | typeof ANCHOR_AMM_ERROR__INVALID_CREATOR_FEE
+  | typeof ANCHOR_AMM_ERROR__INVALID_DEPOSIT_FEE
+  | typeof ANCHOR_AMM_ERROR__INVALID_DYNAMIC_FEE
+  | typeof ANCHOR_AMM_ERROR__INVALID_FEE_RATE
+  | typeof ANCHOR_AMM_ERROR__INVALID_LAUNCH_PARAMS
+  | typeof ANCHOR_AMM_ERROR__INVALID_LBP_SCHEDULE
+  | typeof ANCHOR_AMM_ERROR__INVALID_ORACLE
+  | typeof ANCHOR_AMM_ERROR__INVALID_ORACLE_DEVIATION
+  | typeof ANCHOR_AMM_ERROR__INVALID_PAUSE_FLAGS
+  | typeof ANCHOR_AMM_ERROR__INVALID_PMM_K
+  | typeof ANCHOR_AMM_ERROR__INVALID_POOL_LAYOUT
+  | typeof ANCHOR_AMM_ERROR__INVALID_PRICE_BAND
+  | typeof ANCHOR_AMM_ERROR__INVALID_REFERRAL_FEE
+  | typeof ANCHOR_AMM_ERROR__INVALID_REMAINING_ACCOUNTS
+  | typeof ANCHOR_AMM_ERROR__INVALID_TICK_ARRAY
+  | typeof ANCHOR_AMM_ERROR__INVALID_TICK_INDEX
+  | typeof ANCHOR_AMM_ERROR__INVALID_TICK_RANGE
+  | typeof ANCHOR_AMM_ERROR__INVALID_TICK_SPACING
+  | typeof ANCHOR_AMM_ERROR__INVALID_TOKEN_COUNT
+  | typeof ANCHOR_AMM_ERROR__INVALID_WEIGHTS
+  | typeof ANCHOR_AMM_ERROR__INVARIANT_DID_NOT_CONVERGE
+  | typeof ANCHOR_AMM_ERROR__LAUNCH_ALREADY_STARTED
+  | typeof ANCHOR_AMM_ERROR__LAUNCH_MAX_BUY_EXCEEDED
+  | typeof ANCHOR_AMM_ERROR__LBP_NOT_ENDED
+  | typeof ANCHOR_AMM_ERROR__MAX_IN_RATIO_EXCEEDED
+  | typeof ANCHOR_AMM_ERROR__MAX_OUT_RATIO_EXCEEDED
   | typeof ANCHOR_AMM_ERROR__MINIMUM_LIQUIDITY_LOCKED
   | typeof ANCHOR_AMM_ERROR__MINT_MISMATCH
+  | typeof ANCHOR_AMM_ERROR__NOT_CONSTANT_SUM_POOL
+  | typeof ANCHOR_AMM_ERROR__NOT_STABLE_POOL
+  | typeof ANCHOR_AMM_ERROR__NO_CREATOR_FEES
   | typeof ANCHOR_AMM_ERROR__NO_EXCESS_TOKENS
+  | typeof ANCHOR_AMM_ERROR__OPPOSITE_SWAP_IN_TRANSACTION
+  | typeof ANCHOR_AMM_ERROR__ORACLE_CONFIDENCE_TOO_WIDE
+  | typeof ANCHOR_AMM_ERROR__ORACLE_PRICE_DEVIATION
+  | typeof ANCHOR_AMM_ERROR__PAUSED
+  | typeof ANCHOR_AMM_ERROR__POOL_DUST_EXCEEDED
+  | typeof ANCHOR_AMM_ERROR__POOL_LOCKED
+  | typeof ANCHOR_AMM_ERROR__POOL_NOT_EMPTY
+  | typeof ANCHOR_AMM_ERROR__POOL_WITHDRAW_PAUSE
+  | typeof ANCHOR_AMM_ERROR__PRICE_BAND_EXCEEDED
   | typeof ANCHOR_AMM_ERROR__SLIPPAGE_EXCEEDED
+  | typeof ANCHOR_AMM_ERROR__SLOT_PRICE_MOVE_EXCEEDED
+  | typeof ANCHOR_AMM_ERROR__SQRT_PRICE_OUT_OF_BOUNDS
+  | typeof ANCHOR_AMM_ERROR__STALE_ORACLE
+  | typeof ANCHOR_AMM_ERROR__TRADING_NOT_STARTED
+  | typeof ANCHOR_AMM_ERROR__UNAUTHORIZED
+  | typeof ANCHOR_AMM_ERROR__UNSUPPORTED_CURVE_OPERATION
   | typeof ANCHOR_AMM_ERROR__ZERO_AMOUNT;
 
 let anchorAmmErrorMessages: Record<AnchorAmmError, string> | undefined;
@@ -51,10 +189,56 @@ if (process.env.NODE_ENV !== "production") {
     [ANCHOR_AMM_ERROR__IDENTICAL_MINTS]: `Token A and Token B mints cannot be the same`,
     [ANCHOR_AMM_ERROR__INSUFFICIENT_INITIAL_LIQUIDITY]: `Initial liquidity deposit must mint more than 1000 LP tokens`,
     [ANCHOR_AMM_ERROR__INSUFFICIENT_LIQUIDITY]: `Pool has insufficient liquidity for this operation`,
+    [ANCHOR_AMM_ERROR__INVALID_AMP]: `Amplification coefficient must be between 1 and MAX_AMP`,
+    [ANCHOR_AMM_ERROR__INVALID_AMP_RAMP]: `Amplification ramp is too short, too steep or started too recently`,
+    [ANCHOR_AMM_ERROR__INVALID_CREATOR_FEE]: `Creator fee exceeds MAX_CREATOR_FEE_BPS`,
+    [ANCHOR_AMM_ERROR__INVALID_DEPOSIT_FEE]: `Deposit fee exceeds MAX_DEPOSIT_FEE_BPS`,
+    [ANCHOR_AMM_ERROR__INVALID_DYNAMIC_FEE]: `Dynamic fee cap must be between SWAP_FEE_BPS and MAX_SWAP_FEE_BPS with a positive half-life`,
+    [ANCHOR_AMM_ERROR__INVALID_FEE_RATE]: `Fee rate must be below 10000 bps`,
+    [ANCHOR_AMM_ERROR__INVALID_LAUNCH_PARAMS]: `Launch fee must be at most MAX_LAUNCH_FEE_BPS with a non-negative duration`,
+    [ANCHOR_AMM_ERROR__INVALID_LBP_SCHEDULE]: `Liquidity bootstrapping schedule must end after it starts`,
+    [ANCHOR_AMM_ERROR__INVALID_ORACLE]: `Oracle account is missing or not a valid price account`,
+    [ANCHOR_AMM_ERROR__INVALID_ORACLE_DEVIATION]: `Maximum oracle deviation must be at most 10000 bps`,
+    [ANCHOR_AMM_ERROR__INVALID_PAUSE_FLAGS]: `Pause flags must be a combination of PAUSE_* flags`,
+    [ANCHOR_AMM_ERROR__INVALID_PMM_K]: `PMM liquidity parameter k must be at most 1 (WAD)`,
+    [ANCHOR_AMM_ERROR__INVALID_POOL_LAYOUT]: `Account is not a liquidity pool in a layout migrate_pool can upgrade`,
+    [ANCHOR_AMM_ERROR__INVALID_PRICE_BAND]: `Price band must be below half of PRICE_BAND_DENOMINATOR`,
+    [ANCHOR_AMM_ERROR__INVALID_REFERRAL_FEE]: `Referral fee exceeds MAX_REFERRAL_FEE_BPS or has no referrer token account`,
+    [ANCHOR_AMM_ERROR__INVALID_REMAINING_ACCOUNTS]: `Remaining accounts don't match the pool's tokens`,
+    [ANCHOR_AMM_ERROR__INVALID_TICK_ARRAY]: `Tick array does not belong to this pool or does not contain the tick`,
+    [ANCHOR_AMM_ERROR__INVALID_TICK_INDEX]: `Tick index is out of bounds or not a multiple of the tick spacing`,
+    [ANCHOR_AMM_ERROR__INVALID_TICK_RANGE]: `Lower tick must be below upper tick`,
+    [ANCHOR_AMM_ERROR__INVALID_TICK_SPACING]: `Tick spacing must be between 1 and 16384`,
+    [ANCHOR_AMM_ERROR__INVALID_TOKEN_COUNT]: `Multi-asset pools hold between 3 and MAX_MULTI_ASSET_TOKENS tokens`,
+    [ANCHOR_AMM_ERROR__INVALID_WEIGHTS]: `Weights must each be at least MIN_WEIGHT_BPS and sum to WEIGHT_DENOMINATOR`,
+    [ANCHOR_AMM_ERROR__INVARIANT_DID_NOT_CONVERGE]: `StableSwap invariant did not converge`,
+    [ANCHOR_AMM_ERROR__LAUNCH_ALREADY_STARTED]: `Launch parameters can only be set before the pool holds liquidity`,
+    [ANCHOR_AMM_ERROR__LAUNCH_MAX_BUY_EXCEEDED]: `Swap output exceeds the launch window's maximum buy`,
+    [ANCHOR_AMM_ERROR__LBP_NOT_ENDED]: `Liquidity can only be withdrawn once the bootstrapping period has ended`,
+    [ANCHOR_AMM_ERROR__MAX_IN_RATIO_EXCEEDED]: `Swap input exceeds the maximum share of the input reserve`,
+    [ANCHOR_AMM_ERROR__MAX_OUT_RATIO_EXCEEDED]: `Swap output exceeds the maximum share of the output reserve`,
     [ANCHOR_AMM_ERROR__MINIMUM_LIQUIDITY_LOCKED]: `Cannot withdraw minimum locked liquidity (1000 LP tokens)`,
     [ANCHOR_AMM_ERROR__MINT_MISMATCH]: `Mismatch in input mints`,
+    [ANCHOR_AMM_ERROR__NOT_CONSTANT_SUM_POOL]: `Operation is only supported on constant-sum pools`,
+    [ANCHOR_AMM_ERROR__NOT_STABLE_POOL]: `Operation is only supported on StableSwap pools`,
+    [ANCHOR_AMM_ERROR__NO_CREATOR_FEES]: `No creator fees to claim`,
     [ANCHOR_AMM_ERROR__NO_EXCESS_TOKENS]: `No excess tokens in vault to skim`,
+    [ANCHOR_AMM_ERROR__OPPOSITE_SWAP_IN_TRANSACTION]: `Transaction also swaps this pool in the opposite direction`,
+    [ANCHOR_AMM_ERROR__ORACLE_CONFIDENCE_TOO_WIDE]: `Oracle confidence interval is wider than the pool allows`,
+    [ANCHOR_AMM_ERROR__ORACLE_PRICE_DEVIATION]: `Pool price after the swap deviates too far from the reference oracle`,
+    [ANCHOR_AMM_ERROR__PAUSED]: `This operation is paused`,
+    [ANCHOR_AMM_ERROR__POOL_DUST_EXCEEDED]: `Vaults hold more than the dust a closing pool may burn`,
+    [ANCHOR_AMM_ERROR__POOL_LOCKED]: `Pool is locked by an instruction in progress`,
+    [ANCHOR_AMM_ERROR__POOL_NOT_EMPTY]: `Pool still has liquidity beyond the locked minimum`,
+    [ANCHOR_AMM_ERROR__POOL_WITHDRAW_PAUSE]: `Withdrawals can only be paused globally`,
+    [ANCHOR_AMM_ERROR__PRICE_BAND_EXCEEDED]: `Swap would move the pool outside its price band`,
     [ANCHOR_AMM_ERROR__SLIPPAGE_EXCEEDED]: `Output amount is less than minimum specified`,
+    [ANCHOR_AMM_ERROR__SLOT_PRICE_MOVE_EXCEEDED]: `Swaps in this slot moved the price more than max_slot_price_move_bps`,
+    [ANCHOR_AMM_ERROR__SQRT_PRICE_OUT_OF_BOUNDS]: `Sqrt price is out of bounds`,
+    [ANCHOR_AMM_ERROR__STALE_ORACLE]: `Oracle price is older than the pool's maximum age`,
+    [ANCHOR_AMM_ERROR__TRADING_NOT_STARTED]: `Trading has not started on this pool yet`,
+    [ANCHOR_AMM_ERROR__UNAUTHORIZED]: `Signer is not the pool admin`,
+    [ANCHOR_AMM_ERROR__UNSUPPORTED_CURVE_OPERATION]: `Operation is not supported by this pool's curve`,
     [ANCHOR_AMM_ERROR__ZERO_AMOUNT]: `Amount must be greater than zero`,
   };
 }
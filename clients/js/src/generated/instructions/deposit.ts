@@ -64,6 +64,8 @@ export type DepositInstruction<
   TAccountTokenBMint extends string | AccountMeta<string> = string,
   TAccountLpTokenMint extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountPoolStats extends string | AccountMeta<string> = string,
+  TAccountGlobalState extends string | AccountMeta<string> = string,
   TAccountFeeLpTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
@@ -74,6 +76,8 @@ export type DepositInstruction<
   TAccountLpTokenSystemProgramTokenAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -110,6 +114,12 @@ export type DepositInstruction<
       TAccountLiquidityPool extends string
         ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountPoolStats extends string
+        ? WritableAccount<TAccountPoolStats>
+        : TAccountPoolStats,
+      TAccountGlobalState extends string
+        ? ReadonlyAccount<TAccountGlobalState>
+        : TAccountGlobalState,
       TAccountFeeLpTokenAccount extends string
         ? WritableAccount<TAccountFeeLpTokenAccount>
         : TAccountFeeLpTokenAccount,
@@ -125,6 +135,12 @@ export type DepositInstruction<
       TAccountLpTokenSystemProgramTokenAccount extends string
         ? WritableAccount<TAccountLpTokenSystemProgramTokenAccount>
         : TAccountLpTokenSystemProgramTokenAccount,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -192,28 +208,36 @@ export type DepositAsyncInput<
   TAccountTokenBMint extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountFeeLpTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountLpTokenSystemProgramTokenAccount extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   lpTokenSignerTokenAccount?: Address<TAccountLpTokenSignerTokenAccount>;
   tokenASignerTokenAccount?: Address<TAccountTokenASignerTokenAccount>;
   tokenBSignerTokenAccount?: Address<TAccountTokenBSignerTokenAccount>;
-  tokenAVault?: Address<TAccountTokenAVault>;
-  tokenBVault?: Address<TAccountTokenBVault>;
+  tokenAVault: Address<TAccountTokenAVault>;
+  tokenBVault: Address<TAccountTokenBVault>;
   tokenAMint: Address<TAccountTokenAMint>;
   tokenBMint: Address<TAccountTokenBMint>;
-  lpTokenMint?: Address<TAccountLpTokenMint>;
+  lpTokenMint: Address<TAccountLpTokenMint>;
   liquidityPool?: Address<TAccountLiquidityPool>;
+  poolStats?: Address<TAccountPoolStats>;
+  globalState?: Address<TAccountGlobalState>;
   /** Protocol fee LP token account owned by the pool PDA */
-  feeLpTokenAccount?: Address<TAccountFeeLpTokenAccount>;
+  feeLpTokenAccount: Address<TAccountFeeLpTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   lpTokenSystemProgramTokenAccount?: Address<TAccountLpTokenSystemProgramTokenAccount>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   tokenAAmountDesired: DepositInstructionDataArgs["tokenAAmountDesired"];
   tokenBAmountDesired: DepositInstructionDataArgs["tokenBAmountDesired"];
   tokenAAmountMin: DepositInstructionDataArgs["tokenAAmountMin"];
@@ -232,11 +256,15 @@ export async function getDepositInstructionAsync<
   TAccountTokenBMint extends string,
   TAccountLpTokenMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
+  TAccountGlobalState extends string,
   TAccountFeeLpTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountLpTokenSystemProgramTokenAccount extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: DepositAsyncInput<
@@ -250,11 +278,15 @@ export async function getDepositInstructionAsync<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
-    TAccountLpTokenSystemProgramTokenAccount
+    TAccountLpTokenSystemProgramTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -270,11 +302,15 @@ export async function getDepositInstructionAsync<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
-    TAccountLpTokenSystemProgramTokenAccount
+    TAccountLpTokenSystemProgramTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -301,6 +337,8 @@ export async function getDepositInstructionAsync<
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     feeLpTokenAccount: {
       value: input.feeLpTokenAccount ?? null,
       isWritable: true,
@@ -315,6 +353,8 @@ export async function getDepositInstructionAsync<
       value: input.lpTokenSystemProgramTokenAccount ?? null,
       isWritable: true,
     },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -329,20 +369,6 @@ export async function getDepositInstructionAsync<
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
   }
-  if (!accounts.lpTokenMint.value) {
-    accounts.lpTokenMint.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            108, 112, 95, 116, 111, 107, 101, 110, 95, 109, 105, 110, 116,
-          ]),
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenAMint.value)),
-        getAddressEncoder().encode(expectAddress(accounts.tokenBMint.value)),
-      ],
-    });
-  }
   if (!accounts.lpTokenSignerTokenAccount.value) {
     accounts.lpTokenSignerTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -390,48 +416,26 @@ export async function getDepositInstructionAsync<
       ],
     });
   }
-  if (!accounts.tokenAVault.value) {
-    accounts.tokenAVault.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+  if (!accounts.poolStats.value) {
+    accounts.poolStats.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ]),
+          new Uint8Array([112, 111, 111, 108, 95, 115, 116, 97, 116, 115]),
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenAMint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
       ],
     });
   }
-  if (!accounts.tokenBVault.value) {
-    accounts.tokenBVault.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+  if (!accounts.globalState.value) {
+    accounts.globalState.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
         getBytesEncoder().encode(
           new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
+            103, 108, 111, 98, 97, 108, 95, 115, 116, 97, 116, 101,
           ]),
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenBMint.value)),
-      ],
-    });
-  }
-  if (!accounts.feeLpTokenAccount.value) {
-    accounts.feeLpTokenAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
-      seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
-        getAddressEncoder().encode(expectAddress(accounts.lpTokenMint.value)),
       ],
     });
   }
@@ -459,6 +463,19 @@ export async function getDepositInstructionAsync<
         ],
       });
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -473,11 +490,15 @@ export async function getDepositInstructionAsync<
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.feeLpTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.lpTokenSystemProgramTokenAccount),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getDepositInstructionDataEncoder().encode(
       args as DepositInstructionDataArgs,
@@ -495,11 +516,15 @@ export async function getDepositInstructionAsync<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
-    TAccountLpTokenSystemProgramTokenAccount
+    TAccountLpTokenSystemProgramTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -514,11 +539,15 @@ export type DepositInput<
   TAccountTokenBMint extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountFeeLpTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountLpTokenSystemProgramTokenAccount extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   lpTokenSignerTokenAccount: Address<TAccountLpTokenSignerTokenAccount>;
@@ -530,12 +559,16 @@ export type DepositInput<
   tokenBMint: Address<TAccountTokenBMint>;
   lpTokenMint: Address<TAccountLpTokenMint>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  poolStats: Address<TAccountPoolStats>;
+  globalState: Address<TAccountGlobalState>;
   /** Protocol fee LP token account owned by the pool PDA */
   feeLpTokenAccount: Address<TAccountFeeLpTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   lpTokenSystemProgramTokenAccount: Address<TAccountLpTokenSystemProgramTokenAccount>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   tokenAAmountDesired: DepositInstructionDataArgs["tokenAAmountDesired"];
   tokenBAmountDesired: DepositInstructionDataArgs["tokenBAmountDesired"];
   tokenAAmountMin: DepositInstructionDataArgs["tokenAAmountMin"];
@@ -554,11 +587,15 @@ export function getDepositInstruction<
   TAccountTokenBMint extends string,
   TAccountLpTokenMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
+  TAccountGlobalState extends string,
   TAccountFeeLpTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountLpTokenSystemProgramTokenAccount extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: DepositInput<
@@ -572,11 +609,15 @@ export function getDepositInstruction<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
-    TAccountLpTokenSystemProgramTokenAccount
+    TAccountLpTokenSystemProgramTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): DepositInstruction<
@@ -591,11 +632,15 @@ export function getDepositInstruction<
   TAccountTokenBMint,
   TAccountLpTokenMint,
   TAccountLiquidityPool,
+  TAccountPoolStats,
+  TAccountGlobalState,
   TAccountFeeLpTokenAccount,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
   TAccountSystemProgram,
-  TAccountLpTokenSystemProgramTokenAccount
+  TAccountLpTokenSystemProgramTokenAccount,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ANCHOR_AMM_PROGRAM_ADDRESS;
@@ -621,6 +666,8 @@ export function getDepositInstruction<
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     feeLpTokenAccount: {
       value: input.feeLpTokenAccount ?? null,
       isWritable: true,
@@ -635,6 +682,8 @@ export function getDepositInstruction<
       value: input.lpTokenSystemProgramTokenAccount ?? null,
       isWritable: true,
     },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -671,11 +720,15 @@ export function getDepositInstruction<
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.feeLpTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.lpTokenSystemProgramTokenAccount),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getDepositInstructionDataEncoder().encode(
       args as DepositInstructionDataArgs,
@@ -693,11 +746,15 @@ export function getDepositInstruction<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
-    TAccountLpTokenSystemProgramTokenAccount
+    TAccountLpTokenSystemProgramTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -717,12 +774,16 @@ export type ParsedDepositInstruction<
     tokenBMint: TAccountMetas[7];
     lpTokenMint: TAccountMetas[8];
     liquidityPool: TAccountMetas[9];
+    poolStats: TAccountMetas[10];
+    globalState: TAccountMetas[11];
     /** Protocol fee LP token account owned by the pool PDA */
-    feeLpTokenAccount: TAccountMetas[10];
-    tokenProgram: TAccountMetas[11];
-    associatedTokenProgram: TAccountMetas[12];
-    systemProgram: TAccountMetas[13];
-    lpTokenSystemProgramTokenAccount: TAccountMetas[14];
+    feeLpTokenAccount: TAccountMetas[12];
+    tokenProgram: TAccountMetas[13];
+    associatedTokenProgram: TAccountMetas[14];
+    systemProgram: TAccountMetas[15];
+    lpTokenSystemProgramTokenAccount: TAccountMetas[16];
+    eventAuthority: TAccountMetas[17];
+    program: TAccountMetas[18];
   };
   data: DepositInstructionData;
 };
@@ -735,7 +796,7 @@ export function parseDepositInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedDepositInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 15) {
+  if (instruction.accounts.length < 19) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -758,11 +819,15 @@ export function parseDepositInstruction<
       tokenBMint: getNextAccount(),
       lpTokenMint: getNextAccount(),
       liquidityPool: getNextAccount(),
+      poolStats: getNextAccount(),
+      globalState: getNextAccount(),
       feeLpTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       lpTokenSystemProgramTokenAccount: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getDepositInstructionDataDecoder().decode(instruction.data),
   };
@@ -6,9 +6,44 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./claimCreatorFees";
+export * from "./closePool";
+export * from "./collectFees";
+export * from "./decreaseLiquidity";
 export * from "./deposit";
+export * from "./depositMultiAsset";
+export * from "./depositMultiAssetSingle";
+export * from "./increaseLiquidity";
+export * from "./initializeConcentratedPool";
+export * from "./initializeConstantSumPool";
+export * from "./initializeGlobalState";
+export * from "./initializeLbp";
+export * from "./initializeMultiAssetPool";
+export * from "./initializePmmPool";
 export * from "./initializePool";
+export * from "./initializeStablePool";
+export * from "./initializeTickArray";
+export * from "./initializeWeightedPool";
+export * from "./migratePool";
+export * from "./openPosition";
+export * from "./quoteDeposit";
+export * from "./quoteSwap";
+export * from "./rampAmp";
+export * from "./setDynamicFee";
+export * from "./setGlobalPause";
+export * from "./setLaunchParams";
+export * from "./setPoolPause";
+export * from "./setPriceBand";
+export * from "./setReferenceOracle";
+export * from "./setSlotPriceMoveLimit";
 export * from "./skim";
+export * from "./stopRampAmp";
 export * from "./swap";
+export * from "./swapConcentrated";
+export * from "./swapMultiAsset";
+export * from "./swapV2";
 export * from "./sync";
+export * from "./transferCreator";
 export * from "./withdraw";
+export * from "./withdrawMultiAsset";
+export * from "./withdrawMultiAssetSingle";
@@ -11,11 +11,15 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -55,6 +59,7 @@ export type InitializePoolInstruction<
   TAccountTokenAMint extends string | AccountMeta<string> = string,
   TAccountTokenBMint extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountPoolStats extends string | AccountMeta<string> = string,
   TAccountLpTokenMint extends string | AccountMeta<string> = string,
   TAccountLpTokenSignerTokenAccount extends string | AccountMeta<string> =
     string,
@@ -65,6 +70,8 @@ export type InitializePoolInstruction<
     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -89,6 +96,9 @@ export type InitializePoolInstruction<
       TAccountLiquidityPool extends string
         ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountPoolStats extends string
+        ? WritableAccount<TAccountPoolStats>
+        : TAccountPoolStats,
       TAccountLpTokenMint extends string
         ? WritableAccount<TAccountLpTokenMint>
         : TAccountLpTokenMint,
@@ -107,19 +117,37 @@ export type InitializePoolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type InitializePoolInstructionData = {
   discriminator: ReadonlyUint8Array;
+  creatorFeeBps: number;
+  depositFeeBps: number;
+  depositFeeToProtocol: boolean;
 };
 
-export type InitializePoolInstructionDataArgs = {};
+export type InitializePoolInstructionDataArgs = {
+  creatorFeeBps: number;
+  depositFeeBps: number;
+  depositFeeToProtocol: boolean;
+};
 
 export function getInitializePoolInstructionDataEncoder(): FixedSizeEncoder<InitializePoolInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([["discriminator", fixEncoderSize(getBytesEncoder(), 1)]]),
+    getStructEncoder([
+      ["discriminator", fixEncoderSize(getBytesEncoder(), 1)],
+      ["creatorFeeBps", getU16Encoder()],
+      ["depositFeeBps", getU16Encoder()],
+      ["depositFeeToProtocol", getBooleanEncoder()],
+    ]),
     (value) => ({ ...value, discriminator: INITIALIZE_POOL_DISCRIMINATOR }),
   );
 }
@@ -127,6 +155,9 @@ export function getInitializePoolInstructionDataEncoder(): FixedSizeEncoder<Init
 export function getInitializePoolInstructionDataDecoder(): FixedSizeDecoder<InitializePoolInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 1)],
+    ["creatorFeeBps", getU16Decoder()],
+    ["depositFeeBps", getU16Decoder()],
+    ["depositFeeToProtocol", getBooleanDecoder()],
   ]);
 }
 
@@ -147,12 +178,15 @@ export type InitializePoolAsyncInput<
   TAccountTokenAMint extends string = string,
   TAccountTokenBMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLpTokenSignerTokenAccount extends string = string,
   TAccountFeeLpTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   tokenAVault?: Address<TAccountTokenAVault>;
@@ -160,6 +194,8 @@ export type InitializePoolAsyncInput<
   tokenAMint: Address<TAccountTokenAMint>;
   tokenBMint: Address<TAccountTokenBMint>;
   liquidityPool?: Address<TAccountLiquidityPool>;
+  poolStats?: Address<TAccountPoolStats>;
+  /** Already exists if the pair had a pool before, see `close_pool` */
   lpTokenMint?: Address<TAccountLpTokenMint>;
   lpTokenSignerTokenAccount?: Address<TAccountLpTokenSignerTokenAccount>;
   /** Protocol fee LP token account owned by the pool PDA */
@@ -167,6 +203,11 @@ export type InitializePoolAsyncInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  creatorFeeBps: InitializePoolInstructionDataArgs["creatorFeeBps"];
+  depositFeeBps: InitializePoolInstructionDataArgs["depositFeeBps"];
+  depositFeeToProtocol: InitializePoolInstructionDataArgs["depositFeeToProtocol"];
 };
 
 export async function getInitializePoolInstructionAsync<
@@ -176,12 +217,15 @@ export async function getInitializePoolInstructionAsync<
   TAccountTokenAMint extends string,
   TAccountTokenBMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
   TAccountLpTokenMint extends string,
   TAccountLpTokenSignerTokenAccount extends string,
   TAccountFeeLpTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: InitializePoolAsyncInput<
@@ -191,12 +235,15 @@ export async function getInitializePoolInstructionAsync<
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
     TAccountLpTokenMint,
     TAccountLpTokenSignerTokenAccount,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -208,12 +255,15 @@ export async function getInitializePoolInstructionAsync<
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
     TAccountLpTokenMint,
     TAccountLpTokenSignerTokenAccount,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -227,6 +277,7 @@ export async function getInitializePoolInstructionAsync<
     tokenAMint: { value: input.tokenAMint ?? null, isWritable: false },
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     lpTokenSignerTokenAccount: {
       value: input.lpTokenSignerTokenAccount ?? null,
@@ -242,12 +293,17 @@ export async function getInitializePoolInstructionAsync<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.liquidityPool.value) {
     accounts.liquidityPool.value = await getProgramDerivedAddress({
@@ -289,6 +345,17 @@ export async function getInitializePoolInstructionAsync<
       ],
     });
   }
+  if (!accounts.poolStats.value) {
+    accounts.poolStats.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([112, 111, 111, 108, 95, 115, 116, 97, 116, 115]),
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
+      ],
+    });
+  }
   if (!accounts.lpTokenMint.value) {
     accounts.lpTokenMint.value = await getProgramDerivedAddress({
       programAddress,
@@ -333,6 +400,19 @@ export async function getInitializePoolInstructionAsync<
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -343,14 +423,19 @@ export async function getInitializePoolInstructionAsync<
       getAccountMeta(accounts.tokenAMint),
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.lpTokenSignerTokenAccount),
       getAccountMeta(accounts.feeLpTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
-    data: getInitializePoolInstructionDataEncoder().encode({}),
+    data: getInitializePoolInstructionDataEncoder().encode(
+      args as InitializePoolInstructionDataArgs,
+    ),
     programAddress,
   } as InitializePoolInstruction<
     TProgramAddress,
@@ -360,12 +445,15 @@ export async function getInitializePoolInstructionAsync<
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
     TAccountLpTokenMint,
     TAccountLpTokenSignerTokenAccount,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -376,12 +464,15 @@ export type InitializePoolInput<
   TAccountTokenAMint extends string = string,
   TAccountTokenBMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLpTokenSignerTokenAccount extends string = string,
   TAccountFeeLpTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   tokenAVault: Address<TAccountTokenAVault>;
@@ -389,6 +480,8 @@ export type InitializePoolInput<
   tokenAMint: Address<TAccountTokenAMint>;
   tokenBMint: Address<TAccountTokenBMint>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  poolStats: Address<TAccountPoolStats>;
+  /** Already exists if the pair had a pool before, see `close_pool` */
   lpTokenMint: Address<TAccountLpTokenMint>;
   lpTokenSignerTokenAccount: Address<TAccountLpTokenSignerTokenAccount>;
   /** Protocol fee LP token account owned by the pool PDA */
@@ -396,6 +489,11 @@ export type InitializePoolInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  creatorFeeBps: InitializePoolInstructionDataArgs["creatorFeeBps"];
+  depositFeeBps: InitializePoolInstructionDataArgs["depositFeeBps"];
+  depositFeeToProtocol: InitializePoolInstructionDataArgs["depositFeeToProtocol"];
 };
 
 export function getInitializePoolInstruction<
@@ -405,12 +503,15 @@ export function getInitializePoolInstruction<
   TAccountTokenAMint extends string,
   TAccountTokenBMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
   TAccountLpTokenMint extends string,
   TAccountLpTokenSignerTokenAccount extends string,
   TAccountFeeLpTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: InitializePoolInput<
@@ -420,12 +521,15 @@ export function getInitializePoolInstruction<
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
     TAccountLpTokenMint,
     TAccountLpTokenSignerTokenAccount,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): InitializePoolInstruction<
@@ -436,12 +540,15 @@ export function getInitializePoolInstruction<
   TAccountTokenAMint,
   TAccountTokenBMint,
   TAccountLiquidityPool,
+  TAccountPoolStats,
   TAccountLpTokenMint,
   TAccountLpTokenSignerTokenAccount,
   TAccountFeeLpTokenAccount,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ANCHOR_AMM_PROGRAM_ADDRESS;
@@ -454,6 +561,7 @@ export function getInitializePoolInstruction<
     tokenAMint: { value: input.tokenAMint ?? null, isWritable: false },
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     lpTokenSignerTokenAccount: {
       value: input.lpTokenSignerTokenAccount ?? null,
@@ -469,12 +577,17 @@ export function getInitializePoolInstruction<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
@@ -498,14 +611,19 @@ export function getInitializePoolInstruction<
       getAccountMeta(accounts.tokenAMint),
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.lpTokenSignerTokenAccount),
       getAccountMeta(accounts.feeLpTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
-    data: getInitializePoolInstructionDataEncoder().encode({}),
+    data: getInitializePoolInstructionDataEncoder().encode(
+      args as InitializePoolInstructionDataArgs,
+    ),
     programAddress,
   } as InitializePoolInstruction<
     TProgramAddress,
@@ -515,12 +633,15 @@ export function getInitializePoolInstruction<
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
     TAccountLpTokenMint,
     TAccountLpTokenSignerTokenAccount,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -536,13 +657,17 @@ export type ParsedInitializePoolInstruction<
     tokenAMint: TAccountMetas[3];
     tokenBMint: TAccountMetas[4];
     liquidityPool: TAccountMetas[5];
-    lpTokenMint: TAccountMetas[6];
-    lpTokenSignerTokenAccount: TAccountMetas[7];
+    poolStats: TAccountMetas[6];
+    /** Already exists if the pair had a pool before, see `close_pool` */
+    lpTokenMint: TAccountMetas[7];
+    lpTokenSignerTokenAccount: TAccountMetas[8];
     /** Protocol fee LP token account owned by the pool PDA */
-    feeLpTokenAccount: TAccountMetas[8];
-    tokenProgram: TAccountMetas[9];
-    associatedTokenProgram: TAccountMetas[10];
-    systemProgram: TAccountMetas[11];
+    feeLpTokenAccount: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    associatedTokenProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[12];
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: InitializePoolInstructionData;
 };
@@ -555,7 +680,7 @@ export function parseInitializePoolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedInitializePoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -574,12 +699,15 @@ export function parseInitializePoolInstruction<
       tokenAMint: getNextAccount(),
       tokenBMint: getNextAccount(),
       liquidityPool: getNextAccount(),
+      poolStats: getNextAccount(),
       lpTokenMint: getNextAccount(),
       lpTokenSignerTokenAccount: getNextAccount(),
       feeLpTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getInitializePoolInstructionDataDecoder().decode(instruction.data),
   };
@@ -49,6 +49,7 @@ export type SkimInstruction<
   TProgram extends string = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountGlobalState extends string | AccountMeta<string> = string,
   TAccountTokenAMint extends string | AccountMeta<string> = string,
   TAccountTokenBMint extends string | AccountMeta<string> = string,
   TAccountTokenAVault extends string | AccountMeta<string> = string,
@@ -59,6 +60,8 @@ export type SkimInstruction<
     string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -71,6 +74,9 @@ export type SkimInstruction<
       TAccountLiquidityPool extends string
         ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountGlobalState extends string
+        ? ReadonlyAccount<TAccountGlobalState>
+        : TAccountGlobalState,
       TAccountTokenAMint extends string
         ? ReadonlyAccount<TAccountTokenAMint>
         : TAccountTokenAMint,
@@ -92,6 +98,12 @@ export type SkimInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -126,6 +138,7 @@ export function getSkimInstructionDataCodec(): FixedSizeCodec<
 export type SkimAsyncInput<
   TAccountSigner extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountTokenAMint extends string = string,
   TAccountTokenBMint extends string = string,
   TAccountTokenAVault extends string = string,
@@ -133,9 +146,12 @@ export type SkimAsyncInput<
   TAccountTokenASignerTokenAccount extends string = string,
   TAccountTokenBSignerTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   liquidityPool?: Address<TAccountLiquidityPool>;
+  globalState?: Address<TAccountGlobalState>;
   tokenAMint: Address<TAccountTokenAMint>;
   tokenBMint: Address<TAccountTokenBMint>;
   tokenAVault?: Address<TAccountTokenAVault>;
@@ -143,11 +159,14 @@ export type SkimAsyncInput<
   tokenASignerTokenAccount?: Address<TAccountTokenASignerTokenAccount>;
   tokenBSignerTokenAccount?: Address<TAccountTokenBSignerTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export async function getSkimInstructionAsync<
   TAccountSigner extends string,
   TAccountLiquidityPool extends string,
+  TAccountGlobalState extends string,
   TAccountTokenAMint extends string,
   TAccountTokenBMint extends string,
   TAccountTokenAVault extends string,
@@ -155,18 +174,23 @@ export async function getSkimInstructionAsync<
   TAccountTokenASignerTokenAccount extends string,
   TAccountTokenBSignerTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: SkimAsyncInput<
     TAccountSigner,
     TAccountLiquidityPool,
+    TAccountGlobalState,
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
     TAccountTokenASignerTokenAccount,
     TAccountTokenBSignerTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -174,13 +198,16 @@ export async function getSkimInstructionAsync<
     TProgramAddress,
     TAccountSigner,
     TAccountLiquidityPool,
+    TAccountGlobalState,
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
     TAccountTokenASignerTokenAccount,
     TAccountTokenBSignerTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -190,6 +217,7 @@ export async function getSkimInstructionAsync<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     tokenAMint: { value: input.tokenAMint ?? null, isWritable: false },
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     tokenAVault: { value: input.tokenAVault ?? null, isWritable: true },
@@ -203,6 +231,8 @@ export async function getSkimInstructionAsync<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -224,6 +254,18 @@ export async function getSkimInstructionAsync<
       ],
     });
   }
+  if (!accounts.globalState.value) {
+    accounts.globalState.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 115, 116, 97, 116, 101,
+          ]),
+        ),
+      ],
+    });
+  }
   if (!accounts.tokenAVault.value) {
     accounts.tokenAVault.value = await getProgramDerivedAddress({
       programAddress:
@@ -284,12 +326,26 @@ export async function getSkimInstructionAsync<
       ],
     });
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.tokenAMint),
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.tokenAVault),
@@ -297,6 +353,8 @@ export async function getSkimInstructionAsync<
       getAccountMeta(accounts.tokenASignerTokenAccount),
       getAccountMeta(accounts.tokenBSignerTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSkimInstructionDataEncoder().encode({}),
     programAddress,
@@ -304,19 +362,23 @@ export async function getSkimInstructionAsync<
     TProgramAddress,
     TAccountSigner,
     TAccountLiquidityPool,
+    TAccountGlobalState,
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
     TAccountTokenASignerTokenAccount,
     TAccountTokenBSignerTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type SkimInput<
   TAccountSigner extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountTokenAMint extends string = string,
   TAccountTokenBMint extends string = string,
   TAccountTokenAVault extends string = string,
@@ -324,9 +386,12 @@ export type SkimInput<
   TAccountTokenASignerTokenAccount extends string = string,
   TAccountTokenBSignerTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  globalState: Address<TAccountGlobalState>;
   tokenAMint: Address<TAccountTokenAMint>;
   tokenBMint: Address<TAccountTokenBMint>;
   tokenAVault: Address<TAccountTokenAVault>;
@@ -334,11 +399,14 @@ export type SkimInput<
   tokenASignerTokenAccount: Address<TAccountTokenASignerTokenAccount>;
   tokenBSignerTokenAccount: Address<TAccountTokenBSignerTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export function getSkimInstruction<
   TAccountSigner extends string,
   TAccountLiquidityPool extends string,
+  TAccountGlobalState extends string,
   TAccountTokenAMint extends string,
   TAccountTokenBMint extends string,
   TAccountTokenAVault extends string,
@@ -346,31 +414,39 @@ export function getSkimInstruction<
   TAccountTokenASignerTokenAccount extends string,
   TAccountTokenBSignerTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: SkimInput<
     TAccountSigner,
     TAccountLiquidityPool,
+    TAccountGlobalState,
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
     TAccountTokenASignerTokenAccount,
     TAccountTokenBSignerTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): SkimInstruction<
   TProgramAddress,
   TAccountSigner,
   TAccountLiquidityPool,
+  TAccountGlobalState,
   TAccountTokenAMint,
   TAccountTokenBMint,
   TAccountTokenAVault,
   TAccountTokenBVault,
   TAccountTokenASignerTokenAccount,
   TAccountTokenBSignerTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ANCHOR_AMM_PROGRAM_ADDRESS;
@@ -379,6 +455,7 @@ export function getSkimInstruction<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     tokenAMint: { value: input.tokenAMint ?? null, isWritable: false },
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     tokenAVault: { value: input.tokenAVault ?? null, isWritable: true },
@@ -392,6 +469,8 @@ export function getSkimInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -409,6 +488,7 @@ export function getSkimInstruction<
     accounts: [
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.tokenAMint),
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.tokenAVault),
@@ -416,6 +496,8 @@ export function getSkimInstruction<
       getAccountMeta(accounts.tokenASignerTokenAccount),
       getAccountMeta(accounts.tokenBSignerTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSkimInstructionDataEncoder().encode({}),
     programAddress,
@@ -423,13 +505,16 @@ export function getSkimInstruction<
     TProgramAddress,
     TAccountSigner,
     TAccountLiquidityPool,
+    TAccountGlobalState,
     TAccountTokenAMint,
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
     TAccountTokenASignerTokenAccount,
     TAccountTokenBSignerTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -441,13 +526,16 @@ export type ParsedSkimInstruction<
   accounts: {
     signer: TAccountMetas[0];
     liquidityPool: TAccountMetas[1];
-    tokenAMint: TAccountMetas[2];
-    tokenBMint: TAccountMetas[3];
-    tokenAVault: TAccountMetas[4];
-    tokenBVault: TAccountMetas[5];
-    tokenASignerTokenAccount: TAccountMetas[6];
-    tokenBSignerTokenAccount: TAccountMetas[7];
-    tokenProgram: TAccountMetas[8];
+    globalState: TAccountMetas[2];
+    tokenAMint: TAccountMetas[3];
+    tokenBMint: TAccountMetas[4];
+    tokenAVault: TAccountMetas[5];
+    tokenBVault: TAccountMetas[6];
+    tokenASignerTokenAccount: TAccountMetas[7];
+    tokenBSignerTokenAccount: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: SkimInstructionData;
 };
@@ -460,7 +548,7 @@ export function parseSkimInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedSkimInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -475,6 +563,7 @@ export function parseSkimInstruction<
     accounts: {
       signer: getNextAccount(),
       liquidityPool: getNextAccount(),
+      globalState: getNextAccount(),
       tokenAMint: getNextAccount(),
       tokenBMint: getNextAccount(),
       tokenAVault: getNextAccount(),
@@ -482,6 +571,8 @@ export function parseSkimInstruction<
       tokenASignerTokenAccount: getNextAccount(),
       tokenBSignerTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSkimInstructionDataDecoder().decode(instruction.data),
   };
@@ -18,6 +18,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -62,12 +64,17 @@ export type SwapInstruction<
   TAccountToken1Mint extends string | AccountMeta<string> = string,
   TAccountLpTokenMint extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountPoolStats extends string | AccountMeta<string> = string,
+  TAccountGlobalState extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
   TAccountAssociatedTokenProgram extends string | AccountMeta<string> =
     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
+  TAccountReferrerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -101,6 +108,12 @@ export type SwapInstruction<
       TAccountLiquidityPool extends string
         ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountPoolStats extends string
+        ? WritableAccount<TAccountPoolStats>
+        : TAccountPoolStats,
+      TAccountGlobalState extends string
+        ? ReadonlyAccount<TAccountGlobalState>
+        : TAccountGlobalState,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -110,6 +123,15 @@ export type SwapInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountReferrerTokenAccount extends string
+        ? WritableAccount<TAccountReferrerTokenAccount>
+        : TAccountReferrerTokenAccount,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -119,12 +141,14 @@ export type SwapInstructionData = {
   token0Amount: bigint;
   token1MinAmount: bigint;
   expiration: bigint;
+  referralFeeBps: number;
 };
 
 export type SwapInstructionDataArgs = {
   token0Amount: number | bigint;
   token1MinAmount: number | bigint;
   expiration: number | bigint;
+  referralFeeBps: number;
 };
 
 export function getSwapInstructionDataEncoder(): FixedSizeEncoder<SwapInstructionDataArgs> {
@@ -134,6 +158,7 @@ export function getSwapInstructionDataEncoder(): FixedSizeEncoder<SwapInstructio
       ["token0Amount", getU64Encoder()],
       ["token1MinAmount", getU64Encoder()],
       ["expiration", getI64Encoder()],
+      ["referralFeeBps", getU16Encoder()],
     ]),
     (value) => ({ ...value, discriminator: SWAP_DISCRIMINATOR }),
   );
@@ -145,6 +170,7 @@ export function getSwapInstructionDataDecoder(): FixedSizeDecoder<SwapInstructio
     ["token0Amount", getU64Decoder()],
     ["token1MinAmount", getU64Decoder()],
     ["expiration", getI64Decoder()],
+    ["referralFeeBps", getU16Decoder()],
   ]);
 }
 
@@ -168,25 +194,40 @@ export type SwapAsyncInput<
   TAccountToken1Mint extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   token0SignerTokenAccount?: Address<TAccountToken0SignerTokenAccount>;
   token1SignerTokenAccount?: Address<TAccountToken1SignerTokenAccount>;
-  token0Vault?: Address<TAccountToken0Vault>;
-  token1Vault?: Address<TAccountToken1Vault>;
+  /** The pool's vault for `token_0_mint` */
+  token0Vault: Address<TAccountToken0Vault>;
+  /** The pool's vault for `token_1_mint` */
+  token1Vault: Address<TAccountToken1Vault>;
   token0Mint: Address<TAccountToken0Mint>;
   token1Mint: Address<TAccountToken1Mint>;
+  /** The pool's LP mint */
   lpTokenMint: Address<TAccountLpTokenMint>;
   liquidityPool?: Address<TAccountLiquidityPool>;
+  poolStats?: Address<TAccountPoolStats>;
+  globalState?: Address<TAccountGlobalState>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Receives the referral fee, in the input mint */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   token0Amount: SwapInstructionDataArgs["token0Amount"];
   token1MinAmount: SwapInstructionDataArgs["token1MinAmount"];
   expiration: SwapInstructionDataArgs["expiration"];
+  referralFeeBps: SwapInstructionDataArgs["referralFeeBps"];
 };
 
 export async function getSwapInstructionAsync<
@@ -199,9 +240,14 @@ export async function getSwapInstructionAsync<
   TAccountToken1Mint extends string,
   TAccountLpTokenMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
+  TAccountGlobalState extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: SwapAsyncInput<
@@ -214,9 +260,14 @@ export async function getSwapInstructionAsync<
     TAccountToken1Mint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReferrerTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -231,9 +282,14 @@ export async function getSwapInstructionAsync<
     TAccountToken1Mint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReferrerTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -256,12 +312,20 @@ export async function getSwapInstructionAsync<
     token1Mint: { value: input.token1Mint ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -312,37 +376,26 @@ export async function getSwapInstructionAsync<
       ],
     });
   }
-  if (!accounts.token0Vault.value) {
-    accounts.token0Vault.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+  if (!accounts.poolStats.value) {
+    accounts.poolStats.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ]),
+          new Uint8Array([112, 111, 111, 108, 95, 115, 116, 97, 116, 115]),
         ),
-        getAddressEncoder().encode(expectAddress(accounts.token0Mint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
       ],
     });
   }
-  if (!accounts.token1Vault.value) {
-    accounts.token1Vault.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+  if (!accounts.globalState.value) {
+    accounts.globalState.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
         getBytesEncoder().encode(
           new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
+            103, 108, 111, 98, 97, 108, 95, 115, 116, 97, 116, 101,
           ]),
         ),
-        getAddressEncoder().encode(expectAddress(accounts.token1Mint.value)),
       ],
     });
   }
@@ -354,6 +407,19 @@ export async function getSwapInstructionAsync<
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -367,9 +433,14 @@ export async function getSwapInstructionAsync<
       getAccountMeta(accounts.token1Mint),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSwapInstructionDataEncoder().encode(
       args as SwapInstructionDataArgs,
@@ -386,9 +457,14 @@ export async function getSwapInstructionAsync<
     TAccountToken1Mint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReferrerTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -402,25 +478,40 @@ export type SwapInput<
   TAccountToken1Mint extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   token0SignerTokenAccount: Address<TAccountToken0SignerTokenAccount>;
   token1SignerTokenAccount: Address<TAccountToken1SignerTokenAccount>;
+  /** The pool's vault for `token_0_mint` */
   token0Vault: Address<TAccountToken0Vault>;
+  /** The pool's vault for `token_1_mint` */
   token1Vault: Address<TAccountToken1Vault>;
   token0Mint: Address<TAccountToken0Mint>;
   token1Mint: Address<TAccountToken1Mint>;
+  /** The pool's LP mint */
   lpTokenMint: Address<TAccountLpTokenMint>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  poolStats: Address<TAccountPoolStats>;
+  globalState: Address<TAccountGlobalState>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Receives the referral fee, in the input mint */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   token0Amount: SwapInstructionDataArgs["token0Amount"];
   token1MinAmount: SwapInstructionDataArgs["token1MinAmount"];
   expiration: SwapInstructionDataArgs["expiration"];
+  referralFeeBps: SwapInstructionDataArgs["referralFeeBps"];
 };
 
 export function getSwapInstruction<
@@ -433,9 +524,14 @@ export function getSwapInstruction<
   TAccountToken1Mint extends string,
   TAccountLpTokenMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
+  TAccountGlobalState extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: SwapInput<
@@ -448,9 +544,14 @@ export function getSwapInstruction<
     TAccountToken1Mint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReferrerTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): SwapInstruction<
@@ -464,9 +565,14 @@ export function getSwapInstruction<
   TAccountToken1Mint,
   TAccountLpTokenMint,
   TAccountLiquidityPool,
+  TAccountPoolStats,
+  TAccountGlobalState,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountReferrerTokenAccount,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ANCHOR_AMM_PROGRAM_ADDRESS;
@@ -488,12 +594,20 @@ export function getSwapInstruction<
     token1Mint: { value: input.token1Mint ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -529,9 +643,14 @@ export function getSwapInstruction<
       getAccountMeta(accounts.token1Mint),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSwapInstructionDataEncoder().encode(
       args as SwapInstructionDataArgs,
@@ -548,9 +667,14 @@ export function getSwapInstruction<
     TAccountToken1Mint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReferrerTokenAccount,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -563,15 +687,24 @@ export type ParsedSwapInstruction<
     signer: TAccountMetas[0];
     token0SignerTokenAccount: TAccountMetas[1];
     token1SignerTokenAccount: TAccountMetas[2];
+    /** The pool's vault for `token_0_mint` */
     token0Vault: TAccountMetas[3];
+    /** The pool's vault for `token_1_mint` */
     token1Vault: TAccountMetas[4];
     token0Mint: TAccountMetas[5];
     token1Mint: TAccountMetas[6];
+    /** The pool's LP mint */
     lpTokenMint: TAccountMetas[7];
     liquidityPool: TAccountMetas[8];
-    tokenProgram: TAccountMetas[9];
-    associatedTokenProgram: TAccountMetas[10];
-    systemProgram: TAccountMetas[11];
+    poolStats: TAccountMetas[9];
+    globalState: TAccountMetas[10];
+    tokenProgram: TAccountMetas[11];
+    associatedTokenProgram: TAccountMetas[12];
+    systemProgram: TAccountMetas[13];
+    /** Receives the referral fee, in the input mint */
+    referrerTokenAccount?: TAccountMetas[14] | undefined;
+    eventAuthority: TAccountMetas[15];
+    program: TAccountMetas[16];
   };
   data: SwapInstructionData;
 };
@@ -584,7 +717,7 @@ export function parseSwapInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedSwapInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -594,6 +727,12 @@ export function parseSwapInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ANCHOR_AMM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -606,9 +745,14 @@ export function parseSwapInstruction<
       token1Mint: getNextAccount(),
       lpTokenMint: getNextAccount(),
       liquidityPool: getNextAccount(),
+      poolStats: getNextAccount(),
+      globalState: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      referrerTokenAccount: getNextOptionalAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSwapInstructionDataDecoder().decode(instruction.data),
   };
@@ -51,6 +51,8 @@ export type SyncInstruction<
   TAccountTokenBVault extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,6 +76,12 @@ export type SyncInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -112,6 +120,8 @@ export type SyncAsyncInput<
   TAccountTokenAVault extends string = string,
   TAccountTokenBVault extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   liquidityPool?: Address<TAccountLiquidityPool>;
   tokenAMint: Address<TAccountTokenAMint>;
@@ -119,6 +129,8 @@ export type SyncAsyncInput<
   tokenAVault?: Address<TAccountTokenAVault>;
   tokenBVault?: Address<TAccountTokenBVault>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export async function getSyncInstructionAsync<
@@ -128,6 +140,8 @@ export async function getSyncInstructionAsync<
   TAccountTokenAVault extends string,
   TAccountTokenBVault extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: SyncAsyncInput<
@@ -136,7 +150,9 @@ export async function getSyncInstructionAsync<
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -147,7 +163,9 @@ export async function getSyncInstructionAsync<
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -161,6 +179,8 @@ export async function getSyncInstructionAsync<
     tokenAVault: { value: input.tokenAVault ?? null, isWritable: true },
     tokenBVault: { value: input.tokenBVault ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -220,6 +240,19 @@ export async function getSyncInstructionAsync<
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -230,6 +263,8 @@ export async function getSyncInstructionAsync<
       getAccountMeta(accounts.tokenAVault),
       getAccountMeta(accounts.tokenBVault),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSyncInstructionDataEncoder().encode({}),
     programAddress,
@@ -240,7 +275,9 @@ export async function getSyncInstructionAsync<
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -251,6 +288,8 @@ export type SyncInput<
   TAccountTokenAVault extends string = string,
   TAccountTokenBVault extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   liquidityPool: Address<TAccountLiquidityPool>;
   tokenAMint: Address<TAccountTokenAMint>;
@@ -258,6 +297,8 @@ export type SyncInput<
   tokenAVault: Address<TAccountTokenAVault>;
   tokenBVault: Address<TAccountTokenBVault>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export function getSyncInstruction<
@@ -267,6 +308,8 @@ export function getSyncInstruction<
   TAccountTokenAVault extends string,
   TAccountTokenBVault extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: SyncInput<
@@ -275,7 +318,9 @@ export function getSyncInstruction<
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): SyncInstruction<
@@ -285,7 +330,9 @@ export function getSyncInstruction<
   TAccountTokenBMint,
   TAccountTokenAVault,
   TAccountTokenBVault,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ANCHOR_AMM_PROGRAM_ADDRESS;
@@ -298,6 +345,8 @@ export function getSyncInstruction<
     tokenAVault: { value: input.tokenAVault ?? null, isWritable: true },
     tokenBVault: { value: input.tokenBVault ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -319,6 +368,8 @@ export function getSyncInstruction<
       getAccountMeta(accounts.tokenAVault),
       getAccountMeta(accounts.tokenBVault),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSyncInstructionDataEncoder().encode({}),
     programAddress,
@@ -329,7 +380,9 @@ export function getSyncInstruction<
     TAccountTokenBMint,
     TAccountTokenAVault,
     TAccountTokenBVault,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -345,6 +398,8 @@ export type ParsedSyncInstruction<
     tokenAVault: TAccountMetas[3];
     tokenBVault: TAccountMetas[4];
     tokenProgram: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: SyncInstructionData;
 };
@@ -357,7 +412,7 @@ export function parseSyncInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedSyncInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -376,6 +431,8 @@ export function parseSyncInstruction<
       tokenAVault: getNextAccount(),
       tokenBVault: getNextAccount(),
       tokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSyncInstructionDataDecoder().decode(instruction.data),
   };
@@ -64,6 +64,8 @@ export type WithdrawInstruction<
   TAccountTokenBMint extends string | AccountMeta<string> = string,
   TAccountLpTokenMint extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountPoolStats extends string | AccountMeta<string> = string,
+  TAccountGlobalState extends string | AccountMeta<string> = string,
   TAccountFeeLpTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
@@ -71,6 +73,8 @@ export type WithdrawInstruction<
     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -107,6 +111,12 @@ export type WithdrawInstruction<
       TAccountLiquidityPool extends string
         ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountPoolStats extends string
+        ? WritableAccount<TAccountPoolStats>
+        : TAccountPoolStats,
+      TAccountGlobalState extends string
+        ? ReadonlyAccount<TAccountGlobalState>
+        : TAccountGlobalState,
       TAccountFeeLpTokenAccount extends string
         ? WritableAccount<TAccountFeeLpTokenAccount>
         : TAccountFeeLpTokenAccount,
@@ -119,6 +129,12 @@ export type WithdrawInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -182,25 +198,33 @@ export type WithdrawAsyncInput<
   TAccountTokenBMint extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountFeeLpTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   lpTokenSignerTokenAccount?: Address<TAccountLpTokenSignerTokenAccount>;
   tokenASignerTokenAccount?: Address<TAccountTokenASignerTokenAccount>;
   tokenBSignerTokenAccount?: Address<TAccountTokenBSignerTokenAccount>;
-  tokenAVault?: Address<TAccountTokenAVault>;
-  tokenBVault?: Address<TAccountTokenBVault>;
+  tokenAVault: Address<TAccountTokenAVault>;
+  tokenBVault: Address<TAccountTokenBVault>;
   tokenAMint: Address<TAccountTokenAMint>;
   tokenBMint: Address<TAccountTokenBMint>;
-  lpTokenMint?: Address<TAccountLpTokenMint>;
+  lpTokenMint: Address<TAccountLpTokenMint>;
   liquidityPool?: Address<TAccountLiquidityPool>;
-  feeLpTokenAccount?: Address<TAccountFeeLpTokenAccount>;
+  poolStats?: Address<TAccountPoolStats>;
+  globalState?: Address<TAccountGlobalState>;
+  feeLpTokenAccount: Address<TAccountFeeLpTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   lpAmountToBurn: WithdrawInstructionDataArgs["lpAmountToBurn"];
   amountAMin: WithdrawInstructionDataArgs["amountAMin"];
   amountBMin: WithdrawInstructionDataArgs["amountBMin"];
@@ -218,10 +242,14 @@ export async function getWithdrawInstructionAsync<
   TAccountTokenBMint extends string,
   TAccountLpTokenMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
+  TAccountGlobalState extends string,
   TAccountFeeLpTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: WithdrawAsyncInput<
@@ -235,10 +263,14 @@ export async function getWithdrawInstructionAsync<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -254,10 +286,14 @@ export async function getWithdrawInstructionAsync<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -284,6 +320,8 @@ export async function getWithdrawInstructionAsync<
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     feeLpTokenAccount: {
       value: input.feeLpTokenAccount ?? null,
       isWritable: true,
@@ -294,6 +332,8 @@ export async function getWithdrawInstructionAsync<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -308,20 +348,6 @@ export async function getWithdrawInstructionAsync<
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
   }
-  if (!accounts.lpTokenMint.value) {
-    accounts.lpTokenMint.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            108, 112, 95, 116, 111, 107, 101, 110, 95, 109, 105, 110, 116,
-          ]),
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenAMint.value)),
-        getAddressEncoder().encode(expectAddress(accounts.tokenBMint.value)),
-      ],
-    });
-  }
   if (!accounts.lpTokenSignerTokenAccount.value) {
     accounts.lpTokenSignerTokenAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -369,48 +395,26 @@ export async function getWithdrawInstructionAsync<
       ],
     });
   }
-  if (!accounts.tokenAVault.value) {
-    accounts.tokenAVault.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+  if (!accounts.poolStats.value) {
+    accounts.poolStats.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ]),
+          new Uint8Array([112, 111, 111, 108, 95, 115, 116, 97, 116, 115]),
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenAMint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
       ],
     });
   }
-  if (!accounts.tokenBVault.value) {
-    accounts.tokenBVault.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+  if (!accounts.globalState.value) {
+    accounts.globalState.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
         getBytesEncoder().encode(
           new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
+            103, 108, 111, 98, 97, 108, 95, 115, 116, 97, 116, 101,
           ]),
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenBMint.value)),
-      ],
-    });
-  }
-  if (!accounts.feeLpTokenAccount.value) {
-    accounts.feeLpTokenAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
-      seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.liquidityPool.value)),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
-        getAddressEncoder().encode(expectAddress(accounts.lpTokenMint.value)),
       ],
     });
   }
@@ -422,6 +426,19 @@ export async function getWithdrawInstructionAsync<
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -436,10 +453,14 @@ export async function getWithdrawInstructionAsync<
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.feeLpTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getWithdrawInstructionDataEncoder().encode(
       args as WithdrawInstructionDataArgs,
@@ -457,10 +478,14 @@ export async function getWithdrawInstructionAsync<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -475,10 +500,14 @@ export type WithdrawInput<
   TAccountTokenBMint extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountPoolStats extends string = string,
+  TAccountGlobalState extends string = string,
   TAccountFeeLpTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   lpTokenSignerTokenAccount: Address<TAccountLpTokenSignerTokenAccount>;
@@ -490,10 +519,14 @@ export type WithdrawInput<
   tokenBMint: Address<TAccountTokenBMint>;
   lpTokenMint: Address<TAccountLpTokenMint>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  poolStats: Address<TAccountPoolStats>;
+  globalState: Address<TAccountGlobalState>;
   feeLpTokenAccount: Address<TAccountFeeLpTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   lpAmountToBurn: WithdrawInstructionDataArgs["lpAmountToBurn"];
   amountAMin: WithdrawInstructionDataArgs["amountAMin"];
   amountBMin: WithdrawInstructionDataArgs["amountBMin"];
@@ -511,10 +544,14 @@ export function getWithdrawInstruction<
   TAccountTokenBMint extends string,
   TAccountLpTokenMint extends string,
   TAccountLiquidityPool extends string,
+  TAccountPoolStats extends string,
+  TAccountGlobalState extends string,
   TAccountFeeLpTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ANCHOR_AMM_PROGRAM_ADDRESS,
 >(
   input: WithdrawInput<
@@ -528,10 +565,14 @@ export function getWithdrawInstruction<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): WithdrawInstruction<
@@ -546,10 +587,14 @@ export function getWithdrawInstruction<
   TAccountTokenBMint,
   TAccountLpTokenMint,
   TAccountLiquidityPool,
+  TAccountPoolStats,
+  TAccountGlobalState,
   TAccountFeeLpTokenAccount,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ANCHOR_AMM_PROGRAM_ADDRESS;
@@ -575,6 +620,8 @@ export function getWithdrawInstruction<
     tokenBMint: { value: input.tokenBMint ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    poolStats: { value: input.poolStats ?? null, isWritable: true },
+    globalState: { value: input.globalState ?? null, isWritable: false },
     feeLpTokenAccount: {
       value: input.feeLpTokenAccount ?? null,
       isWritable: true,
@@ -585,6 +632,8 @@ export function getWithdrawInstruction<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -621,10 +670,14 @@ export function getWithdrawInstruction<
       getAccountMeta(accounts.tokenBMint),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.poolStats),
+      getAccountMeta(accounts.globalState),
       getAccountMeta(accounts.feeLpTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getWithdrawInstructionDataEncoder().encode(
       args as WithdrawInstructionDataArgs,
@@ -642,10 +695,14 @@ export function getWithdrawInstruction<
     TAccountTokenBMint,
     TAccountLpTokenMint,
     TAccountLiquidityPool,
+    TAccountPoolStats,
+    TAccountGlobalState,
     TAccountFeeLpTokenAccount,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -665,10 +722,14 @@ export type ParsedWithdrawInstruction<
     tokenBMint: TAccountMetas[7];
     lpTokenMint: TAccountMetas[8];
     liquidityPool: TAccountMetas[9];
-    feeLpTokenAccount: TAccountMetas[10];
-    tokenProgram: TAccountMetas[11];
-    associatedTokenProgram: TAccountMetas[12];
-    systemProgram: TAccountMetas[13];
+    poolStats: TAccountMetas[10];
+    globalState: TAccountMetas[11];
+    feeLpTokenAccount: TAccountMetas[12];
+    tokenProgram: TAccountMetas[13];
+    associatedTokenProgram: TAccountMetas[14];
+    systemProgram: TAccountMetas[15];
+    eventAuthority: TAccountMetas[16];
+    program: TAccountMetas[17];
   };
   data: WithdrawInstructionData;
 };
@@ -681,7 +742,7 @@ export function parseWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 18) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -704,10 +765,14 @@ export function parseWithdrawInstruction<
       tokenBMint: getNextAccount(),
       lpTokenMint: getNextAccount(),
       liquidityPool: getNextAccount(),
+      poolStats: getNextAccount(),
+      globalState: getNextAccount(),
       feeLpTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getWithdrawInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,19 +14,60 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
+  type ParsedClaimCreatorFeesInstruction,
+  type ParsedClosePoolInstruction,
+  type ParsedCollectFeesInstruction,
+  type ParsedDecreaseLiquidityInstruction,
   type ParsedDepositInstruction,
+  type ParsedDepositMultiAssetInstruction,
+  type ParsedDepositMultiAssetSingleInstruction,
+  type ParsedIncreaseLiquidityInstruction,
+  type ParsedInitializeConcentratedPoolInstruction,
+  type ParsedInitializeConstantSumPoolInstruction,
+  type ParsedInitializeGlobalStateInstruction,
+  type ParsedInitializeLbpInstruction,
+  type ParsedInitializeMultiAssetPoolInstruction,
+  type ParsedInitializePmmPoolInstruction,
   type ParsedInitializePoolInstruction,
+  type ParsedInitializeStablePoolInstruction,
+  type ParsedInitializeTickArrayInstruction,
+  type ParsedInitializeWeightedPoolInstruction,
+  type ParsedMigratePoolInstruction,
+  type ParsedOpenPositionInstruction,
+  type ParsedQuoteDepositInstruction,
+  type ParsedQuoteSwapInstruction,
+  type ParsedRampAmpInstruction,
+  type ParsedSetDynamicFeeInstruction,
+  type ParsedSetGlobalPauseInstruction,
+  type ParsedSetLaunchParamsInstruction,
+  type ParsedSetPoolPauseInstruction,
+  type ParsedSetPriceBandInstruction,
+  type ParsedSetReferenceOracleInstruction,
+  type ParsedSetSlotPriceMoveLimitInstruction,
   type ParsedSkimInstruction,
+  type ParsedStopRampAmpInstruction,
+  type ParsedSwapConcentratedInstruction,
   type ParsedSwapInstruction,
+  type ParsedSwapMultiAssetInstruction,
+  type ParsedSwapV2Instruction,
   type ParsedSyncInstruction,
+  type ParsedTransferCreatorInstruction,
   type ParsedWithdrawInstruction,
+  type ParsedWithdrawMultiAssetInstruction,
+  type ParsedWithdrawMultiAssetSingleInstruction,
 } from "../instructions";
 
 export const ANCHOR_AMM_PROGRAM_ADDRESS =
   "2sU17QtTHDBCr4Q3pZGVUjE71fFyfjiuAjVGqRP91Be5" as Address<"2sU17QtTHDBCr4Q3pZGVUjE71fFyfjiuAjVGqRP91Be5">;
 
 export enum AnchorAmmAccount {
+  ConcentratedPool,
+  GlobalState,
   LiquidityPool,
+  MultiAssetPool,
+  PoolStats,
+  Position,
+  TickArray,
 }
 
 export function identifyAnchorAmmAccount(
@@ -36,30 +77,157 @@ export function identifyAnchorAmmAccount(
   if (
     containsBytes(
       data,
-      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([1])),
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([3])),
+      0,
+    )
+  ) {
+    return AnchorAmmAccount.ConcentratedPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([7])),
+      0,
+    )
+  ) {
+    return AnchorAmmAccount.GlobalState;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([1, 0, 0, 0, 0, 0, 0, 0]),
+      ),
       0,
     )
   ) {
     return AnchorAmmAccount.LiquidityPool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([6])),
+      0,
+    )
+  ) {
+    return AnchorAmmAccount.MultiAssetPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([2])),
+      0,
+    )
+  ) {
+    return AnchorAmmAccount.PoolStats;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([5])),
+      0,
+    )
+  ) {
+    return AnchorAmmAccount.Position;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([4])),
+      0,
+    )
+  ) {
+    return AnchorAmmAccount.TickArray;
+  }
   throw new Error(
     "The provided account could not be identified as a anchorAmm account.",
   );
 }
 
 export enum AnchorAmmInstruction {
+  ClaimCreatorFees,
+  ClosePool,
+  CollectFees,
+  DecreaseLiquidity,
   Deposit,
+  DepositMultiAsset,
+  DepositMultiAssetSingle,
+  IncreaseLiquidity,
+  InitializeConcentratedPool,
+  InitializeConstantSumPool,
+  InitializeGlobalState,
+  InitializeLbp,
+  InitializeMultiAssetPool,
+  InitializePmmPool,
   InitializePool,
+  InitializeStablePool,
+  InitializeTickArray,
+  InitializeWeightedPool,
+  MigratePool,
+  OpenPosition,
+  QuoteDeposit,
+  QuoteSwap,
+  RampAmp,
+  SetDynamicFee,
+  SetGlobalPause,
+  SetLaunchParams,
+  SetPoolPause,
+  SetPriceBand,
+  SetReferenceOracle,
+  SetSlotPriceMoveLimit,
   Skim,
+  StopRampAmp,
   Swap,
+  SwapConcentrated,
+  SwapMultiAsset,
+  SwapV2,
   Sync,
+  TransferCreator,
   Withdraw,
+  WithdrawMultiAsset,
+  WithdrawMultiAssetSingle,
 }
 
 export function identifyAnchorAmmInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): AnchorAmmInstruction {
   const data = "data" in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([32])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.ClaimCreatorFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([38])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.ClosePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([12])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.CollectFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([11])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.DecreaseLiquidity;
+  }
   if (
     containsBytes(
       data,
@@ -69,6 +237,87 @@ export function identifyAnchorAmmInstruction(
   ) {
     return AnchorAmmInstruction.Deposit;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([20])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.DepositMultiAsset;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([21])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.DepositMultiAssetSingle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([10])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.IncreaseLiquidity;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([7])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeConcentratedPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([24])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeConstantSumPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([35])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeGlobalState;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([31])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeLbp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([18])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeMultiAssetPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([26])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializePmmPool;
+  }
   if (
     containsBytes(
       data,
@@ -78,6 +327,141 @@ export function identifyAnchorAmmInstruction(
   ) {
     return AnchorAmmInstruction.InitializePool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([14])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeStablePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([8])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeTickArray;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([17])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.InitializeWeightedPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([39])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.MigratePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([9])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.OpenPosition;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([34])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.QuoteDeposit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([29])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.QuoteSwap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([15])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.RampAmp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([28])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetDynamicFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([36])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetGlobalPause;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([30])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetLaunchParams;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([37])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetPoolPause;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([25])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetPriceBand;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([27])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetReferenceOracle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([41])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SetSlotPriceMoveLimit;
+  }
   if (
     containsBytes(
       data,
@@ -87,6 +471,15 @@ export function identifyAnchorAmmInstruction(
   ) {
     return AnchorAmmInstruction.Skim;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([16])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.StopRampAmp;
+  }
   if (
     containsBytes(
       data,
@@ -96,6 +489,33 @@ export function identifyAnchorAmmInstruction(
   ) {
     return AnchorAmmInstruction.Swap;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([13])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SwapConcentrated;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([19])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SwapMultiAsset;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([40])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.SwapV2;
+  }
   if (
     containsBytes(
       data,
@@ -105,6 +525,15 @@ export function identifyAnchorAmmInstruction(
   ) {
     return AnchorAmmInstruction.Sync;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([33])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.TransferCreator;
+  }
   if (
     containsBytes(
       data,
@@ -114,6 +543,24 @@ export function identifyAnchorAmmInstruction(
   ) {
     return AnchorAmmInstruction.Withdraw;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([22])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.WithdrawMultiAsset;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([23])),
+      0,
+    )
+  ) {
+    return AnchorAmmInstruction.WithdrawMultiAssetSingle;
+  }
   throw new Error(
     "The provided instruction could not be identified as a anchorAmm instruction.",
   );
@@ -122,21 +569,126 @@ export function identifyAnchorAmmInstruction(
 export type ParsedAnchorAmmInstruction<
   TProgram extends string = "2sU17QtTHDBCr4Q3pZGVUjE71fFyfjiuAjVGqRP91Be5",
 > =
+  | ({
+      instructionType: AnchorAmmInstruction.ClaimCreatorFees;
+    } & ParsedClaimCreatorFeesInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.ClosePool;
+    } & ParsedClosePoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.CollectFees;
+    } & ParsedCollectFeesInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.DecreaseLiquidity;
+    } & ParsedDecreaseLiquidityInstruction<TProgram>)
   | ({
       instructionType: AnchorAmmInstruction.Deposit;
     } & ParsedDepositInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.DepositMultiAsset;
+    } & ParsedDepositMultiAssetInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.DepositMultiAssetSingle;
+    } & ParsedDepositMultiAssetSingleInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.IncreaseLiquidity;
+    } & ParsedIncreaseLiquidityInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeConcentratedPool;
+    } & ParsedInitializeConcentratedPoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeConstantSumPool;
+    } & ParsedInitializeConstantSumPoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeGlobalState;
+    } & ParsedInitializeGlobalStateInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeLbp;
+    } & ParsedInitializeLbpInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeMultiAssetPool;
+    } & ParsedInitializeMultiAssetPoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializePmmPool;
+    } & ParsedInitializePmmPoolInstruction<TProgram>)
   | ({
       instructionType: AnchorAmmInstruction.InitializePool;
     } & ParsedInitializePoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeStablePool;
+    } & ParsedInitializeStablePoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeTickArray;
+    } & ParsedInitializeTickArrayInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.InitializeWeightedPool;
+    } & ParsedInitializeWeightedPoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.MigratePool;
+    } & ParsedMigratePoolInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.OpenPosition;
+    } & ParsedOpenPositionInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.QuoteDeposit;
+    } & ParsedQuoteDepositInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.QuoteSwap;
+    } & ParsedQuoteSwapInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.RampAmp;
+    } & ParsedRampAmpInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetDynamicFee;
+    } & ParsedSetDynamicFeeInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetGlobalPause;
+    } & ParsedSetGlobalPauseInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetLaunchParams;
+    } & ParsedSetLaunchParamsInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetPoolPause;
+    } & ParsedSetPoolPauseInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetPriceBand;
+    } & ParsedSetPriceBandInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetReferenceOracle;
+    } & ParsedSetReferenceOracleInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SetSlotPriceMoveLimit;
+    } & ParsedSetSlotPriceMoveLimitInstruction<TProgram>)
   | ({
       instructionType: AnchorAmmInstruction.Skim;
     } & ParsedSkimInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.StopRampAmp;
+    } & ParsedStopRampAmpInstruction<TProgram>)
   | ({
       instructionType: AnchorAmmInstruction.Swap;
     } & ParsedSwapInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SwapConcentrated;
+    } & ParsedSwapConcentratedInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SwapMultiAsset;
+    } & ParsedSwapMultiAssetInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.SwapV2;
+    } & ParsedSwapV2Instruction<TProgram>)
   | ({
       instructionType: AnchorAmmInstruction.Sync;
     } & ParsedSyncInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.TransferCreator;
+    } & ParsedTransferCreatorInstruction<TProgram>)
   | ({
       instructionType: AnchorAmmInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>);
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.WithdrawMultiAsset;
+    } & ParsedWithdrawMultiAssetInstruction<TProgram>)
+  | ({
+      instructionType: AnchorAmmInstruction.WithdrawMultiAssetSingle;
+    } & ParsedWithdrawMultiAssetSingleInstruction<TProgram>);
@@ -10,25 +10,144 @@ use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
 
+/// A two-token pool, loaded zero-copy through `AccountLoader`.
+///
+/// The discriminator is padded to 8 bytes so the fields stay aligned, and the
+/// fields are grouped by size so the layout has no implicit padding.
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidityPool {
-pub discriminator: [u8; 1],
+pub discriminator: [u8; 8],
+/// `SwapCurve::invariant` after the last liquidity event
+pub k_last: u128,
+/// Raw token B units per raw token A unit (WAD) as of the last PMM oracle refresh
+pub pmm_oracle_price: u128,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub token_a_mint: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub token_b_mint: Pubkey,
+/// Allowed to ramp the amplification coefficient and set the price band
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub admin: Pubkey,
+/// Pyth-style price account quoting token A in token B: the pricing oracle of PMM pools
+/// or the reference oracle guarding swaps on other pools; default for pools without one
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub oracle: Pubkey,
+/// Receives `creator_fee_bps` of swap fees and is the only depositor of liquidity
+/// bootstrapping pools; can hand the role to another key. Default for PMM pools,
+/// which earn no creator fees
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub creator: Pubkey,
+/// Paid the rent of the pool's accounts, refunded by `close_pool`
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub payer: Pubkey,
+/// LP token mint, the PDA `["lp_token_mint", token_a_mint, token_b_mint]`
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub lp_mint: Pubkey,
+/// Pool-owned token account holding the token A reserves
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub token_a_vault: Pubkey,
+/// Pool-owned token account holding the token B reserves
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub token_b_vault: Pubkey,
+/// Protocol fee LP token account owned by the pool PDA
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub fee_lp_token_account: Pubkey,
 pub token_a_reserves: u64,
 pub token_b_reserves: u64,
-pub k_last: u128,
+/// StableSwap amplification at `amp_ramp_start_ts`; zero for other pools
+pub amp_initial: u64,
+/// StableSwap amplification reached at `amp_ramp_stop_ts`
+pub amp_target: u64,
+pub amp_ramp_start_ts: i64,
+pub amp_ramp_stop_ts: i64,
+/// Scales token A amounts up to the larger of the two mint precisions
+pub token_a_precision_multiplier: u64,
+/// Scales token B amounts up to the larger of the two mint precisions
+pub token_b_precision_multiplier: u64,
+/// Largest accepted age of an oracle price in seconds
+pub oracle_max_age: u64,
+/// PMM liquidity parameter (WAD); zero for other pools
+pub pmm_k: u64,
+/// PMM equilibrium reserves of token A
+pub token_a_target: u64,
+/// PMM equilibrium reserves of token B
+pub token_b_target: u64,
+/// Seconds it takes the volatility accumulator to halve
+pub volatility_half_life: i64,
+/// Recent spot price moves in basis points, summed per swap and decayed over time
+pub volatility_accumulator: u64,
+pub volatility_updated_at: i64,
+/// Swaps fail before this unix timestamp
+pub trading_starts_at: i64,
+pub launch_fee_duration: i64,
+/// Largest output of a single swap while the launch fee decays; zero for no limit
+pub launch_max_buy: u64,
+/// Liquidity bootstrapping weights start moving at this unix timestamp
+pub lbp_start_ts: i64,
+/// Liquidity bootstrapping weights stop moving at this unix timestamp
+pub lbp_end_ts: i64,
+/// Creator fee LP tokens held in the fee account and not yet claimed
+pub creator_fee_lp_tokens: u64,
+/// Weighted pool share of token A in basis points; zero for other pools.
+/// Liquidity bootstrapping pools keep their starting weights here.
+pub weight_a: u16,
+/// Weighted pool share of token B in basis points; zero for other pools
+pub weight_b: u16,
+/// Constant-sum pool band around an even split of the reserves, in basis points; zero disables it
+pub price_band_bps: u16,
+/// Largest accepted oracle confidence interval as a share of the price, in basis points
+pub oracle_max_confidence_bps: u16,
+/// Largest accepted gap between the post-swap spot price and the reference oracle,
+/// in basis points; zero disables the guard
+pub max_oracle_deviation_bps: u16,
+/// Share of the volatility accumulator charged on top of `SWAP_FEE_BPS`, in basis points;
+/// zero disables dynamic fees
+pub volatility_fee_factor_bps: u16,
+/// Cap on the dynamic swap fee in basis points
+pub max_swap_fee_bps: u16,
+/// Swap fee at `trading_starts_at`, decaying linearly to the regular fee over
+/// `launch_fee_duration`; zero for no launch fee
+pub launch_fee_bps: u16,
+/// Liquidity bootstrapping pool weight of token A at `lbp_end_ts`
+pub lbp_end_weight_a: u16,
+/// Creator's share of swap fees in basis points, minted alongside the protocol fee
+pub creator_fee_bps: u16,
+/// Share of the LP tokens minted by a deposit that the depositor doesn't receive
+pub deposit_fee_bps: u16,
 pub bump: u8,
+/// Bump of `lp_mint`, so instructions check and sign for it without searching
+pub lp_mint_bump: u8,
+/// `CurveType` discriminant
+pub curve_type: u8,
+/// Non-zero if withheld deposit fees are minted to the fee account rather than left
+/// unminted, which spreads them over existing LPs
+pub deposit_fee_to_protocol: u8,
+/// `PAUSE_*` flags other than `PAUSE_WITHDRAW` set by the admin, applied on top of
+/// `GlobalState::paused`
+pub paused: u8,
+/// Layout version, `LIQUIDITY_POOL_VERSION` once written by this program
+pub version: u8,
+pub padding: [u8; 12],
+/// Non-zero while an instruction that moves tokens is changing the pool, see `is_locked`
+pub locked: u8,
+pub padding1: [u8; 1],
+/// Largest `slot_price_move_bps` the swaps of a slot may reach; zero disables the limit
+pub max_slot_price_move_bps: u16,
+pub padding2: [u8; 4],
+/// Spot price moves in basis points summed over the swaps of `price_move_slot`
+pub slot_price_move_bps: u64,
+pub price_move_slot: u64,
+/// Zeroed space for future fields, so they don't need a `migrate_pool` realloc
+#[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+pub reserved: [u8; 40],
 }
 
 
-pub const LIQUIDITY_POOL_DISCRIMINATOR: [u8; 1] = [1];
+pub const LIQUIDITY_POOL_DISCRIMINATOR: [u8; 8] = [1, 0, 0, 0, 0, 0, 0, 0];
 
 impl LiquidityPool {
-      pub const LEN: usize = 98;
+      pub const LEN: usize = 632;
   
   
   
@@ -5,7 +5,19 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#concentrated_pool;
+  pub(crate) mod r#global_state;
   pub(crate) mod r#liquidity_pool;
+  pub(crate) mod r#multi_asset_pool;
+  pub(crate) mod r#pool_stats;
+  pub(crate) mod r#position;
+  pub(crate) mod r#tick_array;
 
+  pub use self::r#concentrated_pool::*;
+  pub use self::r#global_state::*;
   pub use self::r#liquidity_pool::*;
+  pub use self::r#multi_asset_pool::*;
+  pub use self::r#pool_stats::*;
+  pub use self::r#position::*;
+  pub use self::r#tick_array::*;
 
@@ -37,6 +37,144 @@ pub enum AnchorAmmError {
     /// 6008 - No excess tokens in vault to skim
     #[error("No excess tokens in vault to skim")]
     NoExcessTokens = 0x1778,
+    /// 6009 - Tick spacing must be between 1 and 16384
+    #[error("Tick spacing must be between 1 and 16384")]
+    InvalidTickSpacing = 0x1779,
+    /// 6010 - Fee rate must be below 10000 bps
+    #[error("Fee rate must be below 10000 bps")]
+    InvalidFeeRate = 0x177a,
+    /// 6011 - Tick index is out of bounds or not a multiple of the tick spacing
+    #[error("Tick index is out of bounds or not a multiple of the tick spacing")]
+    InvalidTickIndex = 0x177b,
+    /// 6012 - Lower tick must be below upper tick
+    #[error("Lower tick must be below upper tick")]
+    InvalidTickRange = 0x177c,
+    /// 6013 - Tick array does not belong to this pool or does not contain the tick
+    #[error("Tick array does not belong to this pool or does not contain the tick")]
+    InvalidTickArray = 0x177d,
+    /// 6014 - Sqrt price is out of bounds
+    #[error("Sqrt price is out of bounds")]
+    SqrtPriceOutOfBounds = 0x177e,
+    /// 6015 - Amplification coefficient must be between 1 and MAX_AMP
+    #[error("Amplification coefficient must be between 1 and MAX_AMP")]
+    InvalidAmp = 0x177f,
+    /// 6016 - Amplification ramp is too short, too steep or started too recently
+    #[error("Amplification ramp is too short, too steep or started too recently")]
+    InvalidAmpRamp = 0x1780,
+    /// 6017 - Operation is only supported on StableSwap pools
+    #[error("Operation is only supported on StableSwap pools")]
+    NotStablePool = 0x1781,
+    /// 6018 - StableSwap invariant did not converge
+    #[error("StableSwap invariant did not converge")]
+    InvariantDidNotConverge = 0x1782,
+    /// 6019 - Weights must each be at least MIN_WEIGHT_BPS and sum to WEIGHT_DENOMINATOR
+    #[error("Weights must each be at least MIN_WEIGHT_BPS and sum to WEIGHT_DENOMINATOR")]
+    InvalidWeights = 0x1783,
+    /// 6020 - Swap input exceeds the maximum share of the input reserve
+    #[error("Swap input exceeds the maximum share of the input reserve")]
+    MaxInRatioExceeded = 0x1784,
+    /// 6021 - Swap output exceeds the maximum share of the output reserve
+    #[error("Swap output exceeds the maximum share of the output reserve")]
+    MaxOutRatioExceeded = 0x1785,
+    /// 6022 - Liquidity bootstrapping schedule must end after it starts
+    #[error("Liquidity bootstrapping schedule must end after it starts")]
+    InvalidLbpSchedule = 0x1786,
+    /// 6023 - Liquidity can only be withdrawn once the bootstrapping period has ended
+    #[error("Liquidity can only be withdrawn once the bootstrapping period has ended")]
+    LbpNotEnded = 0x1787,
+    /// 6024 - Price band must be below half of PRICE_BAND_DENOMINATOR
+    #[error("Price band must be below half of PRICE_BAND_DENOMINATOR")]
+    InvalidPriceBand = 0x1788,
+    /// 6025 - Swap would move the pool outside its price band
+    #[error("Swap would move the pool outside its price band")]
+    PriceBandExceeded = 0x1789,
+    /// 6026 - Operation is only supported on constant-sum pools
+    #[error("Operation is only supported on constant-sum pools")]
+    NotConstantSumPool = 0x178a,
+    /// 6027 - Oracle account is missing or not a valid price account
+    #[error("Oracle account is missing or not a valid price account")]
+    InvalidOracle = 0x178b,
+    /// 6028 - Oracle price is older than the pool's maximum age
+    #[error("Oracle price is older than the pool's maximum age")]
+    StaleOracle = 0x178c,
+    /// 6029 - Oracle confidence interval is wider than the pool allows
+    #[error("Oracle confidence interval is wider than the pool allows")]
+    OracleConfidenceTooWide = 0x178d,
+    /// 6030 - Pool price after the swap deviates too far from the reference oracle
+    #[error("Pool price after the swap deviates too far from the reference oracle")]
+    OraclePriceDeviation = 0x178e,
+    /// 6031 - Maximum oracle deviation must be at most 10000 bps
+    #[error("Maximum oracle deviation must be at most 10000 bps")]
+    InvalidOracleDeviation = 0x178f,
+    /// 6032 - PMM liquidity parameter k must be at most 1 (WAD)
+    #[error("PMM liquidity parameter k must be at most 1 (WAD)")]
+    InvalidPmmK = 0x1790,
+    /// 6033 - Operation is not supported by this pool's curve
+    #[error("Operation is not supported by this pool's curve")]
+    UnsupportedCurveOperation = 0x1791,
+    /// 6034 - Dynamic fee cap must be between SWAP_FEE_BPS and MAX_SWAP_FEE_BPS with a positive half-life
+    #[error("Dynamic fee cap must be between SWAP_FEE_BPS and MAX_SWAP_FEE_BPS with a positive half-life")]
+    InvalidDynamicFee = 0x1792,
+    /// 6035 - Referral fee exceeds MAX_REFERRAL_FEE_BPS or has no referrer token account
+    #[error("Referral fee exceeds MAX_REFERRAL_FEE_BPS or has no referrer token account")]
+    InvalidReferralFee = 0x1793,
+    /// 6036 - Creator fee exceeds MAX_CREATOR_FEE_BPS
+    #[error("Creator fee exceeds MAX_CREATOR_FEE_BPS")]
+    InvalidCreatorFee = 0x1794,
+    /// 6037 - No creator fees to claim
+    #[error("No creator fees to claim")]
+    NoCreatorFees = 0x1795,
+    /// 6038 - Deposit fee exceeds MAX_DEPOSIT_FEE_BPS
+    #[error("Deposit fee exceeds MAX_DEPOSIT_FEE_BPS")]
+    InvalidDepositFee = 0x1796,
+    /// 6039 - Account is not a liquidity pool in a layout migrate_pool can upgrade
+    #[error("Account is not a liquidity pool in a layout migrate_pool can upgrade")]
+    InvalidPoolLayout = 0x1797,
+    /// 6040 - Pool still has liquidity beyond the locked minimum
+    #[error("Pool still has liquidity beyond the locked minimum")]
+    PoolNotEmpty = 0x1798,
+    /// 6041 - Vaults hold more than the dust a closing pool may burn
+    #[error("Vaults hold more than the dust a closing pool may burn")]
+    PoolDustExceeded = 0x1799,
+    /// 6042 - This operation is paused
+    #[error("This operation is paused")]
+    Paused = 0x179a,
+    /// 6043 - Pause flags must be a combination of PAUSE_* flags
+    #[error("Pause flags must be a combination of PAUSE_* flags")]
+    InvalidPauseFlags = 0x179b,
+    /// 6044 - Withdrawals can only be paused globally
+    #[error("Withdrawals can only be paused globally")]
+    PoolWithdrawPause = 0x179c,
+    /// 6045 - Pool is locked by an instruction in progress
+    #[error("Pool is locked by an instruction in progress")]
+    PoolLocked = 0x179d,
+    /// 6046 - Transaction also swaps this pool in the opposite direction
+    #[error("Transaction also swaps this pool in the opposite direction")]
+    OppositeSwapInTransaction = 0x179e,
+    /// 6047 - Swaps in this slot moved the price more than max_slot_price_move_bps
+    #[error("Swaps in this slot moved the price more than max_slot_price_move_bps")]
+    SlotPriceMoveExceeded = 0x179f,
+    /// 6048 - Trading has not started on this pool yet
+    #[error("Trading has not started on this pool yet")]
+    TradingNotStarted = 0x17a0,
+    /// 6049 - Swap output exceeds the launch window's maximum buy
+    #[error("Swap output exceeds the launch window's maximum buy")]
+    LaunchMaxBuyExceeded = 0x17a1,
+    /// 6050 - Launch fee must be at most MAX_LAUNCH_FEE_BPS with a non-negative duration
+    #[error("Launch fee must be at most MAX_LAUNCH_FEE_BPS with a non-negative duration")]
+    InvalidLaunchParams = 0x17a2,
+    /// 6051 - Launch parameters can only be set before the pool holds liquidity
+    #[error("Launch parameters can only be set before the pool holds liquidity")]
+    LaunchAlreadyStarted = 0x17a3,
+    /// 6052 - Multi-asset pools hold between 3 and MAX_MULTI_ASSET_TOKENS tokens
+    #[error("Multi-asset pools hold between 3 and MAX_MULTI_ASSET_TOKENS tokens")]
+    InvalidTokenCount = 0x17a4,
+    /// 6053 - Remaining accounts don't match the pool's tokens
+    #[error("Remaining accounts don't match the pool's tokens")]
+    InvalidRemainingAccounts = 0x17a5,
+    /// 6054 - Signer is not the pool admin
+    #[error("Signer is not the pool admin")]
+    Unauthorized = 0x17a6,
 }
 
 impl From<AnchorAmmError> for solana_program_error::ProgramError {
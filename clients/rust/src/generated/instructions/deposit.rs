@@ -43,6 +43,12 @@ pub struct Deposit {
           
               
           pub liquidity_pool: solana_pubkey::Pubkey,
+          
+              
+          pub pool_stats: solana_pubkey::Pubkey,
+          
+              
+          pub global_state: solana_pubkey::Pubkey,
                 /// Protocol fee LP token account owned by the pool PDA
 
     
@@ -60,6 +66,12 @@ pub struct Deposit {
           
               
           pub lp_token_system_program_token_account: solana_pubkey::Pubkey,
+          
+              
+          pub event_authority: solana_pubkey::Pubkey,
+          
+              
+          pub program: solana_pubkey::Pubkey,
       }
 
 impl Deposit {
@@ -69,7 +81,7 @@ impl Deposit {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: DepositInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(19+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
@@ -109,6 +121,14 @@ impl Deposit {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.pool_stats,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.global_state,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.fee_lp_token_account,
@@ -129,6 +149,14 @@ impl Deposit {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.lp_token_system_program_token_account,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = DepositInstructionData::new().try_to_vec().unwrap();
@@ -198,11 +226,15 @@ impl DepositInstructionArgs {
           ///   7. `[]` token_b_mint
                 ///   8. `[writable]` lp_token_mint
                 ///   9. `[writable]` liquidity_pool
-                ///   10. `[writable]` fee_lp_token_account
-                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   12. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
-                ///   14. `[writable]` lp_token_system_program_token_account
+                ///   10. `[writable]` pool_stats
+          ///   11. `[]` global_state
+                ///   12. `[writable]` fee_lp_token_account
+                ///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   14. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   16. `[writable]` lp_token_system_program_token_account
+          ///   17. `[]` event_authority
+          ///   18. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct DepositBuilder {
             signer: Option<solana_pubkey::Pubkey>,
@@ -215,11 +247,15 @@ pub struct DepositBuilder {
                 token_b_mint: Option<solana_pubkey::Pubkey>,
                 lp_token_mint: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                pool_stats: Option<solana_pubkey::Pubkey>,
+                global_state: Option<solana_pubkey::Pubkey>,
                 fee_lp_token_account: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
                 lp_token_system_program_token_account: Option<solana_pubkey::Pubkey>,
+                event_authority: Option<solana_pubkey::Pubkey>,
+                program: Option<solana_pubkey::Pubkey>,
                         token_a_amount_desired: Option<u64>,
                 token_b_amount_desired: Option<u64>,
                 token_a_amount_min: Option<u64>,
@@ -281,6 +317,16 @@ impl DepositBuilder {
     pub fn liquidity_pool(&mut self, liquidity_pool: solana_pubkey::Pubkey) -> &mut Self {
                         self.liquidity_pool = Some(liquidity_pool);
                     self
+    }
+            #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: solana_pubkey::Pubkey) -> &mut Self {
+                        self.pool_stats = Some(pool_stats);
+                    self
+    }
+            #[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
     }
             /// Protocol fee LP token account owned by the pool PDA
 #[inline(always)]
@@ -310,6 +356,16 @@ impl DepositBuilder {
     pub fn lp_token_system_program_token_account(&mut self, lp_token_system_program_token_account: solana_pubkey::Pubkey) -> &mut Self {
                         self.lp_token_system_program_token_account = Some(lp_token_system_program_token_account);
                     self
+    }
+            #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+                        self.event_authority = Some(event_authority);
+                    self
+    }
+            #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.program = Some(program);
+                    self
     }
                     #[inline(always)]
       pub fn token_a_amount_desired(&mut self, token_a_amount_desired: u64) -> &mut Self {
@@ -361,11 +417,15 @@ impl DepositBuilder {
                                         token_b_mint: self.token_b_mint.expect("token_b_mint is not set"),
                                         lp_token_mint: self.lp_token_mint.expect("lp_token_mint is not set"),
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        pool_stats: self.pool_stats.expect("pool_stats is not set"),
+                                        global_state: self.global_state.expect("global_state is not set"),
                                         fee_lp_token_account: self.fee_lp_token_account.expect("fee_lp_token_account is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                                         lp_token_system_program_token_account: self.lp_token_system_program_token_account.expect("lp_token_system_program_token_account is not set"),
+                                        event_authority: self.event_authority.expect("event_authority is not set"),
+                                        program: self.program.expect("program is not set"),
                       };
           let args = DepositInstructionArgs {
                                                               token_a_amount_desired: self.token_a_amount_desired.clone().expect("token_a_amount_desired is not set"),
@@ -411,6 +471,12 @@ impl DepositBuilder {
                 
                     
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
                         /// Protocol fee LP token account owned by the pool PDA
 
       
@@ -428,6 +494,12 @@ impl DepositBuilder {
                 
                     
               pub lp_token_system_program_token_account: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `deposit` CPI instruction.
@@ -464,6 +536,12 @@ pub struct DepositCpi<'a, 'b> {
           
               
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
                 /// Protocol fee LP token account owned by the pool PDA
 
     
@@ -481,6 +559,12 @@ pub struct DepositCpi<'a, 'b> {
           
               
           pub lp_token_system_program_token_account: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub program: &'b solana_account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: DepositInstructionArgs,
   }
@@ -503,11 +587,15 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
               token_b_mint: accounts.token_b_mint,
               lp_token_mint: accounts.lp_token_mint,
               liquidity_pool: accounts.liquidity_pool,
+              pool_stats: accounts.pool_stats,
+              global_state: accounts.global_state,
               fee_lp_token_account: accounts.fee_lp_token_account,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
               lp_token_system_program_token_account: accounts.lp_token_system_program_token_account,
+              event_authority: accounts.event_authority,
+              program: accounts.program,
                     __args: args,
           }
   }
@@ -531,7 +619,7 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(19+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -571,6 +659,14 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.pool_stats.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.global_state.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.fee_lp_token_account.key,
@@ -591,6 +687,14 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.lp_token_system_program_token_account.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -608,7 +712,7 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(20 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.lp_token_signer_token_account.clone());
@@ -620,11 +724,15 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
                         account_infos.push(self.token_b_mint.clone());
                         account_infos.push(self.lp_token_mint.clone());
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.pool_stats.clone());
+                        account_infos.push(self.global_state.clone());
                         account_infos.push(self.fee_lp_token_account.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
                         account_infos.push(self.lp_token_system_program_token_account.clone());
+                        account_infos.push(self.event_authority.clone());
+                        account_infos.push(self.program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -649,11 +757,15 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
           ///   7. `[]` token_b_mint
                 ///   8. `[writable]` lp_token_mint
                 ///   9. `[writable]` liquidity_pool
-                ///   10. `[writable]` fee_lp_token_account
-          ///   11. `[]` token_program
-          ///   12. `[]` associated_token_program
-          ///   13. `[]` system_program
-                ///   14. `[writable]` lp_token_system_program_token_account
+                ///   10. `[writable]` pool_stats
+          ///   11. `[]` global_state
+                ///   12. `[writable]` fee_lp_token_account
+          ///   13. `[]` token_program
+          ///   14. `[]` associated_token_program
+          ///   15. `[]` system_program
+                ///   16. `[writable]` lp_token_system_program_token_account
+          ///   17. `[]` event_authority
+          ///   18. `[]` program
 #[derive(Clone, Debug)]
 pub struct DepositCpiBuilder<'a, 'b> {
   instruction: Box<DepositCpiBuilderInstruction<'a, 'b>>,
@@ -673,11 +785,15 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
               token_b_mint: None,
               lp_token_mint: None,
               liquidity_pool: None,
+              pool_stats: None,
+              global_state: None,
               fee_lp_token_account: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
               lp_token_system_program_token_account: None,
+              event_authority: None,
+              program: None,
                                             token_a_amount_desired: None,
                                 token_b_amount_desired: None,
                                 token_a_amount_min: None,
@@ -736,6 +852,16 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
     pub fn liquidity_pool(&mut self, liquidity_pool: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.liquidity_pool = Some(liquidity_pool);
                     self
+    }
+      #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.pool_stats = Some(pool_stats);
+                    self
+    }
+      #[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
     }
       /// Protocol fee LP token account owned by the pool PDA
 #[inline(always)]
@@ -762,6 +888,16 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
     pub fn lp_token_system_program_token_account(&mut self, lp_token_system_program_token_account: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.lp_token_system_program_token_account = Some(lp_token_system_program_token_account);
                     self
+    }
+      #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.event_authority = Some(event_authority);
+                    self
+    }
+      #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.program = Some(program);
+                    self
     }
                     #[inline(always)]
       pub fn token_a_amount_desired(&mut self, token_a_amount_desired: u64) -> &mut Self {
@@ -840,6 +976,10 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          pool_stats: self.instruction.pool_stats.expect("pool_stats is not set"),
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
           fee_lp_token_account: self.instruction.fee_lp_token_account.expect("fee_lp_token_account is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
@@ -849,6 +989,10 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
           system_program: self.instruction.system_program.expect("system_program is not set"),
                   
           lp_token_system_program_token_account: self.instruction.lp_token_system_program_token_account.expect("lp_token_system_program_token_account is not set"),
+                  
+          event_authority: self.instruction.event_authority.expect("event_authority is not set"),
+                  
+          program: self.instruction.program.expect("program is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -868,11 +1012,15 @@ struct DepositCpiBuilderInstruction<'a, 'b> {
                 token_b_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                pool_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
                 fee_lp_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_system_program_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         token_a_amount_desired: Option<u64>,
                 token_b_amount_desired: Option<u64>,
                 token_a_amount_min: Option<u64>,
@@ -18,12 +18,6 @@ pub struct InitializePool {
           pub signer: solana_pubkey::Pubkey,
           
               
-          pub token_a_signer_token_account: solana_pubkey::Pubkey,
-          
-              
-          pub token_b_signer_token_account: solana_pubkey::Pubkey,
-          
-              
           pub token_a_vault: solana_pubkey::Pubkey,
           
               
@@ -39,6 +33,11 @@ pub struct InitializePool {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub pool_stats: solana_pubkey::Pubkey,
+                /// Already exists if the pair had a pool before, see `close_pool`
+
+    
+              
           pub lp_token_mint: solana_pubkey::Pubkey,
           
               
@@ -57,27 +56,25 @@ pub struct InitializePool {
           
               
           pub system_program: solana_pubkey::Pubkey,
+          
+              
+          pub event_authority: solana_pubkey::Pubkey,
+          
+              
+          pub program: solana_pubkey::Pubkey,
       }
 
 impl InitializePool {
-  pub fn instruction(&self) -> solana_instruction::Instruction {
-    self.instruction_with_remaining_accounts(&[])
+  pub fn instruction(&self, args: InitializePoolInstructionArgs) -> solana_instruction::Instruction {
+    self.instruction_with_remaining_accounts(args, &[])
   }
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
-  pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(14+ remaining_accounts.len());
+  pub fn instruction_with_remaining_accounts(&self, args: InitializePoolInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
+    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
-            self.token_a_signer_token_account,
-            false
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
-            self.token_b_signer_token_account,
-            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.token_a_vault,
@@ -98,6 +95,10 @@ impl InitializePool {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.pool_stats,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.lp_token_mint,
@@ -122,9 +123,19 @@ impl InitializePool {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
-    let data = InitializePoolInstructionData::new().try_to_vec().unwrap();
+    let mut data = InitializePoolInstructionData::new().try_to_vec().unwrap();
+          let mut args = args.try_to_vec().unwrap();
+      data.append(&mut args);
     
     solana_instruction::Instruction {
       program_id: crate::ANCHOR_AMM_ID,
@@ -138,13 +149,13 @@ impl InitializePool {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct InitializePoolInstructionData {
             discriminator: [u8; 1],
-      }
+                        }
 
 impl InitializePoolInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [1],
-                  }
+                                                            }
   }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -158,6 +169,19 @@ impl Default for InitializePoolInstructionData {
   }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+ pub struct InitializePoolInstructionArgs {
+                  pub creator_fee_bps: u16,
+                pub deposit_fee_bps: u16,
+                pub deposit_fee_to_protocol: bool,
+      }
+
+impl InitializePoolInstructionArgs {
+  pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
+    borsh::to_vec(self)
+  }
+}
 
 
 /// Instruction builder for `InitializePool`.
@@ -165,36 +189,41 @@ impl Default for InitializePoolInstructionData {
 /// ### Accounts:
 ///
                       ///   0. `[writable, signer]` signer
-                ///   1. `[writable]` token_a_signer_token_account
-                ///   2. `[writable]` token_b_signer_token_account
-                ///   3. `[writable]` token_a_vault
-                ///   4. `[writable]` token_b_vault
-          ///   5. `[]` token_a_mint
-          ///   6. `[]` token_b_mint
-                ///   7. `[writable]` liquidity_pool
-                ///   8. `[writable]` lp_token_mint
-                ///   9. `[writable]` lp_token_signer_token_account
-                ///   10. `[writable]` fee_lp_token_account
-                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   12. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   1. `[writable]` token_a_vault
+                ///   2. `[writable]` token_b_vault
+          ///   3. `[]` token_a_mint
+          ///   4. `[]` token_b_mint
+                ///   5. `[writable]` liquidity_pool
+                ///   6. `[writable]` pool_stats
+                ///   7. `[writable]` lp_token_mint
+                ///   8. `[writable]` lp_token_signer_token_account
+                ///   9. `[writable]` fee_lp_token_account
+                ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   13. `[]` event_authority
+          ///   14. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct InitializePoolBuilder {
             signer: Option<solana_pubkey::Pubkey>,
-                token_a_signer_token_account: Option<solana_pubkey::Pubkey>,
-                token_b_signer_token_account: Option<solana_pubkey::Pubkey>,
                 token_a_vault: Option<solana_pubkey::Pubkey>,
                 token_b_vault: Option<solana_pubkey::Pubkey>,
                 token_a_mint: Option<solana_pubkey::Pubkey>,
                 token_b_mint: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                pool_stats: Option<solana_pubkey::Pubkey>,
                 lp_token_mint: Option<solana_pubkey::Pubkey>,
                 lp_token_signer_token_account: Option<solana_pubkey::Pubkey>,
                 fee_lp_token_account: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
-                __remaining_accounts: Vec<solana_instruction::AccountMeta>,
+                event_authority: Option<solana_pubkey::Pubkey>,
+                program: Option<solana_pubkey::Pubkey>,
+                        creator_fee_bps: Option<u16>,
+                deposit_fee_bps: Option<u16>,
+                deposit_fee_to_protocol: Option<bool>,
+        __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl InitializePoolBuilder {
@@ -207,16 +236,6 @@ impl InitializePoolBuilder {
                     self
     }
             #[inline(always)]
-    pub fn token_a_signer_token_account(&mut self, token_a_signer_token_account: solana_pubkey::Pubkey) -> &mut Self {
-                        self.token_a_signer_token_account = Some(token_a_signer_token_account);
-                    self
-    }
-            #[inline(always)]
-    pub fn token_b_signer_token_account(&mut self, token_b_signer_token_account: solana_pubkey::Pubkey) -> &mut Self {
-                        self.token_b_signer_token_account = Some(token_b_signer_token_account);
-                    self
-    }
-            #[inline(always)]
     pub fn token_a_vault(&mut self, token_a_vault: solana_pubkey::Pubkey) -> &mut Self {
                         self.token_a_vault = Some(token_a_vault);
                     self
@@ -242,6 +261,12 @@ impl InitializePoolBuilder {
                     self
     }
             #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: solana_pubkey::Pubkey) -> &mut Self {
+                        self.pool_stats = Some(pool_stats);
+                    self
+    }
+            /// Already exists if the pair had a pool before, see `close_pool`
+#[inline(always)]
     pub fn lp_token_mint(&mut self, lp_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.lp_token_mint = Some(lp_token_mint);
                     self
@@ -275,7 +300,32 @@ impl InitializePoolBuilder {
                         self.system_program = Some(system_program);
                     self
     }
-            /// Add an additional account to the instruction.
+            #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+                        self.event_authority = Some(event_authority);
+                    self
+    }
+            #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.program = Some(program);
+                    self
+    }
+                    #[inline(always)]
+      pub fn creator_fee_bps(&mut self, creator_fee_bps: u16) -> &mut Self {
+        self.creator_fee_bps = Some(creator_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn deposit_fee_bps(&mut self, deposit_fee_bps: u16) -> &mut Self {
+        self.deposit_fee_bps = Some(deposit_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn deposit_fee_to_protocol(&mut self, deposit_fee_to_protocol: bool) -> &mut Self {
+        self.deposit_fee_to_protocol = Some(deposit_fee_to_protocol);
+        self
+      }
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
     self.__remaining_accounts.push(account);
@@ -291,22 +341,28 @@ impl InitializePoolBuilder {
   pub fn instruction(&self) -> solana_instruction::Instruction {
     let accounts = InitializePool {
                               signer: self.signer.expect("signer is not set"),
-                                        token_a_signer_token_account: self.token_a_signer_token_account.expect("token_a_signer_token_account is not set"),
-                                        token_b_signer_token_account: self.token_b_signer_token_account.expect("token_b_signer_token_account is not set"),
                                         token_a_vault: self.token_a_vault.expect("token_a_vault is not set"),
                                         token_b_vault: self.token_b_vault.expect("token_b_vault is not set"),
                                         token_a_mint: self.token_a_mint.expect("token_a_mint is not set"),
                                         token_b_mint: self.token_b_mint.expect("token_b_mint is not set"),
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        pool_stats: self.pool_stats.expect("pool_stats is not set"),
                                         lp_token_mint: self.lp_token_mint.expect("lp_token_mint is not set"),
                                         lp_token_signer_token_account: self.lp_token_signer_token_account.expect("lp_token_signer_token_account is not set"),
                                         fee_lp_token_account: self.fee_lp_token_account.expect("fee_lp_token_account is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                                        event_authority: self.event_authority.expect("event_authority is not set"),
+                                        program: self.program.expect("program is not set"),
                       };
+          let args = InitializePoolInstructionArgs {
+                                                              creator_fee_bps: self.creator_fee_bps.clone().expect("creator_fee_bps is not set"),
+                                                                  deposit_fee_bps: self.deposit_fee_bps.clone().expect("deposit_fee_bps is not set"),
+                                                                  deposit_fee_to_protocol: self.deposit_fee_to_protocol.clone().expect("deposit_fee_to_protocol is not set"),
+                                    };
     
-    accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
   }
 }
 
@@ -317,12 +373,6 @@ impl InitializePoolBuilder {
               pub signer: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub token_a_signer_token_account: &'b solana_account_info::AccountInfo<'a>,
-                
-                    
-              pub token_b_signer_token_account: &'b solana_account_info::AccountInfo<'a>,
-                
-                    
               pub token_a_vault: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -338,6 +388,11 @@ impl InitializePoolBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+                        /// Already exists if the pair had a pool before, see `close_pool`
+
+      
+                    
               pub lp_token_mint: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -356,6 +411,12 @@ impl InitializePoolBuilder {
                 
                     
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `initialize_pool` CPI instruction.
@@ -367,12 +428,6 @@ pub struct InitializePoolCpi<'a, 'b> {
           pub signer: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub token_a_signer_token_account: &'b solana_account_info::AccountInfo<'a>,
-          
-              
-          pub token_b_signer_token_account: &'b solana_account_info::AccountInfo<'a>,
-          
-              
           pub token_a_vault: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -388,6 +443,11 @@ pub struct InitializePoolCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+                /// Already exists if the pair had a pool before, see `close_pool`
+
+    
+              
           pub lp_token_mint: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -406,30 +466,41 @@ pub struct InitializePoolCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
-        }
+          
+              
+          pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub program: &'b solana_account_info::AccountInfo<'a>,
+            /// The arguments for the instruction.
+    pub __args: InitializePoolInstructionArgs,
+  }
 
 impl<'a, 'b> InitializePoolCpi<'a, 'b> {
   pub fn new(
     program: &'b solana_account_info::AccountInfo<'a>,
           accounts: InitializePoolCpiAccounts<'a, 'b>,
-          ) -> Self {
+              args: InitializePoolInstructionArgs,
+      ) -> Self {
     Self {
       __program: program,
               signer: accounts.signer,
-              token_a_signer_token_account: accounts.token_a_signer_token_account,
-              token_b_signer_token_account: accounts.token_b_signer_token_account,
               token_a_vault: accounts.token_a_vault,
               token_b_vault: accounts.token_b_vault,
               token_a_mint: accounts.token_a_mint,
               token_b_mint: accounts.token_b_mint,
               liquidity_pool: accounts.liquidity_pool,
+              pool_stats: accounts.pool_stats,
               lp_token_mint: accounts.lp_token_mint,
               lp_token_signer_token_account: accounts.lp_token_signer_token_account,
               fee_lp_token_account: accounts.fee_lp_token_account,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
-                }
+              event_authority: accounts.event_authority,
+              program: accounts.program,
+                    __args: args,
+          }
   }
   #[inline(always)]
   pub fn invoke(&self) -> solana_program_error::ProgramResult {
@@ -451,18 +522,10 @@ impl<'a, 'b> InitializePoolCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(14+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
-            *self.token_a_signer_token_account.key,
-            false
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
-            *self.token_b_signer_token_account.key,
-            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.token_a_vault.key,
@@ -483,6 +546,10 @@ impl<'a, 'b> InitializePoolCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.pool_stats.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.lp_token_mint.key,
@@ -507,6 +574,14 @@ impl<'a, 'b> InitializePoolCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -515,29 +590,32 @@ impl<'a, 'b> InitializePoolCpi<'a, 'b> {
           is_writable: remaining_account.2,
       })
     });
-    let data = InitializePoolInstructionData::new().try_to_vec().unwrap();
+    let mut data = InitializePoolInstructionData::new().try_to_vec().unwrap();
+          let mut args = self.__args.try_to_vec().unwrap();
+      data.append(&mut args);
     
     let instruction = solana_instruction::Instruction {
       program_id: crate::ANCHOR_AMM_ID,
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
-                        account_infos.push(self.token_a_signer_token_account.clone());
-                        account_infos.push(self.token_b_signer_token_account.clone());
                         account_infos.push(self.token_a_vault.clone());
                         account_infos.push(self.token_b_vault.clone());
                         account_infos.push(self.token_a_mint.clone());
                         account_infos.push(self.token_b_mint.clone());
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.pool_stats.clone());
                         account_infos.push(self.lp_token_mint.clone());
                         account_infos.push(self.lp_token_signer_token_account.clone());
                         account_infos.push(self.fee_lp_token_account.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.event_authority.clone());
+                        account_infos.push(self.program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -553,19 +631,20 @@ impl<'a, 'b> InitializePoolCpi<'a, 'b> {
 /// ### Accounts:
 ///
                       ///   0. `[writable, signer]` signer
-                ///   1. `[writable]` token_a_signer_token_account
-                ///   2. `[writable]` token_b_signer_token_account
-                ///   3. `[writable]` token_a_vault
-                ///   4. `[writable]` token_b_vault
-          ///   5. `[]` token_a_mint
-          ///   6. `[]` token_b_mint
-                ///   7. `[writable]` liquidity_pool
-                ///   8. `[writable]` lp_token_mint
-                ///   9. `[writable]` lp_token_signer_token_account
-                ///   10. `[writable]` fee_lp_token_account
-          ///   11. `[]` token_program
-          ///   12. `[]` associated_token_program
-          ///   13. `[]` system_program
+                ///   1. `[writable]` token_a_vault
+                ///   2. `[writable]` token_b_vault
+          ///   3. `[]` token_a_mint
+          ///   4. `[]` token_b_mint
+                ///   5. `[writable]` liquidity_pool
+                ///   6. `[writable]` pool_stats
+                ///   7. `[writable]` lp_token_mint
+                ///   8. `[writable]` lp_token_signer_token_account
+                ///   9. `[writable]` fee_lp_token_account
+          ///   10. `[]` token_program
+          ///   11. `[]` associated_token_program
+          ///   12. `[]` system_program
+          ///   13. `[]` event_authority
+          ///   14. `[]` program
 #[derive(Clone, Debug)]
 pub struct InitializePoolCpiBuilder<'a, 'b> {
   instruction: Box<InitializePoolCpiBuilderInstruction<'a, 'b>>,
@@ -576,20 +655,24 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
     let instruction = Box::new(InitializePoolCpiBuilderInstruction {
       __program: program,
               signer: None,
-              token_a_signer_token_account: None,
-              token_b_signer_token_account: None,
               token_a_vault: None,
               token_b_vault: None,
               token_a_mint: None,
               token_b_mint: None,
               liquidity_pool: None,
+              pool_stats: None,
               lp_token_mint: None,
               lp_token_signer_token_account: None,
               fee_lp_token_account: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
-                                __remaining_accounts: Vec::new(),
+              event_authority: None,
+              program: None,
+                                            creator_fee_bps: None,
+                                deposit_fee_bps: None,
+                                deposit_fee_to_protocol: None,
+                    __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
@@ -599,16 +682,6 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
-    pub fn token_a_signer_token_account(&mut self, token_a_signer_token_account: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.token_a_signer_token_account = Some(token_a_signer_token_account);
-                    self
-    }
-      #[inline(always)]
-    pub fn token_b_signer_token_account(&mut self, token_b_signer_token_account: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.token_b_signer_token_account = Some(token_b_signer_token_account);
-                    self
-    }
-      #[inline(always)]
     pub fn token_a_vault(&mut self, token_a_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token_a_vault = Some(token_a_vault);
                     self
@@ -634,6 +707,12 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.pool_stats = Some(pool_stats);
+                    self
+    }
+      /// Already exists if the pair had a pool before, see `close_pool`
+#[inline(always)]
     pub fn lp_token_mint(&mut self, lp_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.lp_token_mint = Some(lp_token_mint);
                     self
@@ -664,7 +743,32 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
                         self.instruction.system_program = Some(system_program);
                     self
     }
-            /// Add an additional account to the instruction.
+      #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.event_authority = Some(event_authority);
+                    self
+    }
+      #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.program = Some(program);
+                    self
+    }
+                    #[inline(always)]
+      pub fn creator_fee_bps(&mut self, creator_fee_bps: u16) -> &mut Self {
+        self.instruction.creator_fee_bps = Some(creator_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn deposit_fee_bps(&mut self, deposit_fee_bps: u16) -> &mut Self {
+        self.instruction.deposit_fee_bps = Some(deposit_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn deposit_fee_to_protocol(&mut self, deposit_fee_to_protocol: bool) -> &mut Self {
+        self.instruction.deposit_fee_to_protocol = Some(deposit_fee_to_protocol);
+        self
+      }
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: &'b solana_account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
     self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
@@ -686,15 +790,16 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
   #[allow(clippy::clone_on_copy)]
   #[allow(clippy::vec_init_then_push)]
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
+          let args = InitializePoolInstructionArgs {
+                                                              creator_fee_bps: self.instruction.creator_fee_bps.clone().expect("creator_fee_bps is not set"),
+                                                                  deposit_fee_bps: self.instruction.deposit_fee_bps.clone().expect("deposit_fee_bps is not set"),
+                                                                  deposit_fee_to_protocol: self.instruction.deposit_fee_to_protocol.clone().expect("deposit_fee_to_protocol is not set"),
+                                    };
         let instruction = InitializePoolCpi {
         __program: self.instruction.__program,
                   
           signer: self.instruction.signer.expect("signer is not set"),
                   
-          token_a_signer_token_account: self.instruction.token_a_signer_token_account.expect("token_a_signer_token_account is not set"),
-                  
-          token_b_signer_token_account: self.instruction.token_b_signer_token_account.expect("token_b_signer_token_account is not set"),
-                  
           token_a_vault: self.instruction.token_a_vault.expect("token_a_vault is not set"),
                   
           token_b_vault: self.instruction.token_b_vault.expect("token_b_vault is not set"),
@@ -705,6 +810,8 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          pool_stats: self.instruction.pool_stats.expect("pool_stats is not set"),
+                  
           lp_token_mint: self.instruction.lp_token_mint.expect("lp_token_mint is not set"),
                   
           lp_token_signer_token_account: self.instruction.lp_token_signer_token_account.expect("lp_token_signer_token_account is not set"),
@@ -716,7 +823,12 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
-                    };
+                  
+          event_authority: self.instruction.event_authority.expect("event_authority is not set"),
+                  
+          program: self.instruction.program.expect("program is not set"),
+                          __args: args,
+            };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
 }
@@ -725,20 +837,24 @@ impl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
 struct InitializePoolCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             signer: Option<&'b solana_account_info::AccountInfo<'a>>,
-                token_a_signer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
-                token_b_signer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_a_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_b_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_a_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_b_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                pool_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_signer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 fee_lp_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-                /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+                event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        creator_fee_bps: Option<u16>,
+                deposit_fee_bps: Option<u16>,
+                deposit_fee_to_protocol: Option<bool>,
+        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
 
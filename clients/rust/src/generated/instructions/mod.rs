@@ -5,17 +5,87 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#claim_creator_fees;
+  pub(crate) mod r#close_pool;
+  pub(crate) mod r#collect_fees;
+  pub(crate) mod r#decrease_liquidity;
   pub(crate) mod r#deposit;
+  pub(crate) mod r#deposit_multi_asset;
+  pub(crate) mod r#deposit_multi_asset_single;
+  pub(crate) mod r#increase_liquidity;
+  pub(crate) mod r#initialize_concentrated_pool;
+  pub(crate) mod r#initialize_constant_sum_pool;
+  pub(crate) mod r#initialize_global_state;
+  pub(crate) mod r#initialize_lbp;
+  pub(crate) mod r#initialize_multi_asset_pool;
+  pub(crate) mod r#initialize_pmm_pool;
   pub(crate) mod r#initialize_pool;
+  pub(crate) mod r#initialize_stable_pool;
+  pub(crate) mod r#initialize_tick_array;
+  pub(crate) mod r#initialize_weighted_pool;
+  pub(crate) mod r#migrate_pool;
+  pub(crate) mod r#open_position;
+  pub(crate) mod r#quote_deposit;
+  pub(crate) mod r#quote_swap;
+  pub(crate) mod r#ramp_amp;
+  pub(crate) mod r#set_dynamic_fee;
+  pub(crate) mod r#set_global_pause;
+  pub(crate) mod r#set_launch_params;
+  pub(crate) mod r#set_pool_pause;
+  pub(crate) mod r#set_price_band;
+  pub(crate) mod r#set_reference_oracle;
+  pub(crate) mod r#set_slot_price_move_limit;
   pub(crate) mod r#skim;
+  pub(crate) mod r#stop_ramp_amp;
   pub(crate) mod r#swap;
+  pub(crate) mod r#swap_concentrated;
+  pub(crate) mod r#swap_multi_asset;
+  pub(crate) mod r#swap_v2;
   pub(crate) mod r#sync;
+  pub(crate) mod r#transfer_creator;
   pub(crate) mod r#withdraw;
+  pub(crate) mod r#withdraw_multi_asset;
+  pub(crate) mod r#withdraw_multi_asset_single;
 
+  pub use self::r#claim_creator_fees::*;
+  pub use self::r#close_pool::*;
+  pub use self::r#collect_fees::*;
+  pub use self::r#decrease_liquidity::*;
   pub use self::r#deposit::*;
+  pub use self::r#deposit_multi_asset::*;
+  pub use self::r#deposit_multi_asset_single::*;
+  pub use self::r#increase_liquidity::*;
+  pub use self::r#initialize_concentrated_pool::*;
+  pub use self::r#initialize_constant_sum_pool::*;
+  pub use self::r#initialize_global_state::*;
+  pub use self::r#initialize_lbp::*;
+  pub use self::r#initialize_multi_asset_pool::*;
+  pub use self::r#initialize_pmm_pool::*;
   pub use self::r#initialize_pool::*;
+  pub use self::r#initialize_stable_pool::*;
+  pub use self::r#initialize_tick_array::*;
+  pub use self::r#initialize_weighted_pool::*;
+  pub use self::r#migrate_pool::*;
+  pub use self::r#open_position::*;
+  pub use self::r#quote_deposit::*;
+  pub use self::r#quote_swap::*;
+  pub use self::r#ramp_amp::*;
+  pub use self::r#set_dynamic_fee::*;
+  pub use self::r#set_global_pause::*;
+  pub use self::r#set_launch_params::*;
+  pub use self::r#set_pool_pause::*;
+  pub use self::r#set_price_band::*;
+  pub use self::r#set_reference_oracle::*;
+  pub use self::r#set_slot_price_move_limit::*;
   pub use self::r#skim::*;
+  pub use self::r#stop_ramp_amp::*;
   pub use self::r#swap::*;
+  pub use self::r#swap_concentrated::*;
+  pub use self::r#swap_multi_asset::*;
+  pub use self::r#swap_v2::*;
   pub use self::r#sync::*;
+  pub use self::r#transfer_creator::*;
   pub use self::r#withdraw::*;
+  pub use self::r#withdraw_multi_asset::*;
+  pub use self::r#withdraw_multi_asset_single::*;
 
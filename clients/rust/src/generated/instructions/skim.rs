@@ -21,6 +21,9 @@ pub struct Skim {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub global_state: solana_pubkey::Pubkey,
+          
+              
           pub token_a_mint: solana_pubkey::Pubkey,
           
               
@@ -40,6 +43,12 @@ pub struct Skim {
           
               
           pub token_program: solana_pubkey::Pubkey,
+          
+              
+          pub event_authority: solana_pubkey::Pubkey,
+          
+              
+          pub program: solana_pubkey::Pubkey,
       }
 
 impl Skim {
@@ -49,7 +58,7 @@ impl Skim {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(9+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
@@ -57,6 +66,10 @@ impl Skim {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.global_state,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_a_mint,
@@ -85,6 +98,14 @@ impl Skim {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let data = SkimInstructionData::new().try_to_vec().unwrap();
@@ -129,17 +150,21 @@ impl Default for SkimInstructionData {
 ///
                       ///   0. `[writable, signer]` signer
                 ///   1. `[writable]` liquidity_pool
-          ///   2. `[]` token_a_mint
-          ///   3. `[]` token_b_mint
-                ///   4. `[writable]` token_a_vault
-                ///   5. `[writable]` token_b_vault
-                ///   6. `[writable]` token_a_signer_token_account
-                ///   7. `[writable]` token_b_signer_token_account
-                ///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   2. `[]` global_state
+          ///   3. `[]` token_a_mint
+          ///   4. `[]` token_b_mint
+                ///   5. `[writable]` token_a_vault
+                ///   6. `[writable]` token_b_vault
+                ///   7. `[writable]` token_a_signer_token_account
+                ///   8. `[writable]` token_b_signer_token_account
+                ///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   10. `[]` event_authority
+          ///   11. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct SkimBuilder {
             signer: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                global_state: Option<solana_pubkey::Pubkey>,
                 token_a_mint: Option<solana_pubkey::Pubkey>,
                 token_b_mint: Option<solana_pubkey::Pubkey>,
                 token_a_vault: Option<solana_pubkey::Pubkey>,
@@ -147,6 +172,8 @@ pub struct SkimBuilder {
                 token_a_signer_token_account: Option<solana_pubkey::Pubkey>,
                 token_b_signer_token_account: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
+                event_authority: Option<solana_pubkey::Pubkey>,
+                program: Option<solana_pubkey::Pubkey>,
                 __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -165,6 +192,11 @@ impl SkimBuilder {
                     self
     }
             #[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
+    }
+            #[inline(always)]
     pub fn token_a_mint(&mut self, token_a_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.token_a_mint = Some(token_a_mint);
                     self
@@ -199,6 +231,16 @@ impl SkimBuilder {
     pub fn token_program(&mut self, token_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.token_program = Some(token_program);
                     self
+    }
+            #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+                        self.event_authority = Some(event_authority);
+                    self
+    }
+            #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.program = Some(program);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -217,6 +259,7 @@ impl SkimBuilder {
     let accounts = Skim {
                               signer: self.signer.expect("signer is not set"),
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        global_state: self.global_state.expect("global_state is not set"),
                                         token_a_mint: self.token_a_mint.expect("token_a_mint is not set"),
                                         token_b_mint: self.token_b_mint.expect("token_b_mint is not set"),
                                         token_a_vault: self.token_a_vault.expect("token_a_vault is not set"),
@@ -224,6 +267,8 @@ impl SkimBuilder {
                                         token_a_signer_token_account: self.token_a_signer_token_account.expect("token_a_signer_token_account is not set"),
                                         token_b_signer_token_account: self.token_b_signer_token_account.expect("token_b_signer_token_account is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        event_authority: self.event_authority.expect("event_authority is not set"),
+                                        program: self.program.expect("program is not set"),
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -240,6 +285,9 @@ impl SkimBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub token_a_mint: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -259,6 +307,12 @@ impl SkimBuilder {
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `skim` CPI instruction.
@@ -273,6 +327,9 @@ pub struct SkimCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub token_a_mint: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -292,6 +349,12 @@ pub struct SkimCpi<'a, 'b> {
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub program: &'b solana_account_info::AccountInfo<'a>,
         }
 
 impl<'a, 'b> SkimCpi<'a, 'b> {
@@ -303,6 +366,7 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
       __program: program,
               signer: accounts.signer,
               liquidity_pool: accounts.liquidity_pool,
+              global_state: accounts.global_state,
               token_a_mint: accounts.token_a_mint,
               token_b_mint: accounts.token_b_mint,
               token_a_vault: accounts.token_a_vault,
@@ -310,6 +374,8 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
               token_a_signer_token_account: accounts.token_a_signer_token_account,
               token_b_signer_token_account: accounts.token_b_signer_token_account,
               token_program: accounts.token_program,
+              event_authority: accounts.event_authority,
+              program: accounts.program,
                 }
   }
   #[inline(always)]
@@ -332,7 +398,7 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(9+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -340,6 +406,10 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.global_state.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_a_mint.key,
@@ -368,6 +438,14 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -383,10 +461,11 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.global_state.clone());
                         account_infos.push(self.token_a_mint.clone());
                         account_infos.push(self.token_b_mint.clone());
                         account_infos.push(self.token_a_vault.clone());
@@ -394,6 +473,8 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
                         account_infos.push(self.token_a_signer_token_account.clone());
                         account_infos.push(self.token_b_signer_token_account.clone());
                         account_infos.push(self.token_program.clone());
+                        account_infos.push(self.event_authority.clone());
+                        account_infos.push(self.program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -410,13 +491,16 @@ impl<'a, 'b> SkimCpi<'a, 'b> {
 ///
                       ///   0. `[writable, signer]` signer
                 ///   1. `[writable]` liquidity_pool
-          ///   2. `[]` token_a_mint
-          ///   3. `[]` token_b_mint
-                ///   4. `[writable]` token_a_vault
-                ///   5. `[writable]` token_b_vault
-                ///   6. `[writable]` token_a_signer_token_account
-                ///   7. `[writable]` token_b_signer_token_account
-          ///   8. `[]` token_program
+          ///   2. `[]` global_state
+          ///   3. `[]` token_a_mint
+          ///   4. `[]` token_b_mint
+                ///   5. `[writable]` token_a_vault
+                ///   6. `[writable]` token_b_vault
+                ///   7. `[writable]` token_a_signer_token_account
+                ///   8. `[writable]` token_b_signer_token_account
+          ///   9. `[]` token_program
+          ///   10. `[]` event_authority
+          ///   11. `[]` program
 #[derive(Clone, Debug)]
 pub struct SkimCpiBuilder<'a, 'b> {
   instruction: Box<SkimCpiBuilderInstruction<'a, 'b>>,
@@ -428,6 +512,7 @@ impl<'a, 'b> SkimCpiBuilder<'a, 'b> {
       __program: program,
               signer: None,
               liquidity_pool: None,
+              global_state: None,
               token_a_mint: None,
               token_b_mint: None,
               token_a_vault: None,
@@ -435,6 +520,8 @@ impl<'a, 'b> SkimCpiBuilder<'a, 'b> {
               token_a_signer_token_account: None,
               token_b_signer_token_account: None,
               token_program: None,
+              event_authority: None,
+              program: None,
                                 __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -450,6 +537,11 @@ impl<'a, 'b> SkimCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
+    }
+      #[inline(always)]
     pub fn token_a_mint(&mut self, token_a_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token_a_mint = Some(token_a_mint);
                     self
@@ -483,6 +575,16 @@ impl<'a, 'b> SkimCpiBuilder<'a, 'b> {
     pub fn token_program(&mut self, token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token_program = Some(token_program);
                     self
+    }
+      #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.event_authority = Some(event_authority);
+                    self
+    }
+      #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.program = Some(program);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -513,6 +615,8 @@ impl<'a, 'b> SkimCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
           token_a_mint: self.instruction.token_a_mint.expect("token_a_mint is not set"),
                   
           token_b_mint: self.instruction.token_b_mint.expect("token_b_mint is not set"),
@@ -526,6 +630,10 @@ impl<'a, 'b> SkimCpiBuilder<'a, 'b> {
           token_b_signer_token_account: self.instruction.token_b_signer_token_account.expect("token_b_signer_token_account is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
+                  
+          event_authority: self.instruction.event_authority.expect("event_authority is not set"),
+                  
+          program: self.instruction.program.expect("program is not set"),
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
@@ -536,6 +644,7 @@ struct SkimCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             signer: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_a_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_b_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_a_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -543,6 +652,8 @@ struct SkimCpiBuilderInstruction<'a, 'b> {
                 token_a_signer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_b_signer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
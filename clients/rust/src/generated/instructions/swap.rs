@@ -22,10 +22,14 @@ pub struct Swap {
           
               
           pub token1_signer_token_account: solana_pubkey::Pubkey,
-          
+                /// The pool's vault for `token_0_mint`
+
+    
               
           pub token0_vault: solana_pubkey::Pubkey,
-          
+                /// The pool's vault for `token_1_mint`
+
+    
               
           pub token1_vault: solana_pubkey::Pubkey,
           
@@ -34,7 +38,9 @@ pub struct Swap {
           
               
           pub token1_mint: solana_pubkey::Pubkey,
-          
+                /// The pool's LP mint
+
+    
               
           pub lp_token_mint: solana_pubkey::Pubkey,
           
@@ -42,6 +48,12 @@ pub struct Swap {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub pool_stats: solana_pubkey::Pubkey,
+          
+              
+          pub global_state: solana_pubkey::Pubkey,
+          
+              
           pub token_program: solana_pubkey::Pubkey,
           
               
@@ -49,6 +61,17 @@ pub struct Swap {
           
               
           pub system_program: solana_pubkey::Pubkey,
+                /// Receives the referral fee, in the input mint
+
+    
+              
+          pub referrer_token_account: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub event_authority: solana_pubkey::Pubkey,
+          
+              
+          pub program: solana_pubkey::Pubkey,
       }
 
 impl Swap {
@@ -58,7 +81,7 @@ impl Swap {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: SwapInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(17+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
@@ -94,6 +117,14 @@ impl Swap {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.pool_stats,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.global_state,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
@@ -106,6 +137,25 @@ impl Swap {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          if let Some(referrer_token_account) = self.referrer_token_account {
+              accounts.push(solana_instruction::AccountMeta::new(
+                referrer_token_account,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ANCHOR_AMM_ID,
+                false,
+              ));
+            }
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = SwapInstructionData::new().try_to_vec().unwrap();
@@ -124,13 +174,13 @@ impl Swap {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct SwapInstructionData {
             discriminator: [u8; 1],
-                        }
+                              }
 
 impl SwapInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [3],
-                                                            }
+                                                                          }
   }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -150,6 +200,7 @@ impl Default for SwapInstructionData {
                   pub token0_amount: u64,
                 pub token1_min_amount: u64,
                 pub expiration: i64,
+                pub referral_fee_bps: u16,
       }
 
 impl SwapInstructionArgs {
@@ -172,9 +223,14 @@ impl SwapInstructionArgs {
           ///   6. `[]` token1_mint
                 ///   7. `[writable]` lp_token_mint
                 ///   8. `[writable]` liquidity_pool
-                ///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   10. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   9. `[writable]` pool_stats
+          ///   10. `[]` global_state
+                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   12. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                      ///   14. `[writable, optional]` referrer_token_account
+          ///   15. `[]` event_authority
+          ///   16. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct SwapBuilder {
             signer: Option<solana_pubkey::Pubkey>,
@@ -186,12 +242,18 @@ pub struct SwapBuilder {
                 token1_mint: Option<solana_pubkey::Pubkey>,
                 lp_token_mint: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                pool_stats: Option<solana_pubkey::Pubkey>,
+                global_state: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
+                referrer_token_account: Option<solana_pubkey::Pubkey>,
+                event_authority: Option<solana_pubkey::Pubkey>,
+                program: Option<solana_pubkey::Pubkey>,
                         token0_amount: Option<u64>,
                 token1_min_amount: Option<u64>,
                 expiration: Option<i64>,
+                referral_fee_bps: Option<u16>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -214,12 +276,14 @@ impl SwapBuilder {
                         self.token1_signer_token_account = Some(token1_signer_token_account);
                     self
     }
-            #[inline(always)]
+            /// The pool's vault for `token_0_mint`
+#[inline(always)]
     pub fn token0_vault(&mut self, token0_vault: solana_pubkey::Pubkey) -> &mut Self {
                         self.token0_vault = Some(token0_vault);
                     self
     }
-            #[inline(always)]
+            /// The pool's vault for `token_1_mint`
+#[inline(always)]
     pub fn token1_vault(&mut self, token1_vault: solana_pubkey::Pubkey) -> &mut Self {
                         self.token1_vault = Some(token1_vault);
                     self
@@ -234,7 +298,8 @@ impl SwapBuilder {
                         self.token1_mint = Some(token1_mint);
                     self
     }
-            #[inline(always)]
+            /// The pool's LP mint
+#[inline(always)]
     pub fn lp_token_mint(&mut self, lp_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.lp_token_mint = Some(lp_token_mint);
                     self
@@ -243,6 +308,16 @@ impl SwapBuilder {
     pub fn liquidity_pool(&mut self, liquidity_pool: solana_pubkey::Pubkey) -> &mut Self {
                         self.liquidity_pool = Some(liquidity_pool);
                     self
+    }
+            #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: solana_pubkey::Pubkey) -> &mut Self {
+                        self.pool_stats = Some(pool_stats);
+                    self
+    }
+            #[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
     }
             /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
 #[inline(always)]
@@ -261,6 +336,23 @@ impl SwapBuilder {
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            /// Receives the referral fee, in the input mint
+/// `[optional account]`
+#[inline(always)]
+    pub fn referrer_token_account(&mut self, referrer_token_account: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer_token_account = referrer_token_account;
+                    self
+    }
+            #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+                        self.event_authority = Some(event_authority);
+                    self
+    }
+            #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.program = Some(program);
+                    self
     }
                     #[inline(always)]
       pub fn token0_amount(&mut self, token0_amount: u64) -> &mut Self {
@@ -276,6 +368,11 @@ impl SwapBuilder {
       pub fn expiration(&mut self, expiration: i64) -> &mut Self {
         self.expiration = Some(expiration);
         self
+      }
+                #[inline(always)]
+      pub fn referral_fee_bps(&mut self, referral_fee_bps: u16) -> &mut Self {
+        self.referral_fee_bps = Some(referral_fee_bps);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -301,14 +398,20 @@ impl SwapBuilder {
                                         token1_mint: self.token1_mint.expect("token1_mint is not set"),
                                         lp_token_mint: self.lp_token_mint.expect("lp_token_mint is not set"),
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        pool_stats: self.pool_stats.expect("pool_stats is not set"),
+                                        global_state: self.global_state.expect("global_state is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                                        referrer_token_account: self.referrer_token_account,
+                                        event_authority: self.event_authority.expect("event_authority is not set"),
+                                        program: self.program.expect("program is not set"),
                       };
           let args = SwapInstructionArgs {
                                                               token0_amount: self.token0_amount.clone().expect("token0_amount is not set"),
                                                                   token1_min_amount: self.token1_min_amount.clone().expect("token1_min_amount is not set"),
                                                                   expiration: self.expiration.clone().expect("expiration is not set"),
+                                                                  referral_fee_bps: self.referral_fee_bps.clone().expect("referral_fee_bps is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -326,10 +429,14 @@ impl SwapBuilder {
                 
                     
               pub token1_signer_token_account: &'b solana_account_info::AccountInfo<'a>,
-                
+                        /// The pool's vault for `token_0_mint`
+
+      
                     
               pub token0_vault: &'b solana_account_info::AccountInfo<'a>,
-                
+                        /// The pool's vault for `token_1_mint`
+
+      
                     
               pub token1_vault: &'b solana_account_info::AccountInfo<'a>,
                 
@@ -338,7 +445,9 @@ impl SwapBuilder {
                 
                     
               pub token1_mint: &'b solana_account_info::AccountInfo<'a>,
-                
+                        /// The pool's LP mint
+
+      
                     
               pub lp_token_mint: &'b solana_account_info::AccountInfo<'a>,
                 
@@ -346,6 +455,12 @@ impl SwapBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -353,6 +468,17 @@ impl SwapBuilder {
                 
                     
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                        /// Receives the referral fee, in the input mint
+
+      
+                    
+              pub referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `swap` CPI instruction.
@@ -368,10 +494,14 @@ pub struct SwapCpi<'a, 'b> {
           
               
           pub token1_signer_token_account: &'b solana_account_info::AccountInfo<'a>,
-          
+                /// The pool's vault for `token_0_mint`
+
+    
               
           pub token0_vault: &'b solana_account_info::AccountInfo<'a>,
-          
+                /// The pool's vault for `token_1_mint`
+
+    
               
           pub token1_vault: &'b solana_account_info::AccountInfo<'a>,
           
@@ -380,7 +510,9 @@ pub struct SwapCpi<'a, 'b> {
           
               
           pub token1_mint: &'b solana_account_info::AccountInfo<'a>,
-          
+                /// The pool's LP mint
+
+    
               
           pub lp_token_mint: &'b solana_account_info::AccountInfo<'a>,
           
@@ -388,6 +520,12 @@ pub struct SwapCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -395,6 +533,17 @@ pub struct SwapCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                /// Receives the referral fee, in the input mint
+
+    
+              
+          pub referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub program: &'b solana_account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: SwapInstructionArgs,
   }
@@ -416,9 +565,14 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
               token1_mint: accounts.token1_mint,
               lp_token_mint: accounts.lp_token_mint,
               liquidity_pool: accounts.liquidity_pool,
+              pool_stats: accounts.pool_stats,
+              global_state: accounts.global_state,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
+              referrer_token_account: accounts.referrer_token_account,
+              event_authority: accounts.event_authority,
+              program: accounts.program,
                     __args: args,
           }
   }
@@ -442,7 +596,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(17+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -478,6 +632,14 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.pool_stats.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.global_state.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
@@ -490,6 +652,25 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          if let Some(referrer_token_account) = self.referrer_token_account {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_token_account.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ANCHOR_AMM_ID,
+                false,
+              ));
+            }
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -507,7 +688,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(18 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.token0_signer_token_account.clone());
@@ -518,9 +699,16 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
                         account_infos.push(self.token1_mint.clone());
                         account_infos.push(self.lp_token_mint.clone());
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.pool_stats.clone());
+                        account_infos.push(self.global_state.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
+                        if let Some(referrer_token_account) = self.referrer_token_account {
+          account_infos.push(referrer_token_account.clone());
+        }
+                        account_infos.push(self.event_authority.clone());
+                        account_infos.push(self.program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -544,9 +732,14 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
           ///   6. `[]` token1_mint
                 ///   7. `[writable]` lp_token_mint
                 ///   8. `[writable]` liquidity_pool
-          ///   9. `[]` token_program
-          ///   10. `[]` associated_token_program
-          ///   11. `[]` system_program
+                ///   9. `[writable]` pool_stats
+          ///   10. `[]` global_state
+          ///   11. `[]` token_program
+          ///   12. `[]` associated_token_program
+          ///   13. `[]` system_program
+                      ///   14. `[writable, optional]` referrer_token_account
+          ///   15. `[]` event_authority
+          ///   16. `[]` program
 #[derive(Clone, Debug)]
 pub struct SwapCpiBuilder<'a, 'b> {
   instruction: Box<SwapCpiBuilderInstruction<'a, 'b>>,
@@ -565,12 +758,18 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
               token1_mint: None,
               lp_token_mint: None,
               liquidity_pool: None,
+              pool_stats: None,
+              global_state: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
+              referrer_token_account: None,
+              event_authority: None,
+              program: None,
                                             token0_amount: None,
                                 token1_min_amount: None,
                                 expiration: None,
+                                referral_fee_bps: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -590,12 +789,14 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                         self.instruction.token1_signer_token_account = Some(token1_signer_token_account);
                     self
     }
-      #[inline(always)]
+      /// The pool's vault for `token_0_mint`
+#[inline(always)]
     pub fn token0_vault(&mut self, token0_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token0_vault = Some(token0_vault);
                     self
     }
-      #[inline(always)]
+      /// The pool's vault for `token_1_mint`
+#[inline(always)]
     pub fn token1_vault(&mut self, token1_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token1_vault = Some(token1_vault);
                     self
@@ -610,7 +811,8 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                         self.instruction.token1_mint = Some(token1_mint);
                     self
     }
-      #[inline(always)]
+      /// The pool's LP mint
+#[inline(always)]
     pub fn lp_token_mint(&mut self, lp_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.lp_token_mint = Some(lp_token_mint);
                     self
@@ -621,6 +823,16 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.pool_stats = Some(pool_stats);
+                    self
+    }
+      #[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
+    }
+      #[inline(always)]
     pub fn token_program(&mut self, token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token_program = Some(token_program);
                     self
@@ -634,6 +846,23 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+      /// Receives the referral fee, in the input mint
+/// `[optional account]`
+#[inline(always)]
+    pub fn referrer_token_account(&mut self, referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer_token_account = referrer_token_account;
+                    self
+    }
+      #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.event_authority = Some(event_authority);
+                    self
+    }
+      #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.program = Some(program);
+                    self
     }
                     #[inline(always)]
       pub fn token0_amount(&mut self, token0_amount: u64) -> &mut Self {
@@ -649,6 +878,11 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
       pub fn expiration(&mut self, expiration: i64) -> &mut Self {
         self.instruction.expiration = Some(expiration);
         self
+      }
+                #[inline(always)]
+      pub fn referral_fee_bps(&mut self, referral_fee_bps: u16) -> &mut Self {
+        self.instruction.referral_fee_bps = Some(referral_fee_bps);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -676,6 +910,7 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                                                               token0_amount: self.instruction.token0_amount.clone().expect("token0_amount is not set"),
                                                                   token1_min_amount: self.instruction.token1_min_amount.clone().expect("token1_min_amount is not set"),
                                                                   expiration: self.instruction.expiration.clone().expect("expiration is not set"),
+                                                                  referral_fee_bps: self.instruction.referral_fee_bps.clone().expect("referral_fee_bps is not set"),
                                     };
         let instruction = SwapCpi {
         __program: self.instruction.__program,
@@ -698,11 +933,21 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          pool_stats: self.instruction.pool_stats.expect("pool_stats is not set"),
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+                  
+          referrer_token_account: self.instruction.referrer_token_account,
+                  
+          event_authority: self.instruction.event_authority.expect("event_authority is not set"),
+                  
+          program: self.instruction.program.expect("program is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -721,12 +966,18 @@ struct SwapCpiBuilderInstruction<'a, 'b> {
                 token1_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                pool_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         token0_amount: Option<u64>,
                 token1_min_amount: Option<u64>,
                 expiration: Option<i64>,
+                referral_fee_bps: Option<u16>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
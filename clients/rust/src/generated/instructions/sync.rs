@@ -31,6 +31,12 @@ pub struct Sync {
           
               
           pub token_program: solana_pubkey::Pubkey,
+          
+              
+          pub event_authority: solana_pubkey::Pubkey,
+          
+              
+          pub program: solana_pubkey::Pubkey,
       }
 
 impl Sync {
@@ -40,7 +46,7 @@ impl Sync {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(8+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
@@ -64,6 +70,14 @@ impl Sync {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let data = SyncInstructionData::new().try_to_vec().unwrap();
@@ -112,6 +126,8 @@ impl Default for SyncInstructionData {
                 ///   3. `[writable]` token_a_vault
                 ///   4. `[writable]` token_b_vault
                 ///   5. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   6. `[]` event_authority
+          ///   7. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct SyncBuilder {
             liquidity_pool: Option<solana_pubkey::Pubkey>,
@@ -120,6 +136,8 @@ pub struct SyncBuilder {
                 token_a_vault: Option<solana_pubkey::Pubkey>,
                 token_b_vault: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
+                event_authority: Option<solana_pubkey::Pubkey>,
+                program: Option<solana_pubkey::Pubkey>,
                 __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -157,6 +175,16 @@ impl SyncBuilder {
     pub fn token_program(&mut self, token_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.token_program = Some(token_program);
                     self
+    }
+            #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+                        self.event_authority = Some(event_authority);
+                    self
+    }
+            #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.program = Some(program);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -179,6 +207,8 @@ impl SyncBuilder {
                                         token_a_vault: self.token_a_vault.expect("token_a_vault is not set"),
                                         token_b_vault: self.token_b_vault.expect("token_b_vault is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        event_authority: self.event_authority.expect("event_authority is not set"),
+                                        program: self.program.expect("program is not set"),
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -205,6 +235,12 @@ impl SyncBuilder {
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `sync` CPI instruction.
@@ -229,6 +265,12 @@ pub struct SyncCpi<'a, 'b> {
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub program: &'b solana_account_info::AccountInfo<'a>,
         }
 
 impl<'a, 'b> SyncCpi<'a, 'b> {
@@ -244,6 +286,8 @@ impl<'a, 'b> SyncCpi<'a, 'b> {
               token_a_vault: accounts.token_a_vault,
               token_b_vault: accounts.token_b_vault,
               token_program: accounts.token_program,
+              event_authority: accounts.event_authority,
+              program: accounts.program,
                 }
   }
   #[inline(always)]
@@ -266,7 +310,7 @@ impl<'a, 'b> SyncCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(8+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
@@ -290,6 +334,14 @@ impl<'a, 'b> SyncCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -305,7 +357,7 @@ impl<'a, 'b> SyncCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.liquidity_pool.clone());
                         account_infos.push(self.token_a_mint.clone());
@@ -313,6 +365,8 @@ impl<'a, 'b> SyncCpi<'a, 'b> {
                         account_infos.push(self.token_a_vault.clone());
                         account_infos.push(self.token_b_vault.clone());
                         account_infos.push(self.token_program.clone());
+                        account_infos.push(self.event_authority.clone());
+                        account_infos.push(self.program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -333,6 +387,8 @@ impl<'a, 'b> SyncCpi<'a, 'b> {
                 ///   3. `[writable]` token_a_vault
                 ///   4. `[writable]` token_b_vault
           ///   5. `[]` token_program
+          ///   6. `[]` event_authority
+          ///   7. `[]` program
 #[derive(Clone, Debug)]
 pub struct SyncCpiBuilder<'a, 'b> {
   instruction: Box<SyncCpiBuilderInstruction<'a, 'b>>,
@@ -348,6 +404,8 @@ impl<'a, 'b> SyncCpiBuilder<'a, 'b> {
               token_a_vault: None,
               token_b_vault: None,
               token_program: None,
+              event_authority: None,
+              program: None,
                                 __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -381,6 +439,16 @@ impl<'a, 'b> SyncCpiBuilder<'a, 'b> {
     pub fn token_program(&mut self, token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token_program = Some(token_program);
                     self
+    }
+      #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.event_authority = Some(event_authority);
+                    self
+    }
+      #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.program = Some(program);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -418,6 +486,10 @@ impl<'a, 'b> SyncCpiBuilder<'a, 'b> {
           token_b_vault: self.instruction.token_b_vault.expect("token_b_vault is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
+                  
+          event_authority: self.instruction.event_authority.expect("event_authority is not set"),
+                  
+          program: self.instruction.program.expect("program is not set"),
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
@@ -432,6 +504,8 @@ struct SyncCpiBuilderInstruction<'a, 'b> {
                 token_a_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_b_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -45,6 +45,12 @@ pub struct Withdraw {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub pool_stats: solana_pubkey::Pubkey,
+          
+              
+          pub global_state: solana_pubkey::Pubkey,
+          
+              
           pub fee_lp_token_account: solana_pubkey::Pubkey,
           
               
@@ -55,6 +61,12 @@ pub struct Withdraw {
           
               
           pub system_program: solana_pubkey::Pubkey,
+          
+              
+          pub event_authority: solana_pubkey::Pubkey,
+          
+              
+          pub program: solana_pubkey::Pubkey,
       }
 
 impl Withdraw {
@@ -64,7 +76,7 @@ impl Withdraw {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: WithdrawInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(14+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(18+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
@@ -104,6 +116,14 @@ impl Withdraw {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.pool_stats,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.global_state,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.fee_lp_token_account,
@@ -120,6 +140,14 @@ impl Withdraw {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = WithdrawInstructionData::new().try_to_vec().unwrap();
@@ -188,10 +216,14 @@ impl WithdrawInstructionArgs {
           ///   7. `[]` token_b_mint
                 ///   8. `[writable]` lp_token_mint
                 ///   9. `[writable]` liquidity_pool
-                ///   10. `[writable]` fee_lp_token_account
-                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   12. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   10. `[writable]` pool_stats
+          ///   11. `[]` global_state
+                ///   12. `[writable]` fee_lp_token_account
+                ///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   14. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   16. `[]` event_authority
+          ///   17. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawBuilder {
             signer: Option<solana_pubkey::Pubkey>,
@@ -204,10 +236,14 @@ pub struct WithdrawBuilder {
                 token_b_mint: Option<solana_pubkey::Pubkey>,
                 lp_token_mint: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                pool_stats: Option<solana_pubkey::Pubkey>,
+                global_state: Option<solana_pubkey::Pubkey>,
                 fee_lp_token_account: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
+                event_authority: Option<solana_pubkey::Pubkey>,
+                program: Option<solana_pubkey::Pubkey>,
                         lp_amount_to_burn: Option<u64>,
                 amount_a_min: Option<u64>,
                 amount_b_min: Option<u64>,
@@ -270,6 +306,16 @@ impl WithdrawBuilder {
                     self
     }
             #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: solana_pubkey::Pubkey) -> &mut Self {
+                        self.pool_stats = Some(pool_stats);
+                    self
+    }
+            #[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
+    }
+            #[inline(always)]
     pub fn fee_lp_token_account(&mut self, fee_lp_token_account: solana_pubkey::Pubkey) -> &mut Self {
                         self.fee_lp_token_account = Some(fee_lp_token_account);
                     self
@@ -291,6 +337,16 @@ impl WithdrawBuilder {
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+                        self.event_authority = Some(event_authority);
+                    self
+    }
+            #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.program = Some(program);
+                    self
     }
                     #[inline(always)]
       pub fn lp_amount_to_burn(&mut self, lp_amount_to_burn: u64) -> &mut Self {
@@ -337,10 +393,14 @@ impl WithdrawBuilder {
                                         token_b_mint: self.token_b_mint.expect("token_b_mint is not set"),
                                         lp_token_mint: self.lp_token_mint.expect("lp_token_mint is not set"),
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        pool_stats: self.pool_stats.expect("pool_stats is not set"),
+                                        global_state: self.global_state.expect("global_state is not set"),
                                         fee_lp_token_account: self.fee_lp_token_account.expect("fee_lp_token_account is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                                        event_authority: self.event_authority.expect("event_authority is not set"),
+                                        program: self.program.expect("program is not set"),
                       };
           let args = WithdrawInstructionArgs {
                                                               lp_amount_to_burn: self.lp_amount_to_burn.clone().expect("lp_amount_to_burn is not set"),
@@ -387,6 +447,12 @@ impl WithdrawBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub fee_lp_token_account: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -397,6 +463,12 @@ impl WithdrawBuilder {
                 
                     
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `withdraw` CPI instruction.
@@ -435,6 +507,12 @@ pub struct WithdrawCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub pool_stats: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub fee_lp_token_account: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -445,6 +523,12 @@ pub struct WithdrawCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub program: &'b solana_account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: WithdrawInstructionArgs,
   }
@@ -467,10 +551,14 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
               token_b_mint: accounts.token_b_mint,
               lp_token_mint: accounts.lp_token_mint,
               liquidity_pool: accounts.liquidity_pool,
+              pool_stats: accounts.pool_stats,
+              global_state: accounts.global_state,
               fee_lp_token_account: accounts.fee_lp_token_account,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
+              event_authority: accounts.event_authority,
+              program: accounts.program,
                     __args: args,
           }
   }
@@ -494,7 +582,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(14+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(18+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -534,6 +622,14 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.pool_stats.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.global_state.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.fee_lp_token_account.key,
@@ -550,6 +646,14 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -567,7 +671,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(19 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.lp_token_signer_token_account.clone());
@@ -579,10 +683,14 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
                         account_infos.push(self.token_b_mint.clone());
                         account_infos.push(self.lp_token_mint.clone());
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.pool_stats.clone());
+                        account_infos.push(self.global_state.clone());
                         account_infos.push(self.fee_lp_token_account.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.event_authority.clone());
+                        account_infos.push(self.program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -607,10 +715,14 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
           ///   7. `[]` token_b_mint
                 ///   8. `[writable]` lp_token_mint
                 ///   9. `[writable]` liquidity_pool
-                ///   10. `[writable]` fee_lp_token_account
-          ///   11. `[]` token_program
-          ///   12. `[]` associated_token_program
-          ///   13. `[]` system_program
+                ///   10. `[writable]` pool_stats
+          ///   11. `[]` global_state
+                ///   12. `[writable]` fee_lp_token_account
+          ///   13. `[]` token_program
+          ///   14. `[]` associated_token_program
+          ///   15. `[]` system_program
+          ///   16. `[]` event_authority
+          ///   17. `[]` program
 #[derive(Clone, Debug)]
 pub struct WithdrawCpiBuilder<'a, 'b> {
   instruction: Box<WithdrawCpiBuilderInstruction<'a, 'b>>,
@@ -630,10 +742,14 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
               token_b_mint: None,
               lp_token_mint: None,
               liquidity_pool: None,
+              pool_stats: None,
+              global_state: None,
               fee_lp_token_account: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
+              event_authority: None,
+              program: None,
                                             lp_amount_to_burn: None,
                                 amount_a_min: None,
                                 amount_b_min: None,
@@ -693,6 +809,16 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn pool_stats(&mut self, pool_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.pool_stats = Some(pool_stats);
+                    self
+    }
+      #[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
+    }
+      #[inline(always)]
     pub fn fee_lp_token_account(&mut self, fee_lp_token_account: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.fee_lp_token_account = Some(fee_lp_token_account);
                     self
@@ -711,6 +837,16 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+      #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.event_authority = Some(event_authority);
+                    self
+    }
+      #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.program = Some(program);
+                    self
     }
                     #[inline(always)]
       pub fn lp_amount_to_burn(&mut self, lp_amount_to_burn: u64) -> &mut Self {
@@ -783,6 +919,10 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          pool_stats: self.instruction.pool_stats.expect("pool_stats is not set"),
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
           fee_lp_token_account: self.instruction.fee_lp_token_account.expect("fee_lp_token_account is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
@@ -790,6 +930,10 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+                  
+          event_authority: self.instruction.event_authority.expect("event_authority is not set"),
+                  
+          program: self.instruction.program.expect("program is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -809,10 +953,14 @@ struct WithdrawCpiBuilderInstruction<'a, 'b> {
                 token_b_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                pool_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
                 fee_lp_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         lp_amount_to_burn: Option<u64>,
                 amount_a_min: Option<u64>,
                 amount_b_min: Option<u64>,
@@ -10,5 +10,6 @@
         pub mod instructions;
         pub mod programs;
         pub mod shared;
+        pub mod types;
     
   pub(crate) use programs::*;
@@ -9,5 +9,5 @@ use solana_pubkey::{pubkey, Pubkey};
 
 
   /// `anchor_amm` program ID.
-  pub const ANCHOR_AMM_ID: Pubkey = pubkey!("7Zk1fV2VY517YbBFtWV76K58msXYcBjGoqsTauox1GcQ");
+  pub const ANCHOR_AMM_ID: Pubkey = pubkey!("2sU17QtTHDBCr4Q3pZGVUjE71fFyfjiuAjVGqRP91Be5");
 
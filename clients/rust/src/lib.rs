@@ -0,0 +1,10 @@
+//! Rust client for the `anchor_amm` program.
+//!
+//! `generated` is produced by codama from the program IDL, see `codama.json`;
+//! everything else is written by hand.
+
+pub mod generated;
+pub mod return_data;
+
+pub use generated::programs::ANCHOR_AMM_ID as ID;
+pub use generated::*;
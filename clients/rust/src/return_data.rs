@@ -0,0 +1,33 @@
+//! Typed decoding of the return data set by `swap`, `swap_v2`, `deposit` and `withdraw`.
+//!
+//! Lives outside `generated` so that rerunning codama doesn't drop it. Call these
+//! right after invoking the instruction, e.g. with `SwapCpi::invoke`, before any
+//! other CPI overwrites the return data.
+
+use borsh::BorshDeserialize;
+
+use crate::generated::types::{DepositResult, SwapResult, WithdrawResult};
+
+/// The return data of the last CPI, if `anchor_amm` set it and it decodes as `T`.
+fn decode<T: BorshDeserialize>() -> Option<T> {
+    let (program_id, data) = solana_cpi::get_return_data()?;
+    if program_id != crate::ANCHOR_AMM_ID {
+        return None;
+    }
+    T::try_from_slice(&data).ok()
+}
+
+/// The `SwapResult` set by the last `swap` or `swap_v2` invocation.
+pub fn swap_result() -> Option<SwapResult> {
+    decode()
+}
+
+/// The `DepositResult` set by the last `deposit` invocation.
+pub fn deposit_result() -> Option<DepositResult> {
+    decode()
+}
+
+/// The `WithdrawResult` set by the last `withdraw` invocation.
+pub fn withdraw_result() -> Option<WithdrawResult> {
+    decode()
+}
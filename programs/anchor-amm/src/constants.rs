@@ -2,3 +2,124 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const LIQUIDITY_POOL_SEED: &str = "liquidity_pool";
+
+#[constant]
+pub const POOL_STATS_SEED: &str = "pool_stats";
+
+#[constant]
+pub const CONCENTRATED_POOL_SEED: &str = "concentrated_pool";
+
+#[constant]
+pub const TICK_ARRAY_SEED: &str = "tick_array";
+
+#[constant]
+pub const POSITION_SEED: &str = "position";
+
+/// Layout version written to `LiquidityPool::version`; pools from before versioning are version 0
+#[constant]
+pub const LIQUIDITY_POOL_VERSION: u8 = 2;
+
+#[constant]
+pub const GLOBAL_STATE_SEED: &str = "global_state";
+
+/// `paused` flag halting swaps
+#[constant]
+pub const PAUSE_SWAP: u8 = 1 << 0;
+
+/// `paused` flag halting deposits
+#[constant]
+pub const PAUSE_DEPOSIT: u8 = 1 << 1;
+
+/// `paused` flag halting withdrawals, never set unless asked for so LPs can exit.
+/// Only the protocol admin may set it, globally, so pool admins can't trap LPs.
+#[constant]
+pub const PAUSE_WITHDRAW: u8 = 1 << 2;
+
+/// `paused` flag halting skims
+#[constant]
+pub const PAUSE_SKIM: u8 = 1 << 3;
+
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_SWAP | PAUSE_DEPOSIT | PAUSE_WITHDRAW | PAUSE_SKIM;
+
+/// Swap fee of two-token pools in basis points, and the base fee in dynamic-fee mode
+#[constant]
+pub const SWAP_FEE_BPS: u64 = 30;
+
+/// Upper bound on the dynamic swap fee cap (10%)
+#[constant]
+pub const MAX_SWAP_FEE_BPS: u16 = 1_000;
+
+/// Largest referral fee a swap may pay out of its input, in basis points (1%)
+#[constant]
+pub const MAX_REFERRAL_FEE_BPS: u16 = 100;
+
+/// Largest share of swap fees a pool creator may take, in basis points (25%)
+#[constant]
+pub const MAX_CREATOR_FEE_BPS: u16 = 2_500;
+
+/// Largest share of a deposit's LP tokens a pool may withhold, in basis points (1%)
+#[constant]
+pub const MAX_DEPOSIT_FEE_BPS: u16 = 100;
+
+/// Most of each token, in raw units, that `close_pool` burns from the vaults
+#[constant]
+pub const MAX_CLOSE_POOL_DUST: u64 = 1_000_000;
+
+/// Upper bound on the initial anti-snipe launch fee (50%)
+#[constant]
+pub const MAX_LAUNCH_FEE_BPS: u16 = 5_000;
+
+/// Number of initializable ticks stored in a single `TickArray`
+pub const TICK_ARRAY_SIZE: usize = 60;
+
+/// Ticks are bounded so that 1.0001^(tick / 2) stays representable as a Q64.64 sqrt price
+#[constant]
+pub const MIN_TICK_INDEX: i32 = -443636;
+
+#[constant]
+pub const MAX_TICK_INDEX: i32 = 443636;
+
+/// Upper bound on the StableSwap amplification coefficient
+#[constant]
+pub const MAX_AMP: u64 = 1_000_000;
+
+/// A single ramp may move the amplification coefficient by at most this factor
+#[constant]
+pub const MAX_AMP_CHANGE: u64 = 10;
+
+/// Minimum duration of an amplification ramp, and minimum gap between ramp starts
+#[constant]
+pub const MIN_AMP_RAMP_DURATION: i64 = 86_400;
+
+/// Newton iterations allowed when solving the StableSwap invariant
+pub const STABLE_MAX_ITERATIONS: u32 = 255;
+
+/// Weighted pool weights are expressed in basis points and must sum to this
+#[constant]
+pub const WEIGHT_DENOMINATOR: u16 = 10_000;
+
+/// Smallest weight a token may have in a weighted pool (1%)
+#[constant]
+pub const MIN_WEIGHT_BPS: u16 = 100;
+
+/// Largest swap input or output on a weighted pool, as a share of the matching reserve (30%)
+#[constant]
+pub const MAX_WEIGHTED_SWAP_RATIO_BPS: u64 = 3_000;
+
+/// Constant-sum price bands are expressed in basis points of the pool's normalized reserves
+#[constant]
+pub const PRICE_BAND_DENOMINATOR: u16 = 10_000;
+
+#[constant]
+pub const MULTI_ASSET_POOL_SEED: &str = "multi_asset_pool";
+
+/// Largest number of tokens a `MultiAssetPool` can hold
+pub const MAX_MULTI_ASSET_TOKENS: usize = 8;
+
+/// Swap fee charged by multi-asset pools, also applied to single-token joins and exits
+#[constant]
+pub const MULTI_ASSET_SWAP_FEE_BPS: u64 = 30;
+
+#[constant]
+pub const MULTI_ASSET_LP_DECIMALS: u8 = 9;
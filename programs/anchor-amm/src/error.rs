@@ -9,6 +9,8 @@ pub enum MathError {
     DivisionByZero,
     #[msg("Token precision must be between 1 and 12 decimals")]
     InvalidPrecision,
+    #[msg("Logarithm is only defined for positive numbers")]
+    LogarithmOfZero,
 }
 
 /// AMM protocol errors
@@ -38,4 +40,132 @@ pub enum AmmError {
     // Skim operation
     #[msg("No excess tokens in vault to skim")]
     NoExcessTokens,
+
+    // Concentrated liquidity
+    #[msg("Tick spacing must be between 1 and 16384")]
+    InvalidTickSpacing,
+    #[msg("Fee rate must be below 10000 bps")]
+    InvalidFeeRate,
+    #[msg("Tick index is out of bounds or not a multiple of the tick spacing")]
+    InvalidTickIndex,
+    #[msg("Lower tick must be below upper tick")]
+    InvalidTickRange,
+    #[msg("Tick array does not belong to this pool or does not contain the tick")]
+    InvalidTickArray,
+    #[msg("Sqrt price is out of bounds")]
+    SqrtPriceOutOfBounds,
+
+    // StableSwap
+    #[msg("Amplification coefficient must be between 1 and MAX_AMP")]
+    InvalidAmp,
+    #[msg("Amplification ramp is too short, too steep or started too recently")]
+    InvalidAmpRamp,
+    #[msg("Operation is only supported on StableSwap pools")]
+    NotStablePool,
+    #[msg("StableSwap invariant did not converge")]
+    InvariantDidNotConverge,
+
+    // Weighted pools
+    #[msg("Weights must each be at least MIN_WEIGHT_BPS and sum to WEIGHT_DENOMINATOR")]
+    InvalidWeights,
+    #[msg("Swap input exceeds the maximum share of the input reserve")]
+    MaxInRatioExceeded,
+    #[msg("Swap output exceeds the maximum share of the output reserve")]
+    MaxOutRatioExceeded,
+
+    // Liquidity bootstrapping pools
+    #[msg("Liquidity bootstrapping schedule must end after it starts")]
+    InvalidLbpSchedule,
+    #[msg("Liquidity can only be withdrawn once the bootstrapping period has ended")]
+    LbpNotEnded,
+
+    // Constant-sum pools
+    #[msg("Price band must be below half of PRICE_BAND_DENOMINATOR")]
+    InvalidPriceBand,
+    #[msg("Swap would move the pool outside its price band")]
+    PriceBandExceeded,
+    #[msg("Operation is only supported on constant-sum pools")]
+    NotConstantSumPool,
+
+    // Oracles and PMM pools
+    #[msg("Oracle account is missing or not a valid price account")]
+    InvalidOracle,
+    #[msg("Oracle price is older than the pool's maximum age")]
+    StaleOracle,
+    #[msg("Oracle confidence interval is wider than the pool allows")]
+    OracleConfidenceTooWide,
+    #[msg("Pool price after the swap deviates too far from the reference oracle")]
+    OraclePriceDeviation,
+    #[msg("Maximum oracle deviation must be at most 10000 bps")]
+    InvalidOracleDeviation,
+    #[msg("PMM liquidity parameter k must be at most 1 (WAD)")]
+    InvalidPmmK,
+    #[msg("Operation is not supported by this pool's curve")]
+    UnsupportedCurveOperation,
+
+    // Dynamic fees
+    #[msg("Dynamic fee cap must be between SWAP_FEE_BPS and MAX_SWAP_FEE_BPS with a positive half-life")]
+    InvalidDynamicFee,
+
+    // Referrals
+    #[msg("Referral fee exceeds MAX_REFERRAL_FEE_BPS or has no referrer token account")]
+    InvalidReferralFee,
+
+    // Creator fees
+    #[msg("Creator fee exceeds MAX_CREATOR_FEE_BPS")]
+    InvalidCreatorFee,
+    #[msg("No creator fees to claim")]
+    NoCreatorFees,
+
+    // Deposit fees
+    #[msg("Deposit fee exceeds MAX_DEPOSIT_FEE_BPS")]
+    InvalidDepositFee,
+
+    // Migrations
+    #[msg("Account is not a liquidity pool in a layout migrate_pool can upgrade")]
+    InvalidPoolLayout,
+
+    // Closing pools
+    #[msg("Pool still has liquidity beyond the locked minimum")]
+    PoolNotEmpty,
+    #[msg("Vaults hold more than the dust a closing pool may burn")]
+    PoolDustExceeded,
+
+    // Pausing
+    #[msg("This operation is paused")]
+    Paused,
+    #[msg("Pause flags must be a combination of PAUSE_* flags")]
+    InvalidPauseFlags,
+    #[msg("Withdrawals can only be paused globally")]
+    PoolWithdrawPause,
+
+    // Reentrancy
+    #[msg("Pool is locked by an instruction in progress")]
+    PoolLocked,
+
+    // Sandwich protection
+    #[msg("Transaction also swaps this pool in the opposite direction")]
+    OppositeSwapInTransaction,
+    #[msg("Swaps in this slot moved the price more than max_slot_price_move_bps")]
+    SlotPriceMoveExceeded,
+
+    // Launch protection
+    #[msg("Trading has not started on this pool yet")]
+    TradingNotStarted,
+    #[msg("Swap output exceeds the launch window's maximum buy")]
+    LaunchMaxBuyExceeded,
+    #[msg("Launch fee must be at most MAX_LAUNCH_FEE_BPS with a non-negative duration")]
+    InvalidLaunchParams,
+    #[msg("Launch parameters can only be set before the pool holds liquidity")]
+    LaunchAlreadyStarted,
+
+    // Multi-asset pools
+    #[msg("Multi-asset pools hold between 3 and MAX_MULTI_ASSET_TOKENS tokens")]
+    InvalidTokenCount,
+    #[msg("Remaining accounts don't match the pool's tokens")]
+    InvalidRemainingAccounts,
+
+    // Access control
+    #[msg("Signer is not the pool admin")]
+    Unauthorized,
 }
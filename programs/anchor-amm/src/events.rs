@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+
+/// Emitted once when a new pool and its LP mint are created.
+#[event]
+pub struct PoolInitialized {
+    pub liquidity_pool: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub liquidity_pool: Pubkey,
+    pub user: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub lp_tokens_minted: u64,
+    /// LP tokens withheld from the depositor
+    pub deposit_fee: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub liquidity_pool: Pubkey,
+    pub user: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub lp_tokens_burned: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+#[event]
+pub struct Swapped {
+    pub liquidity_pool: Pubkey,
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// Portion of `amount_in` kept by the pool as the LP fee
+    pub fee: u64,
+    /// Portion of `amount_in` paid to the referrer before reaching the pool
+    pub referral_fee: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+#[event]
+pub struct ReservesSynced {
+    pub liquidity_pool: Pubkey,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+#[event]
+pub struct ReservesSkimmed {
+    pub liquidity_pool: Pubkey,
+    pub recipient: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+}
+
+/// Emitted whenever accumulated swap fees are minted as LP tokens to the fee account.
+#[event]
+pub struct ProtocolFeeMinted {
+    pub liquidity_pool: Pubkey,
+    pub lp_tokens_minted: u64,
+    pub creator_lp_tokens_minted: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+#[event]
+pub struct ConcentratedPoolInitialized {
+    pub concentrated_pool: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub tick_spacing: u16,
+    pub fee_rate_bps: u16,
+    pub sqrt_price_x64: u128,
+}
+
+#[event]
+pub struct PositionOpened {
+    pub concentrated_pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+/// Emitted by both `increase_liquidity` (positive delta) and `decrease_liquidity` (negative delta).
+#[event]
+pub struct PositionLiquidityChanged {
+    pub concentrated_pool: Pubkey,
+    pub position: Pubkey,
+    pub liquidity_delta: i128,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub position_liquidity: u128,
+}
+
+#[event]
+pub struct PositionFeesCollected {
+    pub concentrated_pool: Pubkey,
+    pub position: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+}
+
+#[event]
+pub struct ConcentratedSwapped {
+    pub concentrated_pool: Pubkey,
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub sqrt_price_x64: u128,
+    pub tick_current_index: i32,
+    pub liquidity: u128,
+}
+
+#[event]
+pub struct AmpRampStarted {
+    pub liquidity_pool: Pubkey,
+    pub amp_initial: u64,
+    pub amp_target: u64,
+    pub ramp_start_ts: i64,
+    pub ramp_stop_ts: i64,
+}
+
+#[event]
+pub struct AmpRampStopped {
+    pub liquidity_pool: Pubkey,
+    pub amp: u64,
+}
+
+#[event]
+pub struct PriceBandUpdated {
+    pub liquidity_pool: Pubkey,
+    pub price_band_bps: u16,
+}
+
+#[event]
+pub struct ReferenceOracleUpdated {
+    pub liquidity_pool: Pubkey,
+    pub oracle: Pubkey,
+    pub max_deviation_bps: u16,
+    pub max_age: u64,
+    pub max_confidence_bps: u16,
+}
+
+#[event]
+pub struct DynamicFeeUpdated {
+    pub liquidity_pool: Pubkey,
+    pub volatility_fee_factor_bps: u16,
+    pub max_swap_fee_bps: u16,
+    pub volatility_half_life: i64,
+}
+
+#[event]
+pub struct LaunchParamsUpdated {
+    pub liquidity_pool: Pubkey,
+    pub trading_starts_at: i64,
+    pub launch_fee_bps: u16,
+    pub launch_fee_duration: i64,
+    pub launch_max_buy: u64,
+}
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub liquidity_pool: Pubkey,
+    pub creator: Pubkey,
+    pub lp_tokens: u64,
+}
+
+#[event]
+pub struct CreatorTransferred {
+    pub liquidity_pool: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct PoolMigrated {
+    pub liquidity_pool: Pubkey,
+    pub version: u8,
+}
+
+/// Emitted by `close_pool`; the dust amounts were burned from the vaults.
+#[event]
+pub struct PoolClosed {
+    pub liquidity_pool: Pubkey,
+    pub payer: Pubkey,
+    pub token_a_dust: u64,
+    pub token_b_dust: u64,
+}
+
+#[event]
+pub struct GlobalPauseUpdated {
+    pub paused: u8,
+}
+
+#[event]
+pub struct PoolPauseUpdated {
+    pub liquidity_pool: Pubkey,
+    pub paused: u8,
+}
+
+#[event]
+pub struct SlotPriceMoveLimitUpdated {
+    pub liquidity_pool: Pubkey,
+    pub max_slot_price_move_bps: u16,
+}
+
+#[event]
+pub struct MultiAssetPoolInitialized {
+    pub multi_asset_pool: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub mints: Vec<Pubkey>,
+    pub weights: Vec<u16>,
+    pub creator: Pubkey,
+}
+
+/// Emitted by proportional and single-token deposits; `amounts` follows pool token order.
+#[event]
+pub struct MultiAssetLiquidityAdded {
+    pub multi_asset_pool: Pubkey,
+    pub user: Pubkey,
+    pub amounts: Vec<u64>,
+    pub lp_tokens_minted: u64,
+}
+
+/// Emitted by proportional and single-token withdrawals; `amounts` follows pool token order.
+#[event]
+pub struct MultiAssetLiquidityRemoved {
+    pub multi_asset_pool: Pubkey,
+    pub user: Pubkey,
+    pub amounts: Vec<u64>,
+    pub lp_tokens_burned: u64,
+}
+
+#[event]
+pub struct MultiAssetSwapped {
+    pub multi_asset_pool: Pubkey,
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+}
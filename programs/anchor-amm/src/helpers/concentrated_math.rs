@@ -0,0 +1,475 @@
+use crate::{
+    error::{AmmError, MathError},
+    MAX_TICK_INDEX, MIN_TICK_INDEX,
+};
+use anchor_lang::prelude::*;
+
+/// 1.0 in Q64.64 fixed point
+pub const Q64: u128 = 1 << 64;
+
+/// `sqrt_price_at_tick(MIN_TICK_INDEX)`
+pub const MIN_SQRT_PRICE_X64: u128 = 4295048017;
+/// `sqrt_price_at_tick(MAX_TICK_INDEX)`
+pub const MAX_SQRT_PRICE_X64: u128 = 79226673515401279992447579061;
+
+/// `1 / sqrt(1.0001)^(2^i)` in Q128.128, one entry per bit of the absolute tick index.
+const TICK_RATIOS_X128: [u128; 19] = [
+    0xfffcb933bd6fad37aa2d162d1a594001,
+    0xfff97272373d413259a46990580e213a,
+    0xfff2e50f5f656932ef12357cf3c7fdcc,
+    0xffe5caca7e10e4e61c3624eaa0941cd0,
+    0xffcb9843d60f6159c9db58835c926644,
+    0xff973b41fa98c081472e6896dfb254c0,
+    0xff2ea16466c96a3843ec78b326b52861,
+    0xfe5dee046a99a2a811c461f1969c3053,
+    0xfcbe86c7900a88aedcffc83b479aa3a4,
+    0xf987a7253ac413176f2b074cf7815e54,
+    0xf3392b0822b70005940c7a398e4b70f3,
+    0xe7159475a2c29b7443b29c7fa6e889d9,
+    0xd097f3bdfd2022b8845ad8f792aa5825,
+    0xa9f746462d870fdf8a65dc1f90e061e5,
+    0x70d869a156d2a1b890bb3df62baf32f7,
+    0x31be135f97d08fd981231505542fcfa6,
+    0x09aa508b5b7a84e1c677de54f3e99bc9,
+    0x005d6af8dedb81196699c329225ee604,
+    0x00002216e584f5fa1ea926041bedfe98,
+];
+
+/// Multiplies two u128 values into a 256-bit result, returned as `(hi, lo)`.
+pub fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Divides the 256-bit value `(hi, lo)` by `denominator`, returning `(quotient, remainder)`.
+///
+/// Returns `None` if the denominator is zero or the quotient doesn't fit in a u128.
+pub fn div_full(hi: u128, lo: u128, denominator: u128) -> Option<(u128, u128)> {
+    if denominator == 0 || hi >= denominator {
+        return None;
+    }
+    if hi == 0 {
+        return Some((lo / denominator, lo % denominator));
+    }
+    // Schoolbook long division, one bit of `lo` at a time
+    let mut remainder = hi;
+    let mut quotient = 0u128;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// Calculates `a * b / denominator` with a 256-bit intermediate product.
+///
+/// # Errors
+/// * `MathError::DivisionByZero` - If `denominator` is zero
+/// * `MathError::Overflow` - If the result doesn't fit in a u128
+pub fn mul_div(a: u128, b: u128, denominator: u128, round_up: bool) -> Result<u128> {
+    require_neq!(denominator, 0, MathError::DivisionByZero);
+    let (hi, lo) = full_mul(a, b);
+    let (quotient, remainder) = div_full(hi, lo, denominator).ok_or(MathError::Overflow)?;
+    if round_up && remainder > 0 {
+        return Ok(quotient.checked_add(1).ok_or(MathError::Overflow)?);
+    }
+    Ok(quotient)
+}
+
+/// Integer square root of the 256-bit value `(hi, lo)`, rounded down.
+///
+/// Newton's method starting from a power of two above the root, so the
+/// iterates decrease monotonically and every division fits in a u128.
+pub fn isqrt_full(hi: u128, lo: u128) -> u128 {
+    if hi == 0 {
+        return lo.isqrt();
+    }
+    let bits = 256 - hi.leading_zeros();
+    let mut x = if bits.div_ceil(2) >= 128 {
+        u128::MAX
+    } else {
+        1u128 << bits.div_ceil(2)
+    };
+    loop {
+        // x >= sqrt(hi, lo) > hi, so the quotient always fits
+        let (quotient, _) = div_full(hi, lo, x).unwrap_or((u128::MAX, 0));
+        let y = x / 2 + quotient / 2 + (x & quotient & 1);
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Calculates `sqrt(1.0001^tick)` as a Q64.64 fixed point number.
+///
+/// Multiplies together the precomputed ratio for every set bit of `|tick|`
+/// (the same decomposition Uniswap V3 uses), then inverts for positive ticks.
+///
+/// # Errors
+/// * `AmmError::InvalidTickIndex` - If `tick` is outside `[MIN_TICK_INDEX, MAX_TICK_INDEX]`
+pub fn sqrt_price_at_tick(tick: i32) -> Result<u128> {
+    require!(
+        (MIN_TICK_INDEX..=MAX_TICK_INDEX).contains(&tick),
+        AmmError::InvalidTickIndex
+    );
+    let abs_tick = tick.unsigned_abs();
+
+    // `None` stands for a ratio of exactly 1.0, which doesn't fit in Q128.128
+    let mut ratio: Option<u128> = None;
+    for (bit, tick_ratio) in TICK_RATIOS_X128.iter().enumerate() {
+        if abs_tick & (1 << bit) != 0 {
+            ratio = Some(match ratio {
+                None => *tick_ratio,
+                Some(ratio) => full_mul(ratio, *tick_ratio).0,
+            });
+        }
+    }
+    let Some(ratio) = ratio else {
+        return Ok(Q64);
+    };
+
+    if tick > 0 {
+        // 2^192 / ratio converts 1/sqrt(p) in Q128.128 into sqrt(p) in Q64.64
+        let (sqrt_price, _) = div_full(1 << 64, 0, ratio).ok_or(MathError::Overflow)?;
+        Ok(sqrt_price)
+    } else {
+        Ok((ratio >> 64) + u128::from(ratio as u64 != 0))
+    }
+}
+
+/// Finds the greatest tick whose sqrt price is less than or equal to `sqrt_price_x64`.
+///
+/// # Errors
+/// * `AmmError::SqrtPriceOutOfBounds` - If the price is outside `[MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64]`
+pub fn tick_at_sqrt_price(sqrt_price_x64: u128) -> Result<i32> {
+    require!(
+        (MIN_SQRT_PRICE_X64..=MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64),
+        AmmError::SqrtPriceOutOfBounds
+    );
+    let mut low = MIN_TICK_INDEX;
+    let mut high = MAX_TICK_INDEX;
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if sqrt_price_at_tick(mid)? <= sqrt_price_x64 {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Amount of token A needed to move `liquidity` between two sqrt prices.
+///
+/// Formula: `Δa = L / sqrt(p_lower) - L / sqrt(p_upper)`
+pub fn get_amount_a_delta(
+    sqrt_price_lower_x64: u128,
+    sqrt_price_upper_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u128> {
+    let at_lower = mul_div(liquidity, Q64, sqrt_price_lower_x64, round_up)?;
+    let at_upper = mul_div(liquidity, Q64, sqrt_price_upper_x64, !round_up)?;
+    Ok(at_lower.saturating_sub(at_upper))
+}
+
+/// Amount of token B needed to move `liquidity` between two sqrt prices.
+///
+/// Formula: `Δb = L * (sqrt(p_upper) - sqrt(p_lower))`
+pub fn get_amount_b_delta(
+    sqrt_price_lower_x64: u128,
+    sqrt_price_upper_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u128> {
+    let sqrt_price_diff = sqrt_price_upper_x64
+        .checked_sub(sqrt_price_lower_x64)
+        .ok_or(MathError::Overflow)?;
+    mul_div(liquidity, sqrt_price_diff, Q64, round_up)
+}
+
+/// Calculates the sqrt price after adding `amount_in` to the pool.
+///
+/// - Token A in (price falls): `sqrt(p') = L * sqrt(p) / (L + Δa * sqrt(p))`, rounded up
+/// - Token B in (price rises): `sqrt(p') = sqrt(p) + Δb / L`, rounded down
+///
+/// Rounding always favours the pool so the trader can never receive more than they paid for.
+pub fn get_next_sqrt_price_from_input(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount_in: u128,
+    a_to_b: bool,
+) -> Result<u128> {
+    if amount_in == 0 {
+        return Ok(sqrt_price_x64);
+    }
+    require_gt!(liquidity, 0, AmmError::InsufficientLiquidity);
+    if a_to_b {
+        let denominator = liquidity
+            .checked_add(mul_div(amount_in, sqrt_price_x64, Q64, false)?)
+            .ok_or(MathError::Overflow)?;
+        mul_div(liquidity, sqrt_price_x64, denominator, true)
+    } else {
+        Ok(sqrt_price_x64
+            .checked_add(mul_div(amount_in, Q64, liquidity, false)?)
+            .ok_or(MathError::Overflow)?)
+    }
+}
+
+pub struct SwapStep {
+    pub sqrt_price_next_x64: u128,
+    pub amount_in: u128,
+    pub amount_out: u128,
+    pub fee_amount: u128,
+}
+
+/// Computes a single exact-input swap step within one tick range.
+///
+/// Moves the price from `sqrt_price_current_x64` towards `sqrt_price_target_x64`,
+/// stopping early if `amount_remaining` (fees included) runs out first.
+///
+/// # Arguments
+/// * `sqrt_price_current_x64` - Current pool sqrt price
+/// * `sqrt_price_target_x64` - Sqrt price of the next tick (or price limit)
+/// * `liquidity` - Active liquidity in the current tick range
+/// * `amount_remaining` - Input still to be swapped, including fees
+/// * `fee_rate_bps` - Swap fee in basis points
+/// * `a_to_b` - Whether token A is the input (price falling)
+pub fn compute_swap_step(
+    sqrt_price_current_x64: u128,
+    sqrt_price_target_x64: u128,
+    liquidity: u128,
+    amount_remaining: u128,
+    fee_rate_bps: u16,
+    a_to_b: bool,
+) -> Result<SwapStep> {
+    let fee_rate = fee_rate_bps as u128;
+    let amount_remaining_less_fee = mul_div(amount_remaining, 10_000 - fee_rate, 10_000, false)?;
+
+    let amount_in_to_target = if a_to_b {
+        get_amount_a_delta(
+            sqrt_price_target_x64,
+            sqrt_price_current_x64,
+            liquidity,
+            true,
+        )?
+    } else {
+        get_amount_b_delta(
+            sqrt_price_current_x64,
+            sqrt_price_target_x64,
+            liquidity,
+            true,
+        )?
+    };
+
+    let reaches_target = amount_remaining_less_fee >= amount_in_to_target;
+    let sqrt_price_next_x64 = if reaches_target {
+        sqrt_price_target_x64
+    } else {
+        get_next_sqrt_price_from_input(
+            sqrt_price_current_x64,
+            liquidity,
+            amount_remaining_less_fee,
+            a_to_b,
+        )?
+    };
+
+    // Short of the target the whole input moved the price, rounded in the pool's favour;
+    // re-deriving it from the price difference could round above what was paid
+    let amount_in = if reaches_target {
+        amount_in_to_target
+    } else {
+        amount_remaining_less_fee
+    };
+    let amount_out = if a_to_b {
+        get_amount_b_delta(
+            sqrt_price_next_x64,
+            sqrt_price_current_x64,
+            liquidity,
+            false,
+        )?
+    } else {
+        get_amount_a_delta(
+            sqrt_price_current_x64,
+            sqrt_price_next_x64,
+            liquidity,
+            false,
+        )?
+    };
+
+    let fee_amount = if reaches_target {
+        mul_div(amount_in, fee_rate, 10_000 - fee_rate, true)?
+    } else {
+        // Whatever isn't swapped in this final step is kept as the fee
+        amount_remaining
+            .checked_sub(amount_in)
+            .ok_or(MathError::Overflow)?
+    };
+
+    Ok(SwapStep {
+        sqrt_price_next_x64,
+        amount_in,
+        amount_out,
+        fee_amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqrt_price_at_tick_bounds() {
+        assert_eq!(sqrt_price_at_tick(0).unwrap(), Q64);
+        assert_eq!(
+            sqrt_price_at_tick(MIN_TICK_INDEX).unwrap(),
+            MIN_SQRT_PRICE_X64
+        );
+        assert_eq!(
+            sqrt_price_at_tick(MAX_TICK_INDEX).unwrap(),
+            MAX_SQRT_PRICE_X64
+        );
+        assert!(sqrt_price_at_tick(MIN_TICK_INDEX - 1).is_err());
+        assert!(sqrt_price_at_tick(MAX_TICK_INDEX + 1).is_err());
+    }
+
+    #[test]
+    fn sqrt_price_at_tick_is_symmetric() {
+        // sqrt(1.0001^t) * sqrt(1.0001^-t) = 1
+        for tick in [1, 10, 1_000, 100_000, 200_000] {
+            let product = mul_div(
+                sqrt_price_at_tick(tick).unwrap(),
+                sqrt_price_at_tick(-tick).unwrap(),
+                Q64,
+                false,
+            )
+            .unwrap();
+            assert!(
+                product.abs_diff(Q64) <= Q64 / 1_000_000_000_000,
+                "tick {tick}"
+            );
+        }
+    }
+
+    #[test]
+    fn tick_at_sqrt_price_round_trips() {
+        for tick in [
+            MIN_TICK_INDEX,
+            MIN_TICK_INDEX + 1,
+            -100_000,
+            -1,
+            0,
+            1,
+            887,
+            100_000,
+            MAX_TICK_INDEX - 1,
+            MAX_TICK_INDEX,
+        ] {
+            let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+            assert_eq!(tick_at_sqrt_price(sqrt_price).unwrap(), tick);
+            if tick > MIN_TICK_INDEX {
+                assert_eq!(tick_at_sqrt_price(sqrt_price - 1).unwrap(), tick - 1);
+            }
+        }
+        assert!(tick_at_sqrt_price(MIN_SQRT_PRICE_X64 - 1).is_err());
+        assert!(tick_at_sqrt_price(MAX_SQRT_PRICE_X64 + 1).is_err());
+    }
+
+    #[test]
+    fn div_full_with_high_word() {
+        // 2^128 = 3 * 0x5555...5555 + 1
+        assert_eq!(div_full(1, 0, 3), Some((u128::MAX / 3, 1)));
+
+        let (a, b) = (u128::MAX - 12_345, (1 << 100) + 7);
+        let (hi, lo) = full_mul(a, b);
+        assert!(hi > 0);
+        assert_eq!(div_full(hi, lo, b), Some((a, 0)));
+        assert_eq!(div_full(hi, lo, a), Some((b, 0)));
+
+        let (lo, carry) = lo.overflowing_add(5);
+        assert_eq!(div_full(hi + carry as u128, lo, b), Some((a, 5)));
+    }
+
+    #[test]
+    fn div_full_rejects_overflow() {
+        assert_eq!(div_full(3, 0, 3), None);
+        assert_eq!(div_full(1, 0, 0), None);
+        assert_eq!(full_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+    }
+
+    #[test]
+    fn mul_div_rounding() {
+        assert_eq!(mul_div(u128::MAX, 3, 6, false).unwrap(), u128::MAX / 2);
+        assert_eq!(mul_div(u128::MAX, 3, 6, true).unwrap(), 1 << 127);
+        assert_eq!(mul_div(6, 4, 3, true).unwrap(), 8);
+        assert!(mul_div(u128::MAX, 2, 1, false).is_err());
+        assert!(mul_div(1, 1, 0, false).is_err());
+    }
+
+    #[test]
+    fn swap_step_never_pays_out_more_than_paid_in() {
+        let liquidity = 1_000_000_000_000;
+        for sqrt_price in [
+            sqrt_price_at_tick(-5_000).unwrap(),
+            Q64,
+            MAX_SQRT_PRICE_X64 / 4,
+        ] {
+            for amount in [1, 997, 1_000_000, 123_456_789] {
+                for a_to_b in [true, false] {
+                    let (target, back_target) = if a_to_b {
+                        (MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64)
+                    } else {
+                        (MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64)
+                    };
+                    let step = compute_swap_step(sqrt_price, target, liquidity, amount, 30, a_to_b)
+                        .unwrap();
+                    assert_eq!(step.amount_in + step.fee_amount, amount);
+
+                    // Swapping the output straight back, fee free, can't return more
+                    let back = compute_swap_step(
+                        step.sqrt_price_next_x64,
+                        back_target,
+                        liquidity,
+                        step.amount_out,
+                        0,
+                        !a_to_b,
+                    )
+                    .unwrap();
+                    assert!(back.amount_out <= step.amount_in);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swap_step_stops_at_target() {
+        let target = sqrt_price_at_tick(-10).unwrap();
+        let step = compute_swap_step(Q64, target, 1_000_000, u64::MAX as u128, 30, true).unwrap();
+        assert_eq!(step.sqrt_price_next_x64, target);
+        assert_eq!(
+            step.amount_in,
+            get_amount_a_delta(target, Q64, 1_000_000, true).unwrap()
+        );
+        assert_eq!(
+            step.amount_out,
+            get_amount_b_delta(target, Q64, 1_000_000, false).unwrap()
+        );
+    }
+}
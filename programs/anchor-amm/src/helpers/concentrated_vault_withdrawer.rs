@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::{ConcentratedPool, CONCENTRATED_POOL_SEED};
+
+/// Trait for transferring tokens out of concentrated pool vaults.
+///
+/// Mirrors `VaultWithdrawer`, but signs with the `ConcentratedPool` PDA, whose
+/// seeds also include the tick spacing.
+pub trait ConcentratedVaultWithdrawer<'info> {
+    fn token_program(&self) -> &Program<'info, Token>;
+    fn concentrated_pool(&self) -> &Account<'info, ConcentratedPool>;
+
+    fn withdraw_from_vault(
+        &self,
+        mint: &Account<'info, Mint>,
+        vault: &Account<'info, TokenAccount>,
+        destination: &Account<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let pool = self.concentrated_pool();
+        let tick_spacing = pool.tick_spacing.to_le_bytes();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            CONCENTRATED_POOL_SEED.as_bytes(),
+            pool.token_a_mint.as_ref(),
+            pool.token_b_mint.as_ref(),
+            tick_spacing.as_ref(),
+            &[pool.bump],
+        ]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program().to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        transfer_checked(transfer_ctx, amount, mint.decimals)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    error::{AmmError, MathError},
+    PRICE_BAND_DENOMINATOR,
+};
+use anchor_lang::prelude::*;
+
+/// Output amount of a constant-sum swap, `x + y = k` on normalized reserves.
+///
+/// Trades at par after scaling both sides to the same precision, rounding the
+/// output down.
+///
+/// # Arguments
+/// * `amount_in` - Input amount after fees
+/// * `reserve_out` - Output reserves, the most the swap can pay out
+/// * `multiplier_in` / `multiplier_out` - Precision multipliers of the input and output tokens
+///
+/// # Errors
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `AmmError::InsufficientLiquidity` - If the output exceeds `reserve_out`
+pub fn get_constant_sum_amount_out(
+    amount_in: u128,
+    reserve_out: u128,
+    multiplier_in: u128,
+    multiplier_out: u128,
+) -> Result<u128> {
+    let amount_out = amount_in
+        .checked_mul(multiplier_in)
+        .ok_or(MathError::Overflow)?
+        .checked_div(multiplier_out)
+        .ok_or(MathError::DivisionByZero)?;
+    require_gte!(reserve_out, amount_out, AmmError::InsufficientLiquidity);
+    Ok(amount_out)
+}
+
+/// Input amount needed for an exact constant-sum output, rounding up.
+///
+/// # Errors
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `AmmError::InsufficientLiquidity` - If `amount_out` exceeds `reserve_out`
+pub fn get_constant_sum_amount_in(
+    amount_out: u128,
+    reserve_out: u128,
+    multiplier_in: u128,
+    multiplier_out: u128,
+) -> Result<u128> {
+    require_gte!(reserve_out, amount_out, AmmError::InsufficientLiquidity);
+    Ok(amount_out
+        .checked_mul(multiplier_out)
+        .ok_or(MathError::Overflow)?
+        .div_ceil(multiplier_in))
+}
+
+/// Rejects a trade that leaves the input side above its allowed share of the pool.
+///
+/// `price_band_bps` is the largest deviation of either side from a 50/50 split of
+/// the normalized reserves; zero disables the check. Only the input side grows
+/// during a swap, so trades that move the pool back towards balance always pass.
+///
+/// # Arguments
+/// * `normalized_in_after` - Input reserves after the swap, scaled to the common precision
+/// * `normalized_out_after` - Output reserves after the swap, scaled to the common precision
+/// * `price_band_bps` - Allowed deviation from an even split, in basis points
+///
+/// # Errors
+/// * `AmmError::PriceBandExceeded` - If the input side ends up outside the band
+pub fn check_price_band(
+    normalized_in_after: u128,
+    normalized_out_after: u128,
+    price_band_bps: u16,
+) -> Result<()> {
+    if price_band_bps == 0 {
+        return Ok(());
+    }
+    let total = normalized_in_after
+        .checked_add(normalized_out_after)
+        .ok_or(MathError::Overflow)?;
+    let max_share = (PRICE_BAND_DENOMINATOR / 2 + price_band_bps) as u128;
+    require!(
+        normalized_in_after
+            .checked_mul(PRICE_BAND_DENOMINATOR as u128)
+            .ok_or(MathError::Overflow)?
+            <= total.checked_mul(max_share).ok_or(MathError::Overflow)?,
+        AmmError::PriceBandExceeded
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 6-decimal token in, a 9-decimal token out, and the reverse
+    const MULTIPLIERS: [(u128, u128); 2] = [(1_000, 1), (1, 1_000)];
+
+    #[test]
+    fn exact_in_rounds_for_the_pool() {
+        for (multiplier_in, multiplier_out) in MULTIPLIERS {
+            for amount_in in [1, 999, 1_001, 1_000_000_007] {
+                let amount_out = get_constant_sum_amount_out(
+                    amount_in,
+                    u64::MAX as u128,
+                    multiplier_in,
+                    multiplier_out,
+                )
+                .unwrap();
+                assert!(amount_out * multiplier_out <= amount_in * multiplier_in);
+            }
+        }
+        assert!(get_constant_sum_amount_out(1_001, 1_000, 1, 1).is_err());
+    }
+
+    #[test]
+    fn exact_out_rounds_for_the_pool() {
+        for (multiplier_in, multiplier_out) in MULTIPLIERS {
+            for amount_out in [1, 999, 1_001, 1_000_000_007] {
+                let amount_in = get_constant_sum_amount_in(
+                    amount_out,
+                    u64::MAX as u128,
+                    multiplier_in,
+                    multiplier_out,
+                )
+                .unwrap();
+                assert!(amount_in > 0);
+                assert!(amount_in * multiplier_in >= amount_out * multiplier_out);
+            }
+        }
+        assert!(get_constant_sum_amount_in(1_001, 1_000, 1, 1).is_err());
+    }
+
+    #[test]
+    fn price_band_caps_the_input_share() {
+        // 5% band: the input side may hold up to 55% of the pool
+        assert!(check_price_band(55, 45, 500).is_ok());
+        assert!(check_price_band(56, 44, 500).is_err());
+        assert!(check_price_band(100, 0, 0).is_ok());
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::{AmmError, MathError},
+    ConcentratedPool, Position, TickArray,
+};
+
+use super::{get_amount_a_delta, get_amount_b_delta, sqrt_price_at_tick};
+
+/// Trait for adding or removing liquidity from a concentrated liquidity position.
+///
+/// Implement this on any Anchor accounts struct holding a position together with the
+/// tick arrays of its lower and upper ticks. The two tick arrays may be the same account.
+pub trait LiquidityModifier<'info> {
+    fn concentrated_pool(&mut self) -> &mut Account<'info, ConcentratedPool>;
+    fn position(&mut self) -> &mut Account<'info, Position>;
+    fn tick_array_lower(&self) -> &AccountLoader<'info, TickArray>;
+    fn tick_array_upper(&self) -> &AccountLoader<'info, TickArray>;
+
+    /// Applies `liquidity_delta` to the position, its boundary ticks and the pool.
+    ///
+    /// # Algorithm (from Uniswap V3)
+    /// 1. Update `liquidity_gross`/`liquidity_net` on both boundary ticks
+    /// 2. Settle fees earned inside the range into the position
+    /// 3. Update the position's liquidity, and the pool's active liquidity if in range
+    /// 4. Clear boundary ticks no longer referenced by any position
+    ///
+    /// # Returns
+    /// `(token_a_amount, token_b_amount)` owed by (positive delta, rounded up) or
+    /// to (negative delta, rounded down) the position owner.
+    fn modify_liquidity(&mut self, liquidity_delta: i128) -> Result<(u128, u128)> {
+        let pool = self.concentrated_pool();
+        let pool_key = pool.key();
+        let tick_spacing = pool.tick_spacing;
+        let tick_current_index = pool.tick_current_index;
+        let sqrt_price_x64 = pool.sqrt_price_x64;
+        let fee_growth_global_a_x64 = pool.fee_growth_global_a_x64;
+        let fee_growth_global_b_x64 = pool.fee_growth_global_b_x64;
+        let tick_lower_index = self.position().tick_lower_index;
+        let tick_upper_index = self.position().tick_upper_index;
+
+        // Both loaders may point at the same account, so only one is borrowed at a time
+        for (tick_array, tick_index, is_upper) in [
+            (self.tick_array_lower(), tick_lower_index, false),
+            (self.tick_array_upper(), tick_upper_index, true),
+        ] {
+            let mut tick_array = tick_array.load_mut()?;
+            require_keys_eq!(
+                tick_array.concentrated_pool,
+                pool_key,
+                AmmError::InvalidTickArray
+            );
+            tick_array.tick_mut(tick_index, tick_spacing)?.update(
+                tick_index,
+                tick_current_index,
+                liquidity_delta,
+                fee_growth_global_a_x64,
+                fee_growth_global_b_x64,
+                is_upper,
+            )?;
+        }
+
+        let tick_lower = *self
+            .tick_array_lower()
+            .load()?
+            .tick(tick_lower_index, tick_spacing)?;
+        let tick_upper = *self
+            .tick_array_upper()
+            .load()?
+            .tick(tick_upper_index, tick_spacing)?;
+        let (fee_growth_inside_a_x64, fee_growth_inside_b_x64) = self
+            .concentrated_pool()
+            .fee_growth_inside(&tick_lower, tick_lower_index, &tick_upper, tick_upper_index);
+
+        let position = self.position();
+        position.update_fees(fee_growth_inside_a_x64, fee_growth_inside_b_x64)?;
+        position.liquidity = position
+            .liquidity
+            .checked_add_signed(liquidity_delta)
+            .ok_or(AmmError::InsufficientLiquidity)?;
+
+        if liquidity_delta < 0 {
+            self.tick_array_lower()
+                .load_mut()?
+                .tick_mut(tick_lower_index, tick_spacing)?
+                .clear_if_unused();
+            self.tick_array_upper()
+                .load_mut()?
+                .tick_mut(tick_upper_index, tick_spacing)?
+                .clear_if_unused();
+        }
+
+        let round_up = liquidity_delta > 0;
+        let liquidity = liquidity_delta.unsigned_abs();
+        let sqrt_price_lower_x64 = sqrt_price_at_tick(tick_lower_index)?;
+        let sqrt_price_upper_x64 = sqrt_price_at_tick(tick_upper_index)?;
+
+        if tick_current_index < tick_lower_index {
+            // Price below range: position is entirely token A
+            let token_a_amount = get_amount_a_delta(
+                sqrt_price_lower_x64,
+                sqrt_price_upper_x64,
+                liquidity,
+                round_up,
+            )?;
+            Ok((token_a_amount, 0))
+        } else if tick_current_index < tick_upper_index {
+            // Price in range: position holds both tokens and is part of the active liquidity
+            let token_a_amount =
+                get_amount_a_delta(sqrt_price_x64, sqrt_price_upper_x64, liquidity, round_up)?;
+            let token_b_amount =
+                get_amount_b_delta(sqrt_price_lower_x64, sqrt_price_x64, liquidity, round_up)?;
+            let pool = self.concentrated_pool();
+            pool.liquidity = pool
+                .liquidity
+                .checked_add_signed(liquidity_delta)
+                .ok_or(MathError::Overflow)?;
+            Ok((token_a_amount, token_b_amount))
+        } else {
+            // Price above range: position is entirely token B
+            let token_b_amount = get_amount_b_delta(
+                sqrt_price_lower_x64,
+                sqrt_price_upper_x64,
+                liquidity,
+                round_up,
+            )?;
+            Ok((0, token_b_amount))
+        }
+    }
+}
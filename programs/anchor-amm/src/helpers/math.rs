@@ -66,6 +66,33 @@ pub fn get_amount_out(amount_in: u128, reserve_in: u128, reserve_out: u128) -> R
         .ok_or(MathError::DivisionByZero.into())
 }
 
+/// Calculates the input amount needed for an exact constant product swap output.
+///
+/// Formula: `Δx = (x * Δy) / (y - Δy) + 1`
+///
+/// The inverse of `get_amount_out`, rounded up so the pool never loses to rounding.
+///
+/// # Arguments
+/// * `amount_out` - The desired output token amount (Δy)
+/// * `reserve_in` - The input token's reserve (x)
+/// * `reserve_out` - The output token's reserve (y)
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If `amount_out` is not below `reserve_out`
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn get_amount_in(amount_out: u128, reserve_in: u128, reserve_out: u128) -> Result<u128> {
+    require_gt!(reserve_out, amount_out, AmmError::InsufficientLiquidity);
+    let numerator = reserve_in
+        .checked_mul(amount_out)
+        .ok_or(MathError::Overflow)?;
+    let denominator = reserve_out - amount_out;
+    Ok(numerator
+        .checked_div(denominator)
+        .ok_or(MathError::DivisionByZero)?
+        .checked_add(1)
+        .ok_or(MathError::Overflow)?)
+}
+
 /// Calculates the amount of tokens received when burning LP tokens.
 ///
 /// Formula: `amount_out = (reserves * lp_amount) / lp_supply`
@@ -1,14 +1,38 @@
+pub mod concentrated_math;
+pub mod concentrated_vault_withdrawer;
+pub mod constant_sum_math;
+pub mod liquidity_modifier;
 pub mod lp_burner;
 pub mod lp_minter;
 pub mod math;
+pub mod multi_asset_lp_minter;
+pub mod multi_asset_vault_withdrawer;
+pub mod oracle;
+pub mod pmm_math;
 pub mod protocol_fee_minter;
 pub mod reserve_syncer;
+pub mod sandwich_guard;
+pub mod stable_math;
+pub mod swap_curve;
 pub mod vault_depositor;
 pub mod vault_withdrawer;
+pub mod weighted_math;
+pub use concentrated_math::*;
+pub use concentrated_vault_withdrawer::*;
+pub use constant_sum_math::*;
+pub use liquidity_modifier::*;
 pub use lp_burner::*;
 pub use lp_minter::*;
 pub use math::*;
+pub use multi_asset_lp_minter::*;
+pub use multi_asset_vault_withdrawer::*;
+pub use oracle::*;
+pub use pmm_math::*;
 pub use protocol_fee_minter::*;
 pub use reserve_syncer::*;
+pub use sandwich_guard::*;
+pub use stable_math::*;
+pub use swap_curve::*;
 pub use vault_depositor::*;
 pub use vault_withdrawer::*;
+pub use weighted_math::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::{MultiAssetPool, MULTI_ASSET_POOL_SEED};
+
+/// Trait for minting basket LP tokens of a multi-asset pool.
+///
+/// Mirrors `LPMinter`, but the mint authority is the `MultiAssetPool` PDA
+/// instead of the LP mint itself.
+pub trait MultiAssetLPMinter<'info> {
+    fn token_program(&self) -> &Program<'info, Token>;
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool>;
+    fn lp_token_mint(&self) -> &Account<'info, Mint>;
+
+    fn mint_lp_tokens(
+        &self,
+        mint_to_account: &Account<'info, TokenAccount>,
+        lp_tokens_to_mint: u64,
+    ) -> Result<()> {
+        let pool = self.multi_asset_pool();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            MULTI_ASSET_POOL_SEED.as_bytes(),
+            pool.lp_token_mint.as_ref(),
+            &[pool.bump],
+        ]];
+
+        let mint_to_ctx = CpiContext::new_with_signer(
+            self.token_program().to_account_info(),
+            MintTo {
+                mint: self.lp_token_mint().to_account_info(),
+                to: mint_to_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        mint_to(mint_to_ctx, lp_tokens_to_mint)
+    }
+}
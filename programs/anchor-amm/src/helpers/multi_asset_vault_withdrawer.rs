@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::{MultiAssetPool, MULTI_ASSET_POOL_SEED};
+
+/// Trait for transferring tokens out of multi-asset pool vaults.
+///
+/// Mirrors `VaultWithdrawer`, but signs with the `MultiAssetPool` PDA, which is
+/// derived from its LP mint rather than a token pair.
+pub trait MultiAssetVaultWithdrawer<'info> {
+    fn token_program(&self) -> &Program<'info, Token>;
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool>;
+
+    fn withdraw_from_vault(
+        &self,
+        mint: &Account<'info, Mint>,
+        vault: &Account<'info, TokenAccount>,
+        destination: &Account<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let pool = self.multi_asset_pool();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            MULTI_ASSET_POOL_SEED.as_bytes(),
+            pool.lp_token_mint.as_ref(),
+            &[pool.bump],
+        ]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program().to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        transfer_checked(transfer_ctx, amount, mint.decimals)
+    }
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+
+use crate::error::{AmmError, MathError};
+
+use super::mul_div;
+
+/// Magic number at the start of every Pyth account
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+/// Pyth account layout version understood by `load_oracle_price`
+const PYTH_VERSION: u32 = 2;
+/// Pyth account type of a price account
+const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+/// Aggregate status meaning the price is being actively published
+const PYTH_STATUS_TRADING: u32 = 1;
+
+// Byte offsets of the fields we read from a Pyth price account
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPONENT_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGGREGATE_PRICE_OFFSET: usize = 208;
+const AGGREGATE_CONFIDENCE_OFFSET: usize = 216;
+const AGGREGATE_STATUS_OFFSET: usize = 224;
+/// Size of the price account header plus its aggregate price
+pub const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Aggregate price read from a Pyth-style price account.
+///
+/// The real value is `price * 10^exponent`, give or take `confidence * 10^exponent`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// Converts the price of one token A into raw token B units per raw token A unit, as a WAD.
+    ///
+    /// The feed quotes whole tokens, so the result is rescaled by the pool's precision
+    /// multipliers: `10^dec_b / 10^dec_a = multiplier_a / multiplier_b`.
+    pub fn price_wad(&self, multiplier_a: u128, multiplier_b: u128) -> Result<u128> {
+        let price = self.price as u128;
+        let scale = 18 + self.exponent;
+        let price_wad = if scale >= 0 {
+            10u128
+                .checked_pow(scale as u32)
+                .and_then(|factor| price.checked_mul(factor))
+                .ok_or(MathError::Overflow)?
+        } else {
+            price
+                / 10u128
+                    .checked_pow((-scale) as u32)
+                    .ok_or(MathError::Overflow)?
+        };
+        let price_wad = mul_div(price_wad, multiplier_a, multiplier_b, false)?;
+        require_gt!(price_wad, 0, AmmError::InvalidOracle);
+        Ok(price_wad)
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+/// Reads and validates the aggregate price of a Pyth v2 price account.
+///
+/// Only the account data is checked, not its owner, so pools must pin the oracle
+/// address at initialization. This also lets tests use locally constructed accounts.
+///
+/// # Arguments
+/// * `oracle` - The price account
+/// * `now` - Current unix timestamp
+/// * `max_age` - Largest accepted age of the price in seconds
+/// * `max_confidence_bps` - Largest accepted confidence interval as a share of the price
+///
+/// # Errors
+/// * `AmmError::InvalidOracle` - If the account isn't a trading Pyth price account with a positive price
+/// * `AmmError::StaleOracle` - If the price is older than `max_age`
+/// * `AmmError::OracleConfidenceTooWide` - If the confidence interval exceeds `max_confidence_bps`
+pub fn load_oracle_price(
+    oracle: &AccountInfo,
+    now: i64,
+    max_age: u64,
+    max_confidence_bps: u16,
+) -> Result<OraclePrice> {
+    let data = oracle.try_borrow_data()?;
+    require!(
+        data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN
+            && read_u32(&data, MAGIC_OFFSET) == PYTH_MAGIC
+            && read_u32(&data, VERSION_OFFSET) == PYTH_VERSION
+            && read_u32(&data, ACCOUNT_TYPE_OFFSET) == PYTH_ACCOUNT_TYPE_PRICE
+            && read_u32(&data, AGGREGATE_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+        AmmError::InvalidOracle
+    );
+    let oracle_price = OraclePrice {
+        price: read_u64(&data, AGGREGATE_PRICE_OFFSET) as i64,
+        confidence: read_u64(&data, AGGREGATE_CONFIDENCE_OFFSET),
+        exponent: read_u32(&data, EXPONENT_OFFSET) as i32,
+        publish_time: read_u64(&data, TIMESTAMP_OFFSET) as i64,
+    };
+    require_gt!(oracle_price.price, 0, AmmError::InvalidOracle);
+    require!(
+        now.saturating_sub(oracle_price.publish_time) <= max_age.min(i64::MAX as u64) as i64,
+        AmmError::StaleOracle
+    );
+    require!(
+        (oracle_price.confidence as u128) * 10_000
+            <= (oracle_price.price as u128) * max_confidence_bps as u128,
+        AmmError::OracleConfidenceTooWide
+    );
+    Ok(oracle_price)
+}
+
+/// Rejects a pool price more than `max_deviation_bps` away from the oracle price.
+///
+/// # Errors
+/// * `AmmError::OraclePriceDeviation` - If `|spot_price - oracle_price| > oracle_price * max_deviation_bps`
+pub fn check_oracle_deviation(
+    spot_price: u128,
+    oracle_price: u128,
+    max_deviation_bps: u16,
+) -> Result<()> {
+    let max_deviation = mul_div(oracle_price, max_deviation_bps as u128, 10_000, false)?;
+    require!(
+        spot_price.abs_diff(oracle_price) <= max_deviation,
+        AmmError::OraclePriceDeviation
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_000;
+
+    fn price_account_data(price: i64, confidence: u64, publish_time: i64) -> Vec<u8> {
+        let mut data = vec![0; PYTH_PRICE_ACCOUNT_MIN_LEN];
+        let mut write =
+            |offset: usize, bytes: &[u8]| data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        write(MAGIC_OFFSET, &PYTH_MAGIC.to_le_bytes());
+        write(VERSION_OFFSET, &PYTH_VERSION.to_le_bytes());
+        write(ACCOUNT_TYPE_OFFSET, &PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+        write(AGGREGATE_STATUS_OFFSET, &PYTH_STATUS_TRADING.to_le_bytes());
+        write(EXPONENT_OFFSET, &(-8i32).to_le_bytes());
+        write(AGGREGATE_PRICE_OFFSET, &price.to_le_bytes());
+        write(AGGREGATE_CONFIDENCE_OFFSET, &confidence.to_le_bytes());
+        write(TIMESTAMP_OFFSET, &publish_time.to_le_bytes());
+        data
+    }
+
+    fn load(data: &mut [u8]) -> Result<OraclePrice> {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let oracle = AccountInfo::new(&key, false, false, &mut lamports, data, &owner, false, 0);
+        load_oracle_price(&oracle, NOW, 60, 100)
+    }
+
+    #[test]
+    fn reads_a_fresh_trading_price() {
+        let mut data = price_account_data(150_000_000, 100_000, NOW - 10);
+        assert_eq!(
+            load(&mut data).unwrap(),
+            OraclePrice {
+                price: 150_000_000,
+                confidence: 100_000,
+                exponent: -8,
+                publish_time: NOW - 10,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_stale_uncertain_or_foreign_prices() {
+        let mut stale = price_account_data(150_000_000, 100_000, NOW - 61);
+        assert_eq!(load(&mut stale).unwrap_err(), AmmError::StaleOracle.into());
+
+        let mut too_wide = price_account_data(150_000_000, 1_500_001, NOW);
+        assert_eq!(
+            load(&mut too_wide).unwrap_err(),
+            AmmError::OracleConfidenceTooWide.into()
+        );
+
+        let mut not_pyth = price_account_data(150_000_000, 100_000, NOW);
+        not_pyth[MAGIC_OFFSET] = 0;
+        assert_eq!(
+            load(&mut not_pyth).unwrap_err(),
+            AmmError::InvalidOracle.into()
+        );
+    }
+
+    #[test]
+    fn deviation_limit_is_inclusive_on_both_sides() {
+        let oracle_price = 2_000_000;
+        // 1% either side passes a 1% limit
+        check_oracle_deviation(2_020_000, oracle_price, 100).unwrap();
+        check_oracle_deviation(1_980_000, oracle_price, 100).unwrap();
+        assert_eq!(
+            check_oracle_deviation(2_020_001, oracle_price, 100).unwrap_err(),
+            AmmError::OraclePriceDeviation.into()
+        );
+        assert_eq!(
+            check_oracle_deviation(1_979_999, oracle_price, 100).unwrap_err(),
+            AmmError::OraclePriceDeviation.into()
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use crate::error::{AmmError, MathError};
+use anchor_lang::prelude::*;
+
+use super::{full_mul, isqrt_full, mul_div, WAD};
+
+/// Inverts a WAD price, e.g. turns "B per A" into "A per B".
+pub fn inverse_wad(price: u128) -> Result<u128> {
+    mul_div(WAD, WAD, price, false)
+}
+
+/// Value of moving one side of a PMM pool from `v1` to `v2`, priced in the other token.
+///
+/// Formula: `i * (V1 - V2) * (1 - k + k * V0^2 / (V1 * V2))`
+///
+/// The integral of DODO's PMM price curve between two reserve levels on the
+/// side whose equilibrium target is `v0`.
+///
+/// # Arguments
+/// * `v0` - Equilibrium target of the moving side
+/// * `v1` - Larger reserve level
+/// * `v2` - Smaller reserve level, must be greater than zero
+/// * `price` - Oracle price of the moving side in the other token (WAD)
+/// * `k` - Liquidity parameter (WAD), 0 for a fixed price and 1 for constant product like curvature
+///
+/// # Errors
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `MathError::DivisionByZero` - If `v2` is zero
+pub fn pmm_general_integrate(v0: u128, v1: u128, v2: u128, price: u128, k: u128) -> Result<u128> {
+    let fair_amount = mul_div(
+        price,
+        v1.checked_sub(v2).ok_or(MathError::Overflow)?,
+        WAD,
+        false,
+    )?;
+    // V0^2 / (V1 * V2) in WAD
+    let v0v0v1v2 = mul_div(mul_div(v0, v0, v1, false)?, WAD, v2, false)?;
+    let penalty = mul_div(k, v0v0v1v2, WAD, false)?;
+    mul_div(
+        fair_amount,
+        (WAD - k).checked_add(penalty).ok_or(MathError::Overflow)?,
+        WAD,
+        false,
+    )
+}
+
+/// Equilibrium target of a side that is `v1` now while the other side holds `delta` above its target.
+///
+/// Formula: `V0 = V1 * (1 + (sqrt(1 + 4 * k * i * delta / V1) - 1) / (2 * k))`
+///
+/// # Arguments
+/// * `v1` - Current reserves of the short side
+/// * `delta` - Excess of the other side over its target
+/// * `price` - Oracle price of the other side in this side's token (WAD)
+/// * `k` - Liquidity parameter (WAD)
+///
+/// # Errors
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn pmm_solve_target(v1: u128, delta: u128, price: u128, k: u128) -> Result<u128> {
+    if v1 == 0 {
+        return Ok(0);
+    }
+    let fair_amount = mul_div(price, delta, WAD, false)?;
+    if k == 0 {
+        return v1
+            .checked_add(fair_amount)
+            .ok_or(MathError::Overflow.into());
+    }
+    // 1 + 4 * k * i * delta / V1 in WAD
+    let radicand = mul_div(
+        k.checked_mul(4).ok_or(MathError::Overflow)?,
+        fair_amount,
+        v1,
+        false,
+    )?
+    .checked_add(WAD)
+    .ok_or(MathError::Overflow)?;
+    let (hi, lo) = full_mul(radicand, WAD);
+    let sqrt = isqrt_full(hi, lo);
+    let premium = mul_div(sqrt - WAD, WAD, k * 2, false)?
+        .checked_add(WAD)
+        .ok_or(MathError::Overflow)?;
+    mul_div(v1, premium, WAD, false)
+}
+
+/// Amount a side with target `v0` and reserves `v1` pays out when `delta` of the other token comes in.
+///
+/// Solves `i * delta = (V1 - V2) * (1 - k + k * V0^2 / (V1 * V2))` for the new
+/// reserves `V2`, i.e. the quadratic `(1 - k) * V2^2 + b * V2 - k * V0^2 = 0` with
+/// `b = k * V0^2 / V1 + i * delta - (1 - k) * V1`, and returns `V1 - V2`.
+/// `V2` and every term that raises it are rounded up so the pool never pays out
+/// more than the curve allows.
+///
+/// # Arguments
+/// * `v0` - Equilibrium target of the paying side
+/// * `v1` - Current reserves of the paying side, at most `v0`
+/// * `delta` - Amount of the other token coming in
+/// * `price` - Oracle price of the incoming token in the paying side's token (WAD)
+/// * `k` - Liquidity parameter (WAD)
+///
+/// # Errors
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `MathError::DivisionByZero` - If `v0` or `v1` is zero
+pub fn pmm_solve_trade(v0: u128, v1: u128, delta: u128, price: u128, k: u128) -> Result<u128> {
+    if delta == 0 {
+        return Ok(0);
+    }
+    let fair_amount = mul_div(price, delta, WAD, false)?;
+    if k == 0 {
+        return Ok(fair_amount.min(v1));
+    }
+    if k == WAD {
+        // V2 = V1 / (1 + i * delta * V1 / V0^2)
+        let temp = mul_div(mul_div(fair_amount, WAD, v0, false)?, v1, v0, false)?;
+        let v2 = mul_div(
+            v1,
+            WAD,
+            WAD.checked_add(temp).ok_or(MathError::Overflow)?,
+            true,
+        )?;
+        return Ok(v1.saturating_sub(v2));
+    }
+
+    let one_minus_k = WAD - k;
+    // k * V0^2 / V1 + i * delta
+    let part2 = mul_div(mul_div(k, v0, WAD, false)?, v0, v1, false)?
+        .checked_add(fair_amount)
+        .ok_or(MathError::Overflow)?;
+    // (1 - k) * V1
+    let part1 = mul_div(one_minus_k, v1, WAD, true)?;
+    // |b| and whether b is negative
+    let (b_abs, b_negative) = if part1 >= part2 {
+        (part1 - part2, true)
+    } else {
+        (part2 - part1, false)
+    };
+
+    // sqrt(b^2 + 4 * (1 - k) * k * V0^2)
+    let four_ac_over_v0 = mul_div(mul_div(one_minus_k * 4, k, WAD, true)?, v0, WAD, true)?;
+    let (b_hi, b_lo) = full_mul(b_abs, b_abs);
+    let (c_hi, c_lo) = full_mul(four_ac_over_v0, v0);
+    let (lo, carry) = b_lo.overflowing_add(c_lo);
+    let hi = b_hi
+        .checked_add(c_hi)
+        .and_then(|hi| hi.checked_add(carry as u128))
+        .ok_or(MathError::Overflow)?;
+    let mut square_root = isqrt_full(hi, lo);
+    // V2 grows with the root, so round it up too
+    if full_mul(square_root, square_root) != (hi, lo) {
+        square_root += 1;
+    }
+
+    let numerator = if b_negative {
+        b_abs.checked_add(square_root).ok_or(MathError::Overflow)?
+    } else {
+        square_root.saturating_sub(b_abs)
+    };
+    let v2 = mul_div(numerator, WAD, one_minus_k * 2, true)?;
+    Ok(v1.saturating_sub(v2))
+}
+
+/// Output amount of a PMM swap for an exact input.
+///
+/// The pool is in one of three states relative to its equilibrium targets:
+/// - Input side short: the trade first refills the input side up to its target at a
+///   premium, then continues on the other branch of the curve.
+/// - Output side short: the trade pushes the output side further below its target.
+/// - Balanced: both sides sit at their targets.
+///
+/// # Arguments
+/// * `amount_in` - Input amount after fees
+/// * `reserve_in` / `reserve_out` - Current reserves
+/// * `target_in` / `target_out` - Equilibrium targets of the same sides
+/// * `price_in` - Oracle price of the input token in the output token (WAD)
+/// * `k` - Liquidity parameter (WAD)
+///
+/// # Errors
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `AmmError::InsufficientLiquidity` - If the output would drain the pool
+pub fn get_pmm_amount_out(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    target_in: u128,
+    target_out: u128,
+    price_in: u128,
+    k: u128,
+) -> Result<u128> {
+    let amount_out = if reserve_in < target_in {
+        let back_to_one_pay = target_in - reserve_in;
+        let back_to_one_receive = reserve_out.saturating_sub(target_out);
+        if amount_in < back_to_one_pay {
+            pmm_general_integrate(target_in, reserve_in + amount_in, reserve_in, price_in, k)?
+                .min(back_to_one_receive)
+        } else {
+            back_to_one_receive
+                .checked_add(pmm_solve_trade(
+                    target_out,
+                    target_out,
+                    amount_in - back_to_one_pay,
+                    price_in,
+                    k,
+                )?)
+                .ok_or(MathError::Overflow)?
+        }
+    } else if reserve_out < target_out {
+        pmm_solve_trade(target_out, reserve_out, amount_in, price_in, k)?
+    } else {
+        pmm_solve_trade(target_out, target_out, amount_in, price_in, k)?
+    };
+    require_gt!(reserve_out, amount_out, AmmError::InsufficientLiquidity);
+    Ok(amount_out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Token A is worth 2 token B
+    const PRICE: u128 = 2 * WAD;
+    const KS: [u128; 4] = [0, WAD / 10, WAD / 2, WAD];
+
+    #[test]
+    fn zero_k_trades_at_the_oracle_price() {
+        assert_eq!(
+            get_pmm_amount_out(1_000, 1_000_000, 2_000_000, 1_000_000, 2_000_000, PRICE, 0)
+                .unwrap(),
+            2_000
+        );
+        assert!(get_pmm_amount_out(
+            1_000_000, 1_000_000, 2_000_000, 1_000_000, 2_000_000, PRICE, 0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn curvature_costs_the_trader() {
+        let mut previous = u128::MAX;
+        for k in KS {
+            let amount_out = get_pmm_amount_out(
+                100_000_000,
+                1_000_000_000,
+                2_000_000_000,
+                1_000_000_000,
+                2_000_000_000,
+                PRICE,
+                k,
+            )
+            .unwrap();
+            assert!(amount_out <= 200_000_000);
+            assert!(amount_out <= previous, "k {k}");
+            previous = amount_out;
+        }
+    }
+
+    #[test]
+    fn round_trip_never_profits() {
+        let (target_a, target_b) = (1_000_000_000_000, 2_000_000_000_000);
+        for k in KS {
+            for amount_in in [1, 1_000, 1_000_000_007, 300_000_000_000] {
+                let amount_out =
+                    get_pmm_amount_out(amount_in, target_a, target_b, target_a, target_b, PRICE, k)
+                        .unwrap();
+                // Sending the output straight back refills B first, at a premium
+                let amount_back = get_pmm_amount_out(
+                    amount_out,
+                    target_b - amount_out,
+                    target_a + amount_in,
+                    target_b,
+                    target_a,
+                    inverse_wad(PRICE).unwrap(),
+                    k,
+                )
+                .unwrap();
+                assert!(amount_back <= amount_in, "k {k}: {amount_in} {amount_back}");
+            }
+        }
+    }
+
+    #[test]
+    fn solve_target_covers_the_excess() {
+        assert_eq!(pmm_solve_target(1_000, 0, PRICE, WAD / 2).unwrap(), 1_000);
+        assert_eq!(pmm_solve_target(1_000, 500, PRICE, 0).unwrap(), 2_000);
+        // Curvature prices the missing reserves above par, so fewer of them are missing:
+        // 1 + (sqrt(1 + 4 * 0.5 * 1) - 1) / (2 * 0.5) = sqrt(3)
+        assert_eq!(
+            pmm_solve_target(1_000_000, 500_000, PRICE, WAD / 2).unwrap(),
+            1_732_050
+        );
+        assert_eq!(pmm_solve_target(0, 500, PRICE, WAD / 2).unwrap(), 0);
+    }
+
+    #[test]
+    fn integrate_is_linear_without_curvature() {
+        assert_eq!(
+            pmm_general_integrate(1_000, 1_000, 900, PRICE, 0).unwrap(),
+            200
+        );
+        assert!(pmm_general_integrate(1_000, 1_000, 900, PRICE, WAD / 2).unwrap() > 200);
+    }
+}
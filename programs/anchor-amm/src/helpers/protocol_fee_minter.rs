@@ -1,92 +1,71 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
-use crate::{error::MathError, LiquidityPool};
+use crate::LiquidityPool;
 
 use super::LPMinter;
 
+/// Denominator of the fee shares used by `ProtocolFeeMinter`, so that the
+/// protocol's 1/6 and a creator share in basis points are both whole numbers.
+pub const FEE_SHARE_DENOMINATOR: u128 = 60_000;
+/// The protocol's 1/6 of swap fees, out of `FEE_SHARE_DENOMINATOR`
+pub const PROTOCOL_FEE_SHARE: u128 = 10_000;
+
+/// LP tokens minted to the fee account, split by beneficiary.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeMint {
+    pub protocol_lp_tokens: u64,
+    /// Held in the fee account until the creator claims them
+    pub creator_lp_tokens: u64,
+}
+
 /// Trait for minting protocol fees as LP tokens before liquidity events.
 ///
 /// Implements the Uniswap V2 protocol fee mechanism where 1/6 of swap fees
 /// are minted as LP tokens to the protocol's fee token account (owned by the pool PDA).
+/// The pool creator's `creator_fee_bps` share of swap fees is minted alongside it.
 ///
 /// The fee is calculated by comparing current k (reserve0 * reserve1) with k_last
 /// (the k value at the last liquidity event). Any growth in sqrt(k) indicates
-/// accumulated swap fees.
+/// accumulated swap fees. Other curves compare their `SwapCurve::root_invariant` instead.
 ///
 /// This trait composes with `LPMinter` to handle the actual token minting.
 pub trait ProtocolFeeMinter<'info>: LPMinter<'info> {
     fn fee_lp_token_account(&self) -> &Account<'info, TokenAccount>;
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool>;
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool>;
 
-    /// Mints protocol fee LP tokens if there has been fee accumulation since k_last.
+    /// Mints protocol and creator fee LP tokens if there has been fee accumulation since k_last.
+    ///
+    /// The amount comes from `LiquidityPool::accrued_fee_lp_tokens`.
     ///
     /// # Algorithm (from Uniswap V2)
-    /// 1. Calculate rootK = sqrt(reserve0 * reserve1)
-    /// 2. Calculate rootKLast = sqrt(k_last)
-    /// 3. If rootK > rootKLast (fees accumulated):
-    ///    - numerator = totalSupply * (rootK - rootKLast)
-    ///    - denominator = rootK * 5 + rootKLast
+    /// 1. Calculate rootK = sqrt(reserve0 * reserve1), i.e. the curve's root invariant
+    /// 2. Calculate rootKLast = sqrt(k_last), likewise
+    /// 3. If rootK > rootKLast (fees accumulated), mint a share φ of the growth:
+    ///    - numerator = totalSupply * (rootK - rootKLast) * φ
+    ///    - denominator = rootK * (1 - φ) + rootKLast * φ
     ///    - liquidity = numerator / denominator
     ///    - Mint `liquidity` LP tokens to fee account
     ///
-    /// The formula gives protocol 1/6 of the swap fees (the 5 creates this ratio).
+    /// With φ = 1/6 this is Uniswap's `totalSupply * (rootK - rootKLast) / (rootK * 5 + rootKLast)`.
+    /// Here φ is the protocol's 1/6 plus the creator's share, and the minted tokens
+    /// are split between them in proportion.
     ///
     /// # Arguments
     /// * `lp_token_mint_bump` - The PDA bump seed for the LP token mint
     ///
     /// # Returns
-    /// Ok(()) if successful, or an error if minting fails
-    fn mint_protocol_fee(&self, lp_token_mint_bump: u8) -> Result<()> {
-        let k_last = self.liquidity_pool().k_last;
-
-        // If k_last is 0, this is either first deposit or fees are disabled
-        if k_last == 0 {
-            return Ok(());
+    /// The LP tokens minted to the fee account (zero if no fees accrued)
+    fn mint_protocol_fee(&self, lp_token_mint_bump: u8) -> Result<FeeMint> {
+        let fee_mint = self
+            .liquidity_pool()
+            .load()?
+            .accrued_fee_lp_tokens(self.lp_token_mint().supply, Clock::get()?.unix_timestamp)?;
+        let liquidity = fee_mint.protocol_lp_tokens + fee_mint.creator_lp_tokens;
+        if liquidity > 0 {
+            // Use composed LPMinter to mint tokens to fee account
+            self.mint_lp_tokens(self.fee_lp_token_account(), liquidity, lp_token_mint_bump)?;
         }
-
-        let reserve_a = self.liquidity_pool().token_a_reserves as u128;
-        let reserve_b = self.liquidity_pool().token_b_reserves as u128;
-
-        let k = reserve_a
-            .checked_mul(reserve_b)
-            .ok_or(MathError::Overflow)?;
-        let root_k = k.isqrt();
-        let root_k_last = k_last.isqrt();
-
-        // Only mint if k has grown (fees accumulated from swaps)
-        if root_k > root_k_last {
-            let total_supply = self.lp_token_mint().supply as u128;
-
-            // numerator = totalSupply * (rootK - rootKLast)
-            let numerator = total_supply
-                .checked_mul(root_k.checked_sub(root_k_last).ok_or(MathError::Overflow)?)
-                .ok_or(MathError::Overflow)?;
-
-            // denominator = rootK * 5 + rootKLast
-            let denominator = root_k
-                .checked_mul(5)
-                .ok_or(MathError::Overflow)?
-                .checked_add(root_k_last)
-                .ok_or(MathError::Overflow)?;
-
-            // liquidity = numerator / denominator
-            let liquidity = numerator
-                .checked_div(denominator)
-                .ok_or(MathError::DivisionByZero)?;
-
-            if liquidity > 0 {
-                let liquidity_u64: u64 = liquidity.try_into().map_err(|_| MathError::Overflow)?;
-
-                // Use composed LPMinter to mint tokens to fee account
-                self.mint_lp_tokens(
-                    self.fee_lp_token_account(),
-                    liquidity_u64,
-                    lp_token_mint_bump,
-                )?;
-            }
-        }
-
-        Ok(())
+        Ok(fee_mint)
     }
 }
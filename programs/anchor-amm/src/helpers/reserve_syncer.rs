@@ -13,7 +13,7 @@ use crate::LiquidityPool;
 /// - Correcting reserve drift if accounting gets out of sync
 /// - Arbitrage opportunities when reserves don't reflect actual balances
 pub trait ReserveSyncer<'info> {
-    fn liquidity_pool(&mut self) -> &mut Account<'info, LiquidityPool>;
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool>;
     fn token_a_vault(&self) -> &Account<'info, TokenAccount>;
     fn token_b_vault(&self) -> &Account<'info, TokenAccount>;
 
@@ -21,8 +21,10 @@ pub trait ReserveSyncer<'info> {
     ///
     /// Updates `token_a_reserves` and `token_b_reserves` in the liquidity pool
     /// to match the current `amount` held in each vault's token account.
-    fn sync_reserves(&mut self) {
-        self.liquidity_pool().token_a_reserves = self.token_a_vault().amount;
-        self.liquidity_pool().token_b_reserves = self.token_b_vault().amount;
+    fn sync_reserves(&self) -> Result<()> {
+        let mut pool = self.liquidity_pool().load_mut()?;
+        pool.token_a_reserves = self.token_a_vault().amount;
+        pool.token_b_reserves = self.token_b_vault().amount;
+        Ok(())
     }
 }
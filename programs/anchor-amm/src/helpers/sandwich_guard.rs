@@ -0,0 +1,345 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::Instruction,
+        sysvar::instructions::{
+            load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID,
+        },
+    },
+    Discriminator,
+};
+
+use crate::{error::AmmError, instruction};
+
+/// Position of `liquidity_pool` in the accounts of `swap`
+const SWAP_POOL_INDEX: usize = 8;
+/// Position of `token_0_mint`, the input mint, in the accounts of `swap`
+const SWAP_INPUT_MINT_INDEX: usize = 5;
+/// Position of `liquidity_pool` in the accounts of `swap_v2`
+const SWAP_V2_POOL_INDEX: usize = 7;
+
+/// Fails with `AmmError::OppositeSwapInTransaction` if another instruction of the
+/// transaction swaps `liquidity_pool` the other way, i.e. the swap sits inside a sandwich.
+///
+/// Only top-level instructions are listed in the instructions sysvar, so swaps made
+/// through CPI go unnoticed.
+pub fn check_no_opposite_swap(
+    instructions_sysvar: &AccountInfo,
+    liquidity_pool: &Pubkey,
+    token_a_mint: &Pubkey,
+    is_token_a_in: bool,
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let mut index = 0;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        if index != current_index && ix.program_id == crate::ID {
+            require!(
+                swap_direction(&ix, liquidity_pool, token_a_mint)? != Some(!is_token_a_in),
+                AmmError::OppositeSwapInTransaction
+            );
+        }
+        index += 1;
+    }
+    Ok(())
+}
+
+/// The oracle and the instructions sysvar among the remaining accounts of `swap`, in
+/// either order. The sysvar is found by its key and the oracle is the first other account.
+pub fn split_swap_remaining_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> (
+    Option<&'a AccountInfo<'info>>,
+    Option<&'a AccountInfo<'info>>,
+) {
+    let is_sysvar = |account: &&AccountInfo| account.key() == INSTRUCTIONS_SYSVAR_ID;
+    (
+        remaining_accounts
+            .iter()
+            .find(|account| !is_sysvar(account)),
+        remaining_accounts.iter().find(is_sysvar),
+    )
+}
+
+/// Whether `ix` swaps token A into `liquidity_pool`, or `None` if it doesn't swap on it.
+fn swap_direction(
+    ix: &Instruction,
+    liquidity_pool: &Pubkey,
+    token_a_mint: &Pubkey,
+) -> Result<Option<bool>> {
+    let account = |index: usize| ix.accounts.get(index).map(|meta| meta.pubkey);
+    if ix.data.starts_with(instruction::Swap::DISCRIMINATOR) {
+        if account(SWAP_POOL_INDEX) == Some(*liquidity_pool) {
+            return Ok(Some(account(SWAP_INPUT_MINT_INDEX) == Some(*token_a_mint)));
+        }
+    } else if let Some(mut args) = ix.data.strip_prefix(instruction::SwapV2::DISCRIMINATOR) {
+        if account(SWAP_V2_POOL_INDEX) == Some(*liquidity_pool) {
+            return Ok(Some(
+                instruction::SwapV2::deserialize(&mut args)?.is_token_a_in,
+            ));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{
+        solana_program::{instruction::AccountMeta, sysvar::instructions::ID as INSTRUCTIONS_ID},
+        InstructionData, ToAccountMetas,
+    };
+
+    use super::*;
+    use crate::{accounts, error::AmmError};
+
+    struct Pool {
+        liquidity_pool: Pubkey,
+        token_a_mint: Pubkey,
+        token_b_mint: Pubkey,
+    }
+
+    impl Pool {
+        fn new() -> Self {
+            Pool {
+                liquidity_pool: Pubkey::new_unique(),
+                token_a_mint: Pubkey::new_unique(),
+                token_b_mint: Pubkey::new_unique(),
+            }
+        }
+
+        fn swap(&self, is_token_a_in: bool) -> Instruction {
+            let (token_0_mint, token_1_mint) = if is_token_a_in {
+                (self.token_a_mint, self.token_b_mint)
+            } else {
+                (self.token_b_mint, self.token_a_mint)
+            };
+            Instruction {
+                program_id: crate::ID,
+                accounts: accounts::Swap {
+                    signer: Pubkey::new_unique(),
+                    token_0_signer_token_account: Pubkey::new_unique(),
+                    token_1_signer_token_account: Pubkey::new_unique(),
+                    token_0_vault: Pubkey::new_unique(),
+                    token_1_vault: Pubkey::new_unique(),
+                    token_0_mint,
+                    token_1_mint,
+                    lp_token_mint: Pubkey::new_unique(),
+                    liquidity_pool: self.liquidity_pool,
+                    pool_stats: Pubkey::new_unique(),
+                    global_state: Pubkey::new_unique(),
+                    token_program: Pubkey::new_unique(),
+                    associated_token_program: Pubkey::new_unique(),
+                    system_program: Pubkey::new_unique(),
+                    referrer_token_account: None,
+                    event_authority: Pubkey::new_unique(),
+                    program: crate::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::Swap {
+                    token_0_amount: 1_000,
+                    token_1_min_amount: 0,
+                    expiration: i64::MAX,
+                    referral_fee_bps: 0,
+                }
+                .data(),
+            }
+        }
+
+        fn swap_v2(&self, is_token_a_in: bool) -> Instruction {
+            Instruction {
+                program_id: crate::ID,
+                accounts: accounts::SwapV2 {
+                    signer: Pubkey::new_unique(),
+                    token_a_signer_token_account: Pubkey::new_unique(),
+                    token_b_signer_token_account: Pubkey::new_unique(),
+                    token_a_vault: Pubkey::new_unique(),
+                    token_b_vault: Pubkey::new_unique(),
+                    token_a_mint: self.token_a_mint,
+                    token_b_mint: self.token_b_mint,
+                    liquidity_pool: self.liquidity_pool,
+                    pool_stats: Pubkey::new_unique(),
+                    global_state: Pubkey::new_unique(),
+                    token_program: Pubkey::new_unique(),
+                    referrer_token_account: None,
+                    instructions_sysvar: Some(INSTRUCTIONS_ID),
+                    event_authority: Pubkey::new_unique(),
+                    program: crate::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::SwapV2 {
+                    amount_in: 1_000,
+                    min_amount_out: 0,
+                    is_token_a_in,
+                    expiration: i64::MAX,
+                    referral_fee_bps: 0,
+                }
+                .data(),
+            }
+        }
+
+        /// Runs the guard for a swap at `current_index` of `instructions`.
+        fn check(
+            &self,
+            instructions: &[Instruction],
+            current_index: u16,
+            is_token_a_in: bool,
+        ) -> Result<()> {
+            let mut data = instructions_sysvar_data(instructions, current_index);
+            let mut lamports = 0;
+            let owner = Pubkey::default();
+            let instructions_sysvar = AccountInfo::new(
+                &INSTRUCTIONS_ID,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+            check_no_opposite_swap(
+                &instructions_sysvar,
+                &self.liquidity_pool,
+                &self.token_a_mint,
+                is_token_a_in,
+            )
+        }
+    }
+
+    /// Instructions sysvar data as the runtime lays it out.
+    fn instructions_sysvar_data(instructions: &[Instruction], current_index: u16) -> Vec<u8> {
+        let mut data = (instructions.len() as u16).to_le_bytes().to_vec();
+        data.resize(2 + 2 * instructions.len(), 0);
+        for (i, ix) in instructions.iter().enumerate() {
+            let offset = data.len() as u16;
+            data[2 + 2 * i..4 + 2 * i].copy_from_slice(&offset.to_le_bytes());
+            data.extend_from_slice(&(ix.accounts.len() as u16).to_le_bytes());
+            for AccountMeta {
+                pubkey,
+                is_signer,
+                is_writable,
+            } in &ix.accounts
+            {
+                data.push(*is_signer as u8 | (*is_writable as u8) << 1);
+                data.extend_from_slice(pubkey.as_ref());
+            }
+            data.extend_from_slice(ix.program_id.as_ref());
+            data.extend_from_slice(&(ix.data.len() as u16).to_le_bytes());
+            data.extend_from_slice(&ix.data);
+        }
+        data.extend_from_slice(&current_index.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn split_swap_remaining_accounts_in_either_order() {
+        let oracle_key = Pubkey::new_unique();
+        let owner = Pubkey::default();
+        let (mut oracle_lamports, mut sysvar_lamports) = (0, 0);
+        let (mut oracle_data, mut sysvar_data) = (Vec::new(), Vec::new());
+        let oracle = AccountInfo::new(
+            &oracle_key,
+            false,
+            false,
+            &mut oracle_lamports,
+            &mut oracle_data,
+            &owner,
+            false,
+            0,
+        );
+        let sysvar = AccountInfo::new(
+            &INSTRUCTIONS_ID,
+            false,
+            false,
+            &mut sysvar_lamports,
+            &mut sysvar_data,
+            &owner,
+            false,
+            0,
+        );
+
+        for accounts in [
+            vec![oracle.clone(), sysvar.clone()],
+            vec![sysvar.clone(), oracle.clone()],
+        ] {
+            let (found_oracle, found_sysvar) = split_swap_remaining_accounts(&accounts);
+            assert_eq!(found_oracle.map(|account| account.key()), Some(oracle_key));
+            assert_eq!(
+                found_sysvar.map(|account| account.key()),
+                Some(INSTRUCTIONS_ID)
+            );
+        }
+        // A lone sysvar is not mistaken for the oracle
+        let accounts = [sysvar.clone()];
+        let (found_oracle, found_sysvar) = split_swap_remaining_accounts(&accounts);
+        assert!(found_oracle.is_none());
+        assert!(found_sysvar.is_some());
+        assert_eq!(split_swap_remaining_accounts(&[]).0.map(|a| a.key()), None);
+    }
+
+    #[test]
+    fn account_indices_match_swap_accounts() {
+        let pool = Pool::new();
+        let swap = pool.swap(false);
+        assert_eq!(swap.accounts[SWAP_POOL_INDEX].pubkey, pool.liquidity_pool);
+        assert_eq!(
+            swap.accounts[SWAP_INPUT_MINT_INDEX].pubkey,
+            pool.token_b_mint
+        );
+        let swap_v2 = pool.swap_v2(false);
+        assert_eq!(
+            swap_v2.accounts[SWAP_V2_POOL_INDEX].pubkey,
+            pool.liquidity_pool
+        );
+    }
+
+    #[test]
+    fn swap_direction_reads_both_swap_versions() {
+        let pool = Pool::new();
+        for is_token_a_in in [true, false] {
+            for ix in [pool.swap(is_token_a_in), pool.swap_v2(is_token_a_in)] {
+                assert_eq!(
+                    swap_direction(&ix, &pool.liquidity_pool, &pool.token_a_mint).unwrap(),
+                    Some(is_token_a_in)
+                );
+                assert_eq!(
+                    swap_direction(&ix, &Pubkey::new_unique(), &pool.token_a_mint).unwrap(),
+                    None
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn same_direction_swaps_pass() {
+        let pool = Pool::new();
+        for is_token_a_in in [true, false] {
+            let instructions = [
+                pool.swap(is_token_a_in),
+                pool.swap_v2(is_token_a_in),
+                pool.swap(is_token_a_in),
+            ];
+            pool.check(&instructions, 1, is_token_a_in).unwrap();
+            // Swaps of other pools don't count either way
+            let other = Pool::new();
+            let instructions = [other.swap(!is_token_a_in), pool.swap_v2(is_token_a_in)];
+            pool.check(&instructions, 1, is_token_a_in).unwrap();
+        }
+    }
+
+    #[test]
+    fn opposite_swaps_are_rejected() {
+        let pool = Pool::new();
+        for is_token_a_in in [true, false] {
+            for (front, back) in [
+                (pool.swap(!is_token_a_in), pool.swap(is_token_a_in)),
+                (pool.swap_v2(!is_token_a_in), pool.swap_v2(is_token_a_in)),
+            ] {
+                let instructions = [front, pool.swap_v2(is_token_a_in), back];
+                assert_eq!(
+                    pool.check(&instructions, 1, is_token_a_in).unwrap_err(),
+                    AmmError::OppositeSwapInTransaction.into()
+                );
+            }
+        }
+    }
+}
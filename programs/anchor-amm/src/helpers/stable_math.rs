@@ -0,0 +1,357 @@
+use crate::{
+    error::{AmmError, MathError},
+    STABLE_MAX_ITERATIONS,
+};
+use anchor_lang::prelude::*;
+
+use super::{div_full, full_mul, mul_div, WAD};
+
+/// Number of coins in a pool, `n` in the StableSwap paper
+const N_COINS: u128 = 2;
+
+/// Solves the StableSwap invariant `D` for two normalized reserves.
+///
+/// Formula: `A * n^n * (x + y) + D = A * n^n * D + D^(n+1) / (n^n * x * y)`
+///
+/// Uses Newton's method starting from `D = x + y`, like Curve's `get_D`:
+/// - `D_P = D^3 / (4 * x * y)`
+/// - `D' = (Ann * S + 2 * D_P) * D / ((Ann - 1) * D + 3 * D_P)`
+///
+/// # Arguments
+/// * `amp` - The amplification coefficient `A`
+/// * `x` - Token A reserves scaled by its precision multiplier
+/// * `y` - Token B reserves scaled by its precision multiplier
+///
+/// # Errors
+/// * `AmmError::InvalidAmp` - If `amp` is zero
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `MathError::DivisionByZero` - If either reserve is zero while the other isn't
+/// * `AmmError::InvariantDidNotConverge` - If `D` hasn't settled after `STABLE_MAX_ITERATIONS`
+pub fn compute_d(amp: u64, x: u128, y: u128) -> Result<u128> {
+    require_gt!(amp, 0, AmmError::InvalidAmp);
+    let sum = x.checked_add(y).ok_or(MathError::Overflow)?;
+    if sum == 0 {
+        return Ok(0);
+    }
+    let ann = (amp as u128) * N_COINS * N_COINS;
+    let ann_sum = ann.checked_mul(sum).ok_or(MathError::Overflow)?;
+
+    let mut d = sum;
+    for _ in 0..STABLE_MAX_ITERATIONS {
+        let d_p = cube_div(d, x * N_COINS, y * N_COINS)?;
+        let d_prev = d;
+
+        let numerator = d_p
+            .checked_mul(N_COINS)
+            .and_then(|v| v.checked_add(ann_sum))
+            .ok_or(MathError::Overflow)?;
+        let denominator = (ann - 1)
+            .checked_mul(d)
+            .and_then(|v| v.checked_add(d_p.checked_mul(N_COINS + 1)?))
+            .ok_or(MathError::Overflow)?;
+        d = mul_div(numerator, d, denominator, false)?;
+
+        if d.abs_diff(d_prev) <= 1 {
+            return Ok(d);
+        }
+    }
+    err!(AmmError::InvariantDidNotConverge)
+}
+
+/// Solves for the normalized reserve of one token given the other and the invariant `D`.
+///
+/// Newton's method on `y^2 + (b - D) * y = c`, where
+/// `b = x + D / Ann` and `c = D^3 / (4 * x * Ann)`, like Curve's `get_y`.
+///
+/// # Errors
+/// * `AmmError::InvalidAmp` - If `amp` is zero
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `MathError::DivisionByZero` - If `x` is zero
+/// * `AmmError::InvariantDidNotConverge` - If `y` hasn't settled after `STABLE_MAX_ITERATIONS`
+pub fn compute_y(amp: u64, x: u128, d: u128) -> Result<u128> {
+    require_gt!(amp, 0, AmmError::InvalidAmp);
+    let ann = (amp as u128) * N_COINS * N_COINS;
+    let c = cube_div(
+        d,
+        x.checked_mul(N_COINS).ok_or(MathError::Overflow)?,
+        ann * N_COINS,
+    )?;
+    let b = x.checked_add(d / ann).ok_or(MathError::Overflow)?;
+
+    let mut y = d;
+    for _ in 0..STABLE_MAX_ITERATIONS {
+        let y_prev = y;
+        // y' = (y^2 + c) / (2y + b - D), with a 256-bit numerator
+        let (hi, lo) = full_mul(y, y);
+        let (lo, carry) = lo.overflowing_add(c);
+        let denominator = y
+            .checked_mul(2)
+            .and_then(|v| v.checked_add(b))
+            .and_then(|v| v.checked_sub(d))
+            .ok_or(MathError::Overflow)?;
+        y = div_full(hi + carry as u128, lo, denominator)
+            .ok_or(MathError::Overflow)?
+            .0;
+
+        if y.abs_diff(y_prev) <= 1 {
+            return Ok(y);
+        }
+    }
+    err!(AmmError::InvariantDidNotConverge)
+}
+
+/// Calculates `d^3 / (a * b)`, rounded down once.
+///
+/// Dividing by `a` and `b` one after the other with `mul_div` rounds twice, and
+/// the first error is scaled by `d / b`. At very imbalanced reserves that is enough
+/// to leave Newton's method cycling between a few values, so the remainder of
+/// the first division is carried into the second.
+fn cube_div(d: u128, a: u128, b: u128) -> Result<u128> {
+    require!(a > 0 && b > 0, MathError::DivisionByZero);
+    let (a, b) = (a.max(b), a.min(b));
+    let (hi, lo) = full_mul(d, d);
+    let (q, r) = div_full(hi, lo, a).ok_or(MathError::Overflow)?;
+
+    // d^3 / (a * b) = (q * d + r * d / a) / b
+    let (hi, lo) = full_mul(q, d);
+    let (lo, carry) = lo.overflowing_add(mul_div(r, d, a, false)?);
+    let hi = hi.checked_add(carry as u128).ok_or(MathError::Overflow)?;
+    Ok(div_full(hi, lo, b).ok_or(MathError::Overflow)?.0)
+}
+
+/// Calculates the output amount for a StableSwap swap.
+///
+/// Normalizes both reserves to a common precision, moves the input reserve by
+/// `amount_in` and solves for the output reserve that keeps `D` constant.
+/// One unit is kept back from the output to absorb Newton rounding.
+///
+/// # Arguments
+/// * `amount_in` - The input token amount, after fees
+/// * `reserve_in` / `reserve_out` - Raw reserves of the input and output token
+/// * `multiplier_in` / `multiplier_out` - Precision multipliers of the input and output token
+/// * `amp` - The current amplification coefficient
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If either reserve is zero
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `AmmError::InvariantDidNotConverge` - If Newton's method doesn't settle
+pub fn get_stable_amount_out(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    multiplier_in: u128,
+    multiplier_out: u128,
+    amp: u64,
+) -> Result<u128> {
+    require!(
+        reserve_in > 0 && reserve_out > 0,
+        AmmError::InsufficientLiquidity
+    );
+    let x = reserve_in
+        .checked_mul(multiplier_in)
+        .ok_or(MathError::Overflow)?;
+    let y = reserve_out
+        .checked_mul(multiplier_out)
+        .ok_or(MathError::Overflow)?;
+    let d = compute_d(amp, x, y)?;
+
+    let x_new = amount_in
+        .checked_mul(multiplier_in)
+        .and_then(|v| v.checked_add(x))
+        .ok_or(MathError::Overflow)?;
+    let y_new = compute_y(amp, x_new, d)?;
+
+    let amount_out = y.saturating_sub(y_new).saturating_sub(1);
+    Ok(amount_out / multiplier_out)
+}
+
+/// Calculates the input amount needed for an exact StableSwap swap output.
+///
+/// Moves the output reserve down by `amount_out` and solves for the input reserve
+/// that keeps `D` constant, rounding the result up.
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If either reserve is zero or `amount_out` drains the pool
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `AmmError::InvariantDidNotConverge` - If Newton's method doesn't settle
+pub fn get_stable_amount_in(
+    amount_out: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    multiplier_in: u128,
+    multiplier_out: u128,
+    amp: u64,
+) -> Result<u128> {
+    require!(
+        reserve_in > 0 && reserve_out > amount_out,
+        AmmError::InsufficientLiquidity
+    );
+    let x = reserve_in
+        .checked_mul(multiplier_in)
+        .ok_or(MathError::Overflow)?;
+    let y = reserve_out
+        .checked_mul(multiplier_out)
+        .ok_or(MathError::Overflow)?;
+    let d = compute_d(amp, x, y)?;
+
+    let y_new = y - amount_out * multiplier_out;
+    let x_new = compute_y(amp, y_new, d)?;
+
+    let amount_in = x_new.saturating_sub(x) + 1;
+    Ok(amount_in.div_ceil(multiplier_in))
+}
+
+/// Marginal price of `x` in units of `y` on the StableSwap curve, as a WAD.
+///
+/// Formula: `(y + c) / (x + c)` with `c = 4 * Ann * x^2 * y^2 / D^3`
+///
+/// The ratio of the invariant's partial derivatives, simplified. It tends to 1
+/// as `amp` grows and to the constant-product price `y / x` as `amp` shrinks.
+///
+/// # Arguments
+/// * `amp` - The amplification coefficient `A`
+/// * `x` - Normalized reserves of the priced token
+/// * `y` - Normalized reserves of the quote token
+///
+/// # Errors
+/// * `AmmError::InvalidAmp` - If `amp` is zero
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+/// * `MathError::DivisionByZero` - If either reserve is zero
+pub fn get_stable_spot_price(amp: u64, x: u128, y: u128) -> Result<u128> {
+    let d = compute_d(amp, x, y)?;
+    require_neq!(d, 0, MathError::DivisionByZero);
+    let ann = (amp as u128) * N_COINS * N_COINS;
+    let c = mul_div(
+        ann.checked_mul(4 * x).ok_or(MathError::Overflow)?,
+        y,
+        d,
+        false,
+    )?;
+    let c = mul_div(mul_div(c, x, d, false)?, y, d, false)?;
+    mul_div(
+        y.checked_add(c).ok_or(MathError::Overflow)?,
+        WAD,
+        x.checked_add(c).ok_or(MathError::Overflow)?,
+        false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Raw reserves of a 6-decimal and a 9-decimal token, and their multipliers
+    const MULTIPLIER_A: u128 = 1_000;
+    const MULTIPLIER_B: u128 = 1;
+
+    fn d(amp: u64, reserve_a: u128, reserve_b: u128) -> u128 {
+        compute_d(amp, reserve_a * MULTIPLIER_A, reserve_b * MULTIPLIER_B).unwrap()
+    }
+
+    #[test]
+    fn d_of_balanced_reserves_is_their_sum() {
+        for amp in [1, 100, 5_000] {
+            assert_eq!(compute_d(amp, 1_000_000, 1_000_000).unwrap(), 2_000_000);
+        }
+        assert_eq!(compute_d(100, 0, 0).unwrap(), 0);
+        assert!(compute_d(0, 1, 1).is_err());
+    }
+
+    #[test]
+    fn d_and_y_converge_at_imbalanced_reserves() {
+        for amp in [1, 10, 200, 5_000] {
+            for (x, y) in [
+                (1_000_000_000_000_000, 1_000_000),
+                (1_000_000, 1_000_000_000_000_000),
+                (50_000_000_000, 3_000_000_000_000),
+                (u64::MAX as u128, 1_000),
+            ] {
+                let d = compute_d(amp, x, y).unwrap();
+                assert!(d <= x + y, "amp {amp}: D above x + y");
+                assert!(d > 2 * x.min(y), "amp {amp}: D below the balanced sum");
+                // Solving back for y must land on the reserve D came from
+                let y_solved = compute_y(amp, x, d).unwrap();
+                assert!(
+                    y_solved.abs_diff(y) <= y / 1_000_000 + 2,
+                    "amp {amp}: {x} {y}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spot_price_is_par_when_balanced() {
+        assert_eq!(
+            get_stable_spot_price(100, 1_000_000, 1_000_000).unwrap(),
+            WAD
+        );
+        // Scarcer y makes x cheaper
+        assert!(get_stable_spot_price(100, 2_000_000, 1_000_000).unwrap() < WAD);
+    }
+
+    #[test]
+    fn exact_in_rounds_for_the_pool() {
+        let (reserve_a, reserve_b) = (1_000_000_000_000, 250_000_000_000_000);
+        for amp in [1, 100, 5_000] {
+            for amount_in in [1, 1_000, 1_000_000_007, 400_000_000_000] {
+                let amount_out = get_stable_amount_out(
+                    amount_in,
+                    reserve_a,
+                    reserve_b,
+                    MULTIPLIER_A,
+                    MULTIPLIER_B,
+                    amp,
+                )
+                .unwrap();
+                assert!(
+                    d(amp, reserve_a + amount_in, reserve_b - amount_out)
+                        >= d(amp, reserve_a, reserve_b)
+                );
+
+                // Swapping the output straight back can't return more than was paid
+                let amount_back = get_stable_amount_out(
+                    amount_out,
+                    reserve_b - amount_out,
+                    reserve_a + amount_in,
+                    MULTIPLIER_B,
+                    MULTIPLIER_A,
+                    amp,
+                )
+                .unwrap();
+                assert!(amount_back <= amount_in);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_out_rounds_for_the_pool() {
+        let (reserve_a, reserve_b) = (1_000_000_000_000, 250_000_000_000_000);
+        for amp in [1, 100, 5_000] {
+            for amount_out in [1, 1_000, 1_000_000_007, 200_000_000_000_000] {
+                let amount_in = get_stable_amount_in(
+                    amount_out,
+                    reserve_a,
+                    reserve_b,
+                    MULTIPLIER_A,
+                    MULTIPLIER_B,
+                    amp,
+                )
+                .unwrap();
+                assert!(amount_in > 0);
+                assert!(
+                    d(amp, reserve_a + amount_in, reserve_b - amount_out)
+                        >= d(amp, reserve_a, reserve_b)
+                );
+            }
+        }
+        assert!(get_stable_amount_in(
+            250_000_000_000_000,
+            reserve_a,
+            reserve_b,
+            MULTIPLIER_A,
+            MULTIPLIER_B,
+            100
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,612 @@
+use std::ops::Deref;
+
+use anchor_lang::prelude::*;
+
+use crate::error::{AmmError, MathError};
+
+use super::{
+    calculate_constant_product, check_price_band, compute_d, compute_weighted_invariant,
+    get_amount_in, get_amount_out, get_constant_sum_amount_in, get_constant_sum_amount_out,
+    get_pmm_amount_out, get_stable_amount_in, get_stable_amount_out, get_stable_spot_price,
+    get_weighted_amount_in, get_weighted_amount_out, get_withdraw_amount, inverse_wad, mul_div,
+    WAD,
+};
+
+/// Pricing rules of a two-token pool.
+///
+/// Instructions own the account plumbing (transfers, LP minting, reserve syncing)
+/// and ask the pool's curve for the numbers, so a new curve only needs an
+/// implementation of this trait and a `CurveType` variant.
+///
+/// All amounts are raw token amounts; curves that need normalized reserves
+/// (e.g. StableSwap) scale internally. `is_token_a_in` tells the curve which
+/// side of the pool `reserve_in` belongs to.
+pub trait SwapCurve {
+    /// Output amount for an exact input amount (fees already taken out).
+    fn swap_exact_in(
+        &self,
+        amount_in: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128>;
+
+    /// Input amount (before fees) needed for an exact output amount.
+    fn swap_exact_out(
+        &self,
+        amount_out: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128>;
+
+    /// Pool invariant, stored as `k_last` after every liquidity event.
+    fn invariant(&self, reserve_a: u128, reserve_b: u128) -> Result<u128>;
+
+    /// Converts an invariant to a value that scales linearly with the reserves.
+    ///
+    /// Used for the protocol fee and LP minting. Defaults to the invariant itself.
+    fn root_invariant(&self, invariant: u128) -> u128 {
+        invariant
+    }
+
+    /// LP tokens owed for adding `token_a_amount` and `token_b_amount` to the reserves.
+    ///
+    /// The first deposit mints the root invariant; later deposits mint pro rata to
+    /// its growth: `supply * (V1 - V0) / V0`.
+    fn deposit_lp_tokens(
+        &self,
+        token_a_amount: u128,
+        token_b_amount: u128,
+        reserve_a: u128,
+        reserve_b: u128,
+        lp_supply: u128,
+    ) -> Result<u128> {
+        let reserve_a_after = reserve_a
+            .checked_add(token_a_amount)
+            .ok_or(MathError::Overflow)?;
+        let reserve_b_after = reserve_b
+            .checked_add(token_b_amount)
+            .ok_or(MathError::Overflow)?;
+        let root_after = self.root_invariant(self.invariant(reserve_a_after, reserve_b_after)?);
+        if lp_supply == 0 {
+            return Ok(root_after);
+        }
+        let root_before = self.root_invariant(self.invariant(reserve_a, reserve_b)?);
+        mul_div(
+            lp_supply,
+            root_after.saturating_sub(root_before),
+            root_before,
+            false,
+        )
+    }
+
+    /// Marginal price of token A as raw token B units per raw token A unit (WAD).
+    ///
+    /// Defaults to the reserve ratio, the spot price of a constant-product pool.
+    fn spot_price(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        mul_div(reserve_b, WAD, reserve_a, false)
+    }
+
+    /// Token amounts returned for burning `lp_amount`. Defaults to a pro rata share.
+    fn withdraw_amounts(
+        &self,
+        lp_amount: u128,
+        reserve_a: u128,
+        reserve_b: u128,
+        lp_supply: u128,
+    ) -> Result<(u128, u128)> {
+        Ok((
+            get_withdraw_amount(reserve_a, lp_amount, lp_supply)?,
+            get_withdraw_amount(reserve_b, lp_amount, lp_supply)?,
+        ))
+    }
+}
+
+/// A pool's curve by value, so building one per quote doesn't allocate.
+///
+/// Derefs to `dyn SwapCurve`, so callers use it like any other curve.
+pub enum PoolCurve {
+    ConstantProduct(ConstantProductCurve),
+    Stable(StableCurve),
+    Weighted(WeightedCurve),
+    ConstantSum(ConstantSumCurve),
+    Pmm(PmmCurve),
+}
+
+impl Deref for PoolCurve {
+    type Target = dyn SwapCurve;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            PoolCurve::ConstantProduct(curve) => curve,
+            PoolCurve::Stable(curve) => curve,
+            PoolCurve::Weighted(curve) => curve,
+            PoolCurve::ConstantSum(curve) => curve,
+            PoolCurve::Pmm(curve) => curve,
+        }
+    }
+}
+
+/// Uniswap V2 `x * y = k`.
+pub struct ConstantProductCurve;
+
+impl SwapCurve for ConstantProductCurve {
+    fn swap_exact_in(
+        &self,
+        amount_in: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        _is_token_a_in: bool,
+    ) -> Result<u128> {
+        get_amount_out(amount_in, reserve_in, reserve_out)
+    }
+
+    fn swap_exact_out(
+        &self,
+        amount_out: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        _is_token_a_in: bool,
+    ) -> Result<u128> {
+        get_amount_in(amount_out, reserve_in, reserve_out)
+    }
+
+    fn invariant(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        calculate_constant_product(reserve_a, reserve_b)
+    }
+
+    fn root_invariant(&self, invariant: u128) -> u128 {
+        invariant.isqrt()
+    }
+
+    /// In Uniswap, they decide how many tokens to mint based on the minimum ratio, but
+    /// since deposits are optimized to the pool ratio first it's safe to just go with token A.
+    fn deposit_lp_tokens(
+        &self,
+        token_a_amount: u128,
+        token_b_amount: u128,
+        reserve_a: u128,
+        _reserve_b: u128,
+        lp_supply: u128,
+    ) -> Result<u128> {
+        if lp_supply == 0 {
+            return Ok(self.invariant(token_a_amount, token_b_amount)?.isqrt());
+        }
+        token_a_amount
+            .checked_mul(lp_supply)
+            .ok_or(MathError::Overflow)?
+            .checked_div(reserve_a)
+            .ok_or(MathError::DivisionByZero.into())
+    }
+}
+
+/// Curve StableSwap for pegged assets.
+pub struct StableCurve {
+    /// Amplification coefficient at the current time
+    pub amp: u64,
+    pub token_a_precision_multiplier: u128,
+    pub token_b_precision_multiplier: u128,
+}
+
+impl StableCurve {
+    /// Precision multipliers ordered as `(input, output)`.
+    fn multipliers(&self, is_token_a_in: bool) -> (u128, u128) {
+        if is_token_a_in {
+            (
+                self.token_a_precision_multiplier,
+                self.token_b_precision_multiplier,
+            )
+        } else {
+            (
+                self.token_b_precision_multiplier,
+                self.token_a_precision_multiplier,
+            )
+        }
+    }
+}
+
+impl SwapCurve for StableCurve {
+    fn swap_exact_in(
+        &self,
+        amount_in: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        let (multiplier_in, multiplier_out) = self.multipliers(is_token_a_in);
+        get_stable_amount_out(
+            amount_in,
+            reserve_in,
+            reserve_out,
+            multiplier_in,
+            multiplier_out,
+            self.amp,
+        )
+    }
+
+    fn swap_exact_out(
+        &self,
+        amount_out: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        let (multiplier_in, multiplier_out) = self.multipliers(is_token_a_in);
+        get_stable_amount_in(
+            amount_out,
+            reserve_in,
+            reserve_out,
+            multiplier_in,
+            multiplier_out,
+            self.amp,
+        )
+    }
+
+    fn spot_price(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        let price = get_stable_spot_price(
+            self.amp,
+            reserve_a
+                .checked_mul(self.token_a_precision_multiplier)
+                .ok_or(MathError::Overflow)?,
+            reserve_b
+                .checked_mul(self.token_b_precision_multiplier)
+                .ok_or(MathError::Overflow)?,
+        )?;
+        mul_div(
+            price,
+            self.token_a_precision_multiplier,
+            self.token_b_precision_multiplier,
+            false,
+        )
+    }
+
+    fn invariant(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        compute_d(
+            self.amp,
+            reserve_a
+                .checked_mul(self.token_a_precision_multiplier)
+                .ok_or(MathError::Overflow)?,
+            reserve_b
+                .checked_mul(self.token_b_precision_multiplier)
+                .ok_or(MathError::Overflow)?,
+        )
+    }
+}
+
+/// Constant sum `x + y = k` for assets that must trade 1:1, with an optional price band.
+pub struct ConstantSumCurve {
+    pub token_a_precision_multiplier: u128,
+    pub token_b_precision_multiplier: u128,
+    /// Allowed deviation from an even split of the reserves; zero disables the band
+    pub price_band_bps: u16,
+}
+
+impl ConstantSumCurve {
+    /// Precision multipliers ordered as `(input, output)`.
+    fn multipliers(&self, is_token_a_in: bool) -> (u128, u128) {
+        if is_token_a_in {
+            (
+                self.token_a_precision_multiplier,
+                self.token_b_precision_multiplier,
+            )
+        } else {
+            (
+                self.token_b_precision_multiplier,
+                self.token_a_precision_multiplier,
+            )
+        }
+    }
+
+    /// Checks the reserves left by a swap against the price band.
+    fn check_band(
+        &self,
+        amount_in: u128,
+        amount_out: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<()> {
+        let (multiplier_in, multiplier_out) = self.multipliers(is_token_a_in);
+        let normalized_in_after = reserve_in
+            .checked_add(amount_in)
+            .and_then(|reserve| reserve.checked_mul(multiplier_in))
+            .ok_or(MathError::Overflow)?;
+        let normalized_out_after = (reserve_out - amount_out)
+            .checked_mul(multiplier_out)
+            .ok_or(MathError::Overflow)?;
+        check_price_band(
+            normalized_in_after,
+            normalized_out_after,
+            self.price_band_bps,
+        )
+    }
+}
+
+impl SwapCurve for ConstantSumCurve {
+    fn swap_exact_in(
+        &self,
+        amount_in: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        let (multiplier_in, multiplier_out) = self.multipliers(is_token_a_in);
+        let amount_out =
+            get_constant_sum_amount_out(amount_in, reserve_out, multiplier_in, multiplier_out)?;
+        self.check_band(
+            amount_in,
+            amount_out,
+            reserve_in,
+            reserve_out,
+            is_token_a_in,
+        )?;
+        Ok(amount_out)
+    }
+
+    fn swap_exact_out(
+        &self,
+        amount_out: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        let (multiplier_in, multiplier_out) = self.multipliers(is_token_a_in);
+        let amount_in =
+            get_constant_sum_amount_in(amount_out, reserve_out, multiplier_in, multiplier_out)?;
+        self.check_band(
+            amount_in,
+            amount_out,
+            reserve_in,
+            reserve_out,
+            is_token_a_in,
+        )?;
+        Ok(amount_in)
+    }
+
+    /// Always par, scaled to raw units.
+    fn spot_price(&self, _reserve_a: u128, _reserve_b: u128) -> Result<u128> {
+        mul_div(
+            WAD,
+            self.token_a_precision_multiplier,
+            self.token_b_precision_multiplier,
+            false,
+        )
+    }
+
+    fn invariant(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        reserve_a
+            .checked_mul(self.token_a_precision_multiplier)
+            .zip(reserve_b.checked_mul(self.token_b_precision_multiplier))
+            .and_then(|(a, b)| a.checked_add(b))
+            .ok_or(MathError::Overflow.into())
+    }
+}
+
+/// DODO-style proactive market maker priced by an external oracle.
+///
+/// Token A is the base token and `oracle_price` its price in token B. Liquidity
+/// is concentrated around the oracle price; `k` controls how quickly the price
+/// moves away from it as one side drains below its equilibrium target.
+pub struct PmmCurve {
+    /// Raw token B units per raw token A unit (WAD), as of the last oracle refresh
+    pub oracle_price: u128,
+    /// Liquidity parameter (WAD)
+    pub k: u128,
+    pub token_a_target: u128,
+    pub token_b_target: u128,
+}
+
+impl SwapCurve for PmmCurve {
+    fn swap_exact_in(
+        &self,
+        amount_in: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        if is_token_a_in {
+            get_pmm_amount_out(
+                amount_in,
+                reserve_in,
+                reserve_out,
+                self.token_a_target,
+                self.token_b_target,
+                self.oracle_price,
+                self.k,
+            )
+        } else {
+            get_pmm_amount_out(
+                amount_in,
+                reserve_in,
+                reserve_out,
+                self.token_b_target,
+                self.token_a_target,
+                inverse_wad(self.oracle_price)?,
+                self.k,
+            )
+        }
+    }
+
+    /// The PMM curve has no closed form for exact-output trades.
+    fn swap_exact_out(
+        &self,
+        _amount_out: u128,
+        _reserve_in: u128,
+        _reserve_out: u128,
+        _is_token_a_in: bool,
+    ) -> Result<u128> {
+        err!(AmmError::UnsupportedCurveOperation)
+    }
+
+    /// The marginal price depends on the equilibrium targets, not just the reserves.
+    fn spot_price(&self, _reserve_a: u128, _reserve_b: u128) -> Result<u128> {
+        err!(AmmError::UnsupportedCurveOperation)
+    }
+
+    /// The pool's value depends on the oracle price, so there's no reserve-only
+    /// invariant. Returning zero keeps `k_last` at zero, which disables the protocol fee.
+    fn invariant(&self, _reserve_a: u128, _reserve_b: u128) -> Result<u128> {
+        Ok(0)
+    }
+
+    /// Deposits are proportional to the reserves, like constant-product pools.
+    fn deposit_lp_tokens(
+        &self,
+        token_a_amount: u128,
+        token_b_amount: u128,
+        reserve_a: u128,
+        reserve_b: u128,
+        lp_supply: u128,
+    ) -> Result<u128> {
+        ConstantProductCurve.deposit_lp_tokens(
+            token_a_amount,
+            token_b_amount,
+            reserve_a,
+            reserve_b,
+            lp_supply,
+        )
+    }
+}
+
+/// Balancer weighted product `x^w_a * y^w_b = V`.
+pub struct WeightedCurve {
+    pub weight_a: u16,
+    pub weight_b: u16,
+}
+
+impl WeightedCurve {
+    /// Weights ordered as `(input, output)`.
+    fn weights(&self, is_token_a_in: bool) -> (u16, u16) {
+        if is_token_a_in {
+            (self.weight_a, self.weight_b)
+        } else {
+            (self.weight_b, self.weight_a)
+        }
+    }
+}
+
+impl SwapCurve for WeightedCurve {
+    fn swap_exact_in(
+        &self,
+        amount_in: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        let (weight_in, weight_out) = self.weights(is_token_a_in);
+        get_weighted_amount_out(amount_in, reserve_in, reserve_out, weight_in, weight_out)
+    }
+
+    fn swap_exact_out(
+        &self,
+        amount_out: u128,
+        reserve_in: u128,
+        reserve_out: u128,
+        is_token_a_in: bool,
+    ) -> Result<u128> {
+        let (weight_in, weight_out) = self.weights(is_token_a_in);
+        get_weighted_amount_in(amount_out, reserve_in, reserve_out, weight_in, weight_out)
+    }
+
+    /// `(y / w_b) / (x / w_a)`
+    fn spot_price(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        mul_div(
+            mul_div(reserve_b, WAD, reserve_a, false)?,
+            self.weight_a as u128,
+            self.weight_b as u128,
+            false,
+        )
+    }
+
+    fn invariant(&self, reserve_a: u128, reserve_b: u128) -> Result<u128> {
+        compute_weighted_invariant(reserve_a, reserve_b, self.weight_a, self.weight_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESERVES: [(u128, u128); 3] = [
+        (1_000_000, 1_000_000),
+        (5_000_000_000_000, 7_000_000),
+        (13, 1_000_000_000_000_000_000),
+    ];
+
+    #[test]
+    fn constant_product_exact_in_rounds_for_the_pool() {
+        for (reserve_in, reserve_out) in RESERVES {
+            for amount_in in [1, 3, 999, 1_000_000_007] {
+                let amount_out = ConstantProductCurve
+                    .swap_exact_in(amount_in, reserve_in, reserve_out, true)
+                    .unwrap();
+                assert!(
+                    (reserve_in + amount_in) * (reserve_out - amount_out)
+                        >= reserve_in * reserve_out
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn constant_product_exact_out_rounds_for_the_pool() {
+        for (reserve_in, reserve_out) in RESERVES {
+            for amount_out in [1, 3, 999, reserve_out / 2, reserve_out - 1] {
+                let amount_in = ConstantProductCurve
+                    .swap_exact_out(amount_out, reserve_in, reserve_out, true)
+                    .unwrap();
+                assert!(
+                    (reserve_in + amount_in) * (reserve_out - amount_out)
+                        >= reserve_in * reserve_out
+                );
+                // The input quoted for an output never buys less than that output
+                assert!(
+                    ConstantProductCurve
+                        .swap_exact_in(amount_in, reserve_in, reserve_out, true)
+                        .unwrap()
+                        >= amount_out
+                );
+            }
+            assert!(ConstantProductCurve
+                .swap_exact_out(reserve_out, reserve_in, reserve_out, true)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn constant_product_deposits_round_down() {
+        let curve = ConstantProductCurve;
+        assert_eq!(
+            curve.deposit_lp_tokens(4_000, 9_000, 0, 0, 0).unwrap(),
+            6_000
+        );
+        // 1_000 / 3_001 of the supply, rounded down
+        assert_eq!(
+            curve
+                .deposit_lp_tokens(1_000, 2_000, 3_001, 6_002, 10_000)
+                .unwrap(),
+            3_332
+        );
+    }
+
+    #[test]
+    fn pmm_has_no_exact_out() {
+        let curve = PmmCurve {
+            oracle_price: WAD,
+            k: WAD / 2,
+            token_a_target: 1_000_000,
+            token_b_target: 1_000_000,
+        };
+        assert!(curve
+            .swap_exact_out(1_000, 1_000_000, 1_000_000, true)
+            .is_err());
+        // 1_000 in pays out 999.5 on the curve
+        assert_eq!(
+            curve
+                .swap_exact_in(1_000, 1_000_000, 1_000_000, true)
+                .unwrap(),
+            999
+        );
+    }
+}
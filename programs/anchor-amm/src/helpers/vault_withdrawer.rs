@@ -11,22 +11,22 @@ pub trait VaultWithdrawer<'info> {
     fn token_b_mint(&self) -> &Account<'info, Mint>;
     fn token_a_vault(&self) -> &Account<'info, TokenAccount>;
     fn token_b_vault(&self) -> &Account<'info, TokenAccount>;
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool>;
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool>;
 
     fn withdraw(&self, token_a_amount: u64, token_b_amount: u64) -> Result<()> {
         if token_a_amount > 0 {
             self.withdraw_token(
-                &self.token_a_mint(),
-                &self.token_a_vault(),
-                &self.token_a_signer_token_account(),
+                self.token_a_mint(),
+                self.token_a_vault(),
+                self.token_a_signer_token_account(),
                 token_a_amount,
             )?;
         }
         if token_b_amount > 0 {
             self.withdraw_token(
-                &self.token_b_mint(),
-                &self.token_b_vault(),
-                &self.token_b_signer_token_account(),
+                self.token_b_mint(),
+                self.token_b_vault(),
+                self.token_b_signer_token_account(),
                 token_b_amount,
             )?;
         }
@@ -42,7 +42,7 @@ pub trait VaultWithdrawer<'info> {
     ) -> Result<()> {
         let token_a_key = self.token_a_mint().key();
         let token_b_key = self.token_b_mint().key();
-        let bump = self.liquidity_pool().bump;
+        let bump = self.liquidity_pool().load()?.bump;
 
         let signer_seeds: &[&[&[u8]]] = &[&[
             LIQUIDITY_POOL_SEED.as_bytes(),
@@ -0,0 +1,459 @@
+use crate::{
+    error::{AmmError, MathError},
+    MAX_WEIGHTED_SWAP_RATIO_BPS, WEIGHT_DENOMINATOR,
+};
+use anchor_lang::prelude::*;
+
+use super::mul_div;
+
+/// 1.0 in 18-decimal fixed point
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+
+/// `ln(2)` in 18-decimal fixed point
+const LN_2_WAD: i128 = 693_147_180_559_945_309;
+
+/// Largest exponent `exp_wad` accepts, just under `ln(u128::MAX / WAD)`
+const MAX_EXP_WAD: i128 = 47 * WAD as i128;
+
+/// Below this `exp_wad` rounds to zero, as `e^-42 < 1e-18`
+const MIN_EXP_WAD: i128 = -42 * WAD as i128;
+
+/// Relative error allowed for `pow_wad`, applied against the trader (1e-14)
+const POW_ERROR_DIVISOR: u128 = 100_000_000_000_000;
+
+/// Calculates the natural logarithm of a positive fixed point number.
+///
+/// Splits `x = m * 2^k` with `m` in `[1, 2)`, then evaluates
+/// `ln(m) = 2 * atanh((m - 1) / (m + 1))` with its power series.
+///
+/// # Errors
+/// * `MathError::LogarithmOfZero` - If `x` is zero
+pub fn ln_wad(x: u128) -> Result<i128> {
+    require_gt!(x, 0, MathError::LogarithmOfZero);
+    let mut k: i128 = 0;
+    let mut m = x;
+    while m >= 2 * WAD {
+        m >>= 1;
+        k += 1;
+    }
+    while m < WAD {
+        m <<= 1;
+        k -= 1;
+    }
+
+    // z is in [0, 1/3), so every term shrinks by at least 9x
+    let z = (m - WAD) * WAD / (m + WAD);
+    let z_squared = z * z / WAD;
+    let mut term = z;
+    let mut sum = z;
+    let mut n = 1;
+    while term > 0 {
+        term = term * z_squared / WAD;
+        n += 2;
+        sum += term / n;
+    }
+    Ok(k * LN_2_WAD + 2 * sum as i128)
+}
+
+/// Calculates `e^x` for a signed fixed point exponent.
+///
+/// Splits `x = k * ln(2) + r` with `r` in `[0, ln(2))`, evaluates `e^r`
+/// with its Taylor series and shifts by `k`.
+///
+/// # Errors
+/// * `MathError::Overflow` - If `x` is above `MAX_EXP_WAD`
+pub fn exp_wad(x: i128) -> Result<u128> {
+    require_gte!(MAX_EXP_WAD, x, MathError::Overflow);
+    if x < MIN_EXP_WAD {
+        return Ok(0);
+    }
+    let k = x.div_euclid(LN_2_WAD);
+    let r = x.rem_euclid(LN_2_WAD) as u128;
+
+    let mut term = WAD;
+    let mut sum = WAD;
+    let mut n = 1;
+    while term > 0 {
+        term = term * r / (WAD * n);
+        sum += term;
+        n += 1;
+    }
+    if k >= 0 {
+        require_gte!(sum.leading_zeros() as i128, k, MathError::Overflow);
+        Ok(sum << k)
+    } else {
+        Ok(sum >> -k)
+    }
+}
+
+/// Calculates `base^exponent` for fixed point numbers as `e^(exponent * ln(base))`.
+///
+/// # Errors
+/// * `MathError::LogarithmOfZero` - If `base` is zero
+/// * `MathError::Overflow` - If the result doesn't fit
+pub fn pow_wad(base: u128, exponent: u128) -> Result<u128> {
+    let exponent: i128 = exponent.try_into().map_err(|_| MathError::Overflow)?;
+    let ln_base = ln_wad(base)?;
+    let product = ln_base.checked_mul(exponent).ok_or(MathError::Overflow)? / WAD as i128;
+    exp_wad(product)
+}
+
+/// Converts a weight in basis points to fixed point.
+fn weight_wad(weight: u16) -> u128 {
+    weight as u128 * WAD / WEIGHT_DENOMINATOR as u128
+}
+
+/// Calculates the weighted invariant `V = a^w_a * b^w_b` for raw reserves.
+///
+/// For a 50/50 pool this is `sqrt(a * b)`, so it scales linearly with the reserves
+/// just like `sqrt(k)` does for constant-product pools.
+///
+/// # Errors
+/// * `MathError::LogarithmOfZero` - If either reserve is zero
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn compute_weighted_invariant(
+    reserve_a: u128,
+    reserve_b: u128,
+    weight_a: u16,
+    weight_b: u16,
+) -> Result<u128> {
+    compute_multi_weighted_invariant(&[reserve_a, reserve_b], &[weight_a, weight_b])
+}
+
+/// Calculates the weighted invariant `V = Π b_i^w_i` for any number of raw reserves.
+///
+/// # Errors
+/// * `MathError::LogarithmOfZero` - If any reserve is zero
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn compute_multi_weighted_invariant(reserves: &[u128], weights: &[u16]) -> Result<u128> {
+    let mut exponent: i128 = 0;
+    for (reserve, weight) in reserves.iter().zip(weights) {
+        let ln_reserve = ln_wad(reserve.checked_mul(WAD).ok_or(MathError::Overflow)?)?;
+        exponent = ln_reserve
+            .checked_mul(weight_wad(*weight) as i128)
+            .and_then(|v| exponent.checked_add(v / WAD as i128))
+            .ok_or(MathError::Overflow)?;
+    }
+    Ok(exp_wad(exponent)? / WAD)
+}
+
+/// Calculates the output amount for a weighted-product swap.
+///
+/// Formula: `Δy = y * (1 - (x / (x + Δx))^(w_in / w_out))`
+///
+/// This is derived from the weighted invariant `x^w_in * y^w_out = V`.
+/// The power is rounded up and padded by `POW_ERROR_DIVISOR` so approximation
+/// error never favours the trader.
+///
+/// # Arguments
+/// * `amount_in` - The input token amount, after fees (Δx)
+/// * `reserve_in` / `reserve_out` - The input and output token reserves (x, y)
+/// * `weight_in` / `weight_out` - The input and output token weights in basis points
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If either reserve is zero
+/// * `AmmError::MaxInRatioExceeded` - If Δx exceeds `MAX_WEIGHTED_SWAP_RATIO_BPS` of x
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn get_weighted_amount_out(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    weight_in: u16,
+    weight_out: u16,
+) -> Result<u128> {
+    require!(
+        reserve_in > 0 && reserve_out > 0,
+        AmmError::InsufficientLiquidity
+    );
+    require!(
+        amount_in <= reserve_in * MAX_WEIGHTED_SWAP_RATIO_BPS as u128 / WEIGHT_DENOMINATOR as u128,
+        AmmError::MaxInRatioExceeded
+    );
+    let base = mul_div(
+        reserve_in,
+        WAD,
+        reserve_in
+            .checked_add(amount_in)
+            .ok_or(MathError::Overflow)?,
+        true,
+    )?;
+    let exponent = weight_in as u128 * WAD / weight_out as u128;
+    let power = pow_wad(base, exponent)?;
+    let power = (power + power / POW_ERROR_DIVISOR + 1).min(WAD);
+    mul_div(reserve_out, WAD - power, WAD, false)
+}
+
+/// Calculates the input amount needed for an exact weighted-product swap output.
+///
+/// Formula: `Δx = x * ((y / (y - Δy))^(w_out / w_in) - 1)`
+///
+/// The power is rounded up and padded by `POW_ERROR_DIVISOR` so approximation
+/// error never favours the trader.
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If either reserve is zero
+/// * `AmmError::MaxOutRatioExceeded` - If Δy exceeds `MAX_WEIGHTED_SWAP_RATIO_BPS` of y
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn get_weighted_amount_in(
+    amount_out: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    weight_in: u16,
+    weight_out: u16,
+) -> Result<u128> {
+    require!(
+        reserve_in > 0 && reserve_out > 0,
+        AmmError::InsufficientLiquidity
+    );
+    require!(
+        amount_out
+            <= reserve_out * MAX_WEIGHTED_SWAP_RATIO_BPS as u128 / WEIGHT_DENOMINATOR as u128,
+        AmmError::MaxOutRatioExceeded
+    );
+    let base = mul_div(reserve_out, WAD, reserve_out - amount_out, true)?;
+    let exponent = weight_out as u128 * WAD / weight_in as u128;
+    let power = pow_wad(base, exponent)?;
+    let power = power + power / POW_ERROR_DIVISOR + 1;
+    mul_div(reserve_in, power - WAD, WAD, true)
+}
+
+/// Calculates the LP tokens minted for depositing a single token into a weighted pool.
+///
+/// Formula (Balancer `joinswapExternAmountIn`):
+/// `lp_out = supply * ((1 + Δb * (1 - (1 - w) * fee) / b)^w - 1)`
+///
+/// Only the share of the deposit that is implicitly swapped into the other
+/// tokens, `1 - w`, pays the swap fee.
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If the reserve or LP supply is zero
+/// * `AmmError::MaxInRatioExceeded` - If Δb exceeds `MAX_WEIGHTED_SWAP_RATIO_BPS` of b
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn get_single_asset_deposit_lp(
+    amount_in: u128,
+    reserve: u128,
+    weight: u16,
+    lp_supply: u128,
+    fee_bps: u64,
+) -> Result<u128> {
+    require!(
+        reserve > 0 && lp_supply > 0,
+        AmmError::InsufficientLiquidity
+    );
+    require!(
+        amount_in <= reserve * MAX_WEIGHTED_SWAP_RATIO_BPS as u128 / WEIGHT_DENOMINATOR as u128,
+        AmmError::MaxInRatioExceeded
+    );
+    let fee = swapped_share_fee(amount_in, weight, fee_bps);
+    let ratio = mul_div(reserve + amount_in - fee, WAD, reserve, false)?;
+    let power = pow_wad(ratio, weight_wad(weight))?;
+    let power = power.saturating_sub(power / POW_ERROR_DIVISOR + 1);
+    mul_div(lp_supply, power.saturating_sub(WAD), WAD, false)
+}
+
+/// Calculates the single token returned for burning LP tokens of a weighted pool.
+///
+/// Formula (Balancer `exitswapPoolAmountIn`):
+/// `Δb = b * (1 - (1 - lp_in / supply)^(1 / w)) * (1 - (1 - w) * fee)`
+///
+/// # Errors
+/// * `AmmError::InsufficientLiquidity` - If `lp_amount` is not below the LP supply
+/// * `AmmError::MaxOutRatioExceeded` - If Δb exceeds `MAX_WEIGHTED_SWAP_RATIO_BPS` of b
+/// * `MathError::Overflow` - If any arithmetic operation overflows
+pub fn get_single_asset_withdraw_amount(
+    lp_amount: u128,
+    reserve: u128,
+    weight: u16,
+    lp_supply: u128,
+    fee_bps: u64,
+) -> Result<u128> {
+    require_gt!(lp_supply, lp_amount, AmmError::InsufficientLiquidity);
+    let ratio = mul_div(lp_supply - lp_amount, WAD, lp_supply, true)?;
+    let exponent = WEIGHT_DENOMINATOR as u128 * WAD / weight as u128;
+    let power = pow_wad(ratio, exponent)?;
+    let power = (power + power / POW_ERROR_DIVISOR + 1).min(WAD);
+    let amount_out = mul_div(reserve, WAD - power, WAD, false)?;
+    let amount_out = amount_out - swapped_share_fee(amount_out, weight, fee_bps);
+    require!(
+        amount_out <= reserve * MAX_WEIGHTED_SWAP_RATIO_BPS as u128 / WEIGHT_DENOMINATOR as u128,
+        AmmError::MaxOutRatioExceeded
+    );
+    Ok(amount_out)
+}
+
+/// Swap fee on the `1 - w` share of `amount` that a single-token join or exit trades away.
+fn swapped_share_fee(amount: u128, weight: u16, fee_bps: u64) -> u128 {
+    let swapped = amount * (WEIGHT_DENOMINATOR - weight) as u128 / WEIGHT_DENOMINATOR as u128;
+    swapped * fee_bps as u128 / 10_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WAD_I: i128 = WAD as i128;
+
+    fn assert_close(actual: u128, expected: u128, max_error: u128) {
+        assert!(
+            actual.abs_diff(expected) <= max_error,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn ln_wad_known_values() {
+        assert_eq!(ln_wad(WAD).unwrap(), 0);
+        assert!(ln_wad(2 * WAD).unwrap().abs_diff(LN_2_WAD) <= 10);
+        // ln(e), ln(10), ln(0.5) and ln(1e-18)
+        assert!(ln_wad(2_718_281_828_459_045_235).unwrap().abs_diff(WAD_I) <= 100);
+        assert!(
+            ln_wad(10 * WAD)
+                .unwrap()
+                .abs_diff(2_302_585_092_994_045_684)
+                <= 100
+        );
+        assert!(ln_wad(WAD / 2).unwrap().abs_diff(-LN_2_WAD) <= 10);
+        assert!(ln_wad(1).unwrap().abs_diff(-41_446_531_673_892_822_313) <= 100);
+        assert!(ln_wad(0).is_err());
+    }
+
+    #[test]
+    fn exp_wad_known_values() {
+        assert_eq!(exp_wad(0).unwrap(), WAD);
+        assert_close(exp_wad(WAD_I).unwrap(), 2_718_281_828_459_045_235, 100);
+        assert_close(exp_wad(LN_2_WAD).unwrap(), 2 * WAD, 10);
+        assert_close(exp_wad(-WAD_I).unwrap(), 367_879_441_171_442_321, 10);
+        assert_close(exp_wad(-LN_2_WAD).unwrap(), WAD / 2, 10);
+        assert_eq!(exp_wad(MIN_EXP_WAD - 1).unwrap(), 0);
+        assert!(exp_wad(MAX_EXP_WAD).is_ok());
+        assert!(exp_wad(MAX_EXP_WAD + 1).is_err());
+    }
+
+    #[test]
+    fn pow_wad_known_values() {
+        assert_close(pow_wad(4 * WAD, WAD / 2).unwrap(), 2 * WAD, 100);
+        assert_close(pow_wad(2 * WAD, 3 * WAD).unwrap(), 8 * WAD, 1_000);
+        assert_close(pow_wad(WAD / 4, WAD / 2).unwrap(), WAD / 2, 100);
+        assert_close(pow_wad(123 * WAD, 0).unwrap(), WAD, 0);
+    }
+
+    #[test]
+    fn invariant_of_even_weights_is_geometric_mean() {
+        let invariant = compute_weighted_invariant(1_000_000, 4_000_000, 5_000, 5_000).unwrap();
+        assert_close(invariant, 2_000_000, 1);
+        let invariant =
+            compute_multi_weighted_invariant(&[8_000, 8_000, 8_000], &[3_000, 3_000, 4_000])
+                .unwrap();
+        assert_close(invariant, 8_000, 1);
+    }
+
+    #[test]
+    fn exact_in_rounds_for_the_pool() {
+        let (reserve_in, reserve_out) = (1_000_000_000_000, 3_000_000_000);
+        for (weight_in, weight_out) in [(5_000, 5_000), (8_000, 2_000), (2_000, 8_000)] {
+            for amount_in in [1, 1_000, 1_000_000_007, 50_000_000_000] {
+                let amount_out = get_weighted_amount_out(
+                    amount_in,
+                    reserve_in,
+                    reserve_out,
+                    weight_in,
+                    weight_out,
+                )
+                .unwrap();
+                assert!(
+                    compute_weighted_invariant(
+                        reserve_in + amount_in,
+                        reserve_out - amount_out,
+                        weight_in,
+                        weight_out
+                    )
+                    .unwrap()
+                        >= compute_weighted_invariant(
+                            reserve_in,
+                            reserve_out,
+                            weight_in,
+                            weight_out
+                        )
+                        .unwrap()
+                );
+                // Paying for that output exactly can't cost less than was paid
+                if amount_out > 0 {
+                    let amount_in_needed = get_weighted_amount_in(
+                        amount_out,
+                        reserve_in,
+                        reserve_out,
+                        weight_in,
+                        weight_out,
+                    )
+                    .unwrap();
+                    assert!(amount_in_needed <= amount_in);
+                }
+            }
+        }
+        assert!(
+            get_weighted_amount_out(300_000_000_001, reserve_in, reserve_out, 5_000, 5_000)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn exact_out_rounds_for_the_pool() {
+        let (reserve_in, reserve_out) = (1_000_000_000_000, 3_000_000_000);
+        for (weight_in, weight_out) in [(5_000, 5_000), (8_000, 2_000), (2_000, 8_000)] {
+            for amount_out in [1, 1_000, 1_000_007, 150_000_000] {
+                let amount_in = get_weighted_amount_in(
+                    amount_out,
+                    reserve_in,
+                    reserve_out,
+                    weight_in,
+                    weight_out,
+                )
+                .unwrap();
+                assert!(amount_in > 0);
+                // That input, swapped exactly, must pay out at least as much
+                let amount_out_received = get_weighted_amount_out(
+                    amount_in,
+                    reserve_in,
+                    reserve_out,
+                    weight_in,
+                    weight_out,
+                )
+                .unwrap();
+                assert!(amount_out_received <= amount_out);
+            }
+        }
+    }
+
+    #[test]
+    fn single_asset_join_and_exit_round_for_the_pool() {
+        let (reserve, lp_supply) = (5_000_000_000_000, 1_000_000_000);
+        for weight in [2_000, 3_334, 5_000] {
+            for fee_bps in [0, 30] {
+                for amount_in in [1_000_000, 1_000_000_007, 1_500_000_000_000] {
+                    let lp_out =
+                        get_single_asset_deposit_lp(amount_in, reserve, weight, lp_supply, fee_bps)
+                            .unwrap();
+                    // Burning the LP tokens right away can't return more than was deposited
+                    let amount_out = get_single_asset_withdraw_amount(
+                        lp_out,
+                        reserve + amount_in,
+                        weight,
+                        lp_supply + lp_out,
+                        fee_bps,
+                    )
+                    .unwrap();
+                    assert!(amount_out <= amount_in, "{weight} {fee_bps} {amount_in}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_asset_join_matches_pro_rata_share_without_fees() {
+        // 21% more of a 50% weight token is worth sqrt(1.21) - 1 = 10% of the supply
+        let lp_out =
+            get_single_asset_deposit_lp(2_100_000, 10_000_000, 5_000, 1_000_000, 0).unwrap();
+        assert!((99_999..=100_000).contains(&lp_out));
+        assert!(get_single_asset_withdraw_amount(1_000_000, 10, 5_000, 1_000_000, 0).is_err());
+    }
+}
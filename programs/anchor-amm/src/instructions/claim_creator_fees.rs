@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+};
+
+use crate::{error::AmmError, CreatorFeesClaimed, CurveType, LiquidityPool, LIQUIDITY_POOL_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        has_one = creator @ AmmError::Unauthorized,
+        has_one = fee_lp_token_account,
+        constraint = liquidity_pool.load()?.curve_type() != CurveType::OraclePmm
+            @ AmmError::UnsupportedCurveOperation,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+    #[account(address = liquidity_pool.load()?.lp_mint)]
+    pub lp_token_mint: Account<'info, Mint>,
+    /// Protocol fee LP token account owned by the pool PDA
+    #[account(mut)]
+    pub fee_lp_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program
+    )]
+    pub lp_token_creator_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sends the creator fee LP tokens accrued in the fee account to the creator.
+///
+/// Fees accrue when `ProtocolFeeMinter` runs, i.e. on deposits and withdrawals,
+/// so swap fees earned since the last liquidity event are not yet claimable.
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let pool = *ctx.accounts.liquidity_pool.load()?;
+    let lp_tokens = pool.creator_fee_lp_tokens;
+    require_gt!(lp_tokens, 0, AmmError::NoCreatorFees);
+    ctx.accounts.liquidity_pool.load_mut()?.locked = 1;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LIQUIDITY_POOL_SEED.as_bytes(),
+        pool.token_a_mint.as_ref(),
+        pool.token_b_mint.as_ref(),
+        &[pool.bump],
+    ]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_lp_token_account.to_account_info(),
+                to: ctx
+                    .accounts
+                    .lp_token_creator_token_account
+                    .to_account_info(),
+                mint: ctx.accounts.lp_token_mint.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        lp_tokens,
+        ctx.accounts.lp_token_mint.decimals,
+    )?;
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    pool.creator_fee_lp_tokens = 0;
+    pool.locked = 0;
+    drop(pool);
+
+    emit_cpi!(CreatorFeesClaimed {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        creator: ctx.accounts.creator.key(),
+        lp_tokens,
+    });
+    Ok(())
+}
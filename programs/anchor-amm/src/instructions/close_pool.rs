@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, close_account, Burn, CloseAccount, Mint, Token, TokenAccount};
+
+use crate::{
+    error::AmmError, LiquidityPool, PoolClosed, PoolStats, LIQUIDITY_POOL_SEED,
+    MAX_CLOSE_POOL_DUST, POOL_STATS_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ AmmError::Unauthorized,
+        seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump = liquidity_pool.load()?.bump,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        has_one = fee_lp_token_account,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump = pool_stats.bump
+    )]
+    pub pool_stats: Box<Account<'info, PoolStats>>,
+    #[account(mut)]
+    pub token_a_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub token_b_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    #[account(address = liquidity_pool.load()?.lp_mint)]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+    /// Protocol fee LP token account owned by the pool PDA
+    #[account(mut)]
+    pub fee_lp_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Closes a pool nobody holds liquidity in and refunds its rent to the payer recorded at init.
+///
+/// The LP supply may only be the minimum locked by the first deposit, so whatever
+/// is left in the vaults backs nothing but that locked liquidity and is burned, up
+/// to `MAX_CLOSE_POOL_DUST` of each token. The LP mint can't be closed; a new pool
+/// for the pair picks it up, locked minimum included.
+pub fn handler(ctx: Context<ClosePool>) -> Result<()> {
+    // The first deposit locks 1000 LP tokens in an account nobody can spend from
+    require_gte!(
+        1000,
+        ctx.accounts.lp_token_mint.supply,
+        AmmError::PoolNotEmpty
+    );
+    require!(
+        ctx.accounts.token_a_vault.amount <= MAX_CLOSE_POOL_DUST
+            && ctx.accounts.token_b_vault.amount <= MAX_CLOSE_POOL_DUST,
+        AmmError::PoolDustExceeded
+    );
+
+    // Never released: the pool is closed once the instruction exits
+    let bump = {
+        let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+        pool.locked = 1;
+        pool.bump
+    };
+    let token_a_key = ctx.accounts.token_a_mint.key();
+    let token_b_key = ctx.accounts.token_b_mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LIQUIDITY_POOL_SEED.as_bytes(),
+        token_a_key.as_ref(),
+        token_b_key.as_ref(),
+        &[bump],
+    ]];
+    let token_a_dust = ctx.accounts.token_a_vault.amount;
+    let token_b_dust = ctx.accounts.token_b_vault.amount;
+    for (mint, vault, dust) in [
+        (
+            &ctx.accounts.token_a_mint,
+            &ctx.accounts.token_a_vault,
+            token_a_dust,
+        ),
+        (
+            &ctx.accounts.token_b_mint,
+            &ctx.accounts.token_b_vault,
+            token_b_dust,
+        ),
+    ] {
+        if dust > 0 {
+            burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: mint.to_account_info(),
+                        from: vault.to_account_info(),
+                        authority: ctx.accounts.liquidity_pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                dust,
+            )?;
+        }
+    }
+    for account in [
+        &ctx.accounts.token_a_vault,
+        &ctx.accounts.token_b_vault,
+        &ctx.accounts.fee_lp_token_account,
+    ] {
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: account.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    emit_cpi!(PoolClosed {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        payer: ctx.accounts.payer.key(),
+        token_a_dust,
+        token_b_dust,
+    });
+    Ok(())
+}
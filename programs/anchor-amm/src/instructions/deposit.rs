@@ -7,16 +7,16 @@ use anchor_spl::{
 use crate::{
     error::{AmmError, MathError},
     helpers::{
-        calculate_constant_product, quote, LPMinter, ProtocolFeeMinter, ReserveSyncer,
-        VaultDepositor,
+        quote, FeeMint, LPMinter, ProtocolFeeMinter, ReserveSyncer, TokenDepositor, VaultDepositor,
     },
-    LiquidityPool, LIQUIDITY_POOL_SEED,
+    GlobalState, LiquidityAdded, LiquidityPool, PoolStats, ProtocolFeeMinted, GLOBAL_STATE_SEED,
+    LIQUIDITY_POOL_SEED, PAUSE_DEPOSIT, POOL_STATS_SEED,
 };
 
-// TODO (Pen): Should there be deposit fees? Not gonna bother with fees for now.
 // TODO (Pen): Make the precision have a bigger upper limit (19).
 // TODO (Pen): Think about wrapped SOL
 // TODO (Pen): Price oracle
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Deposit<'info> {
     #[account(mut)]
@@ -43,39 +43,34 @@ pub struct Deposit<'info> {
         associated_token::token_program = token_program
     )]
     pub token_b_signer_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(
-        mut,
-        associated_token::mint = token_a_mint,
-        associated_token::authority = liquidity_pool
-    )]
+    #[account(mut)]
     pub token_a_vault: Box<Account<'info, TokenAccount>>,
-    #[account(
-        mut,
-        associated_token::mint = token_b_mint,
-        associated_token::authority = liquidity_pool
-    )]
+    #[account(mut)]
     pub token_b_vault: Box<Account<'info, TokenAccount>>,
     pub token_a_mint: Box<Account<'info, Mint>>,
     pub token_b_mint: Box<Account<'info, Mint>>,
-    #[account(
-        mut,
-        seeds = [b"lp_token_mint", token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
-        bump
-    )]
+    #[account(mut, address = liquidity_pool.load()?.lp_mint)]
     pub lp_token_mint: Box<Account<'info, Mint>>,
     #[account(
         mut,
         seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
-        bump
+        bump = liquidity_pool.load()?.bump,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        has_one = fee_lp_token_account,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
     )]
-    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
-    /// Protocol fee LP token account owned by the pool PDA
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
     #[account(
         mut,
-        associated_token::mint = lp_token_mint,
-        associated_token::authority = liquidity_pool,
-        associated_token::token_program = token_program
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump = pool_stats.bump
     )]
+    pub pool_stats: Box<Account<'info, PoolStats>>,
+    #[account(seeds = [GLOBAL_STATE_SEED.as_bytes()], bump = global_state.bump)]
+    pub global_state: Box<Account<'info, GlobalState>>,
+    /// Protocol fee LP token account owned by the pool PDA
+    #[account(mut)]
     pub fee_lp_token_account: Box<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -91,6 +86,26 @@ pub struct Deposit<'info> {
     pub lp_token_system_program_token_account: Box<Account<'info, TokenAccount>>,
 }
 
+/// Deposit outcome, written as return data so CPI callers don't have to diff balances.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositResult {
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    /// LP tokens minted to the depositor (excludes the locked minimum on the first deposit)
+    pub lp_tokens_minted: u64,
+    /// LP tokens withheld from the depositor, see `LiquidityPool::deposit_fee_bps`
+    pub deposit_fee: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+/// Adds liquidity at the pool's current ratio and mints LP tokens for it.
+///
+/// Later deposits are charged `deposit_fee_bps` of their LP tokens. The deposit
+/// into an empty pool is not: there are no LPs yet for an unminted fee to go to,
+/// so the creator's seed deposit is always fee-free. The fee is set once when
+/// the pool is initialized and is capped by the compile-time `MAX_DEPOSIT_FEE_BPS`,
+/// which no admin can change.
 pub fn handler(
     ctx: Context<Deposit>,
     token_a_amount_desired: u64,
@@ -98,50 +113,80 @@ pub fn handler(
     token_a_amount_min: u64,
     token_b_amount_min: u64,
     expiration: i64,
-) -> Result<()> {
+) -> Result<DepositResult> {
+    let now = Clock::get()?.unix_timestamp;
+    let (lp_mint_bump, lp_supply) = {
+        let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+        pool.check_not_paused(&ctx.accounts.global_state, PAUSE_DEPOSIT)?;
+        pool.check_lbp_liquidity_change(&ctx.accounts.signer.key(), false, now)?;
+        // Held until the last token CPI below is done
+        pool.locked = 1;
+        (
+            pool.lp_mint_bump,
+            pool.lp_supply(ctx.accounts.lp_token_mint.supply),
+        )
+    };
     require!(
         token_a_amount_desired > 0 && token_b_amount_desired > 0,
         AmmError::ZeroAmount
     );
-    require_gt!(
-        expiration,
-        Clock::get()?.unix_timestamp,
-        AmmError::DeadlineExceeded,
-    );
-    if ctx.accounts.lp_token_mint.supply == 0 {
+    require_gt!(expiration, now, AmmError::DeadlineExceeded);
+    if lp_supply == 0 {
+        let lp_tokens_to_mint = ctx
+            .accounts
+            .get_lp_tokens_to_mint(token_a_amount_desired, token_b_amount_desired)?;
         ctx.accounts
             .deposit(token_a_amount_desired, token_b_amount_desired)?;
 
         // Reload vaults and sync reserves
         ctx.accounts.token_a_vault.reload()?;
         ctx.accounts.token_b_vault.reload()?;
-        ctx.accounts.sync_reserves();
-        ctx.accounts.liquidity_pool.k_last = (ctx.accounts.liquidity_pool.token_a_reserves as u128)
-            .checked_mul(ctx.accounts.liquidity_pool.token_b_reserves as u128)
-            .ok_or(MathError::Overflow)?;
-        let lp_tokens_to_mint: u64 = calculate_constant_product(
-            token_a_amount_desired as u128,
-            token_b_amount_desired as u128,
-        )?
-        .isqrt()
-        .try_into()
-        .map_err(|_| MathError::Overflow)?;
+        ctx.accounts.sync_reserves()?;
+        let pool = {
+            let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+            pool.scale_pmm_targets(0, 0)?;
+            pool.k_last = pool.invariant(now)?;
+            *pool
+        };
         require_gt!(
             lp_tokens_to_mint,
             1000,
             AmmError::InsufficientInitialLiquidity
         );
-        ctx.accounts.mint_lp_tokens(
-            &ctx.accounts.lp_token_system_program_token_account,
-            1000,
-            ctx.bumps.lp_token_mint,
-        )?;
+        // A reopened pool's LP mint still holds the minimum its first deposit locked
+        let locked_lp_tokens = 1000u64.saturating_sub(ctx.accounts.lp_token_mint.supply);
+        if locked_lp_tokens > 0 {
+            ctx.accounts.mint_lp_tokens(
+                &ctx.accounts.lp_token_system_program_token_account,
+                locked_lp_tokens,
+                lp_mint_bump,
+            )?;
+        }
         ctx.accounts.mint_lp_tokens(
             &ctx.accounts.lp_token_signer_token_account,
             lp_tokens_to_mint - 1000,
-            ctx.bumps.lp_token_mint,
+            lp_mint_bump,
         )?;
-        return Ok(());
+        ctx.accounts.liquidity_pool.load_mut()?.locked = 0;
+        ctx.accounts.pool_stats.record_deposit()?;
+        emit_cpi!(LiquidityAdded {
+            liquidity_pool: ctx.accounts.liquidity_pool.key(),
+            user: ctx.accounts.signer.key(),
+            token_a_amount: token_a_amount_desired,
+            token_b_amount: token_b_amount_desired,
+            lp_tokens_minted: lp_tokens_to_mint - 1000,
+            deposit_fee: 0,
+            token_a_reserves: pool.token_a_reserves,
+            token_b_reserves: pool.token_b_reserves,
+        });
+        return Ok(DepositResult {
+            token_a_amount: token_a_amount_desired,
+            token_b_amount: token_b_amount_desired,
+            lp_tokens_minted: lp_tokens_to_mint - 1000,
+            deposit_fee: 0,
+            token_a_reserves: pool.token_a_reserves,
+            token_b_reserves: pool.token_b_reserves,
+        });
     }
     let token_a_amount_desired = token_a_amount_desired as u128;
     let token_b_amount_desired = token_b_amount_desired as u128;
@@ -149,7 +194,27 @@ pub fn handler(
     let token_b_amount_min = token_b_amount_min as u128;
 
     // Mint protocol fees before adding liquidity
-    ctx.accounts.mint_protocol_fee(ctx.bumps.lp_token_mint)?;
+    let fee_mint = ctx.accounts.mint_protocol_fee(lp_mint_bump)?;
+    if fee_mint != FeeMint::default() {
+        ctx.accounts
+            .pool_stats
+            .record_protocol_fee(fee_mint.protocol_lp_tokens)?;
+        let pool = {
+            let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+            pool.creator_fee_lp_tokens = pool
+                .creator_fee_lp_tokens
+                .checked_add(fee_mint.creator_lp_tokens)
+                .ok_or(MathError::Overflow)?;
+            *pool
+        };
+        emit_cpi!(ProtocolFeeMinted {
+            liquidity_pool: ctx.accounts.liquidity_pool.key(),
+            lp_tokens_minted: fee_mint.protocol_lp_tokens,
+            creator_lp_tokens_minted: fee_mint.creator_lp_tokens,
+            token_a_reserves: pool.token_a_reserves,
+            token_b_reserves: pool.token_b_reserves,
+        });
+    }
     ctx.accounts.lp_token_mint.reload()?;
     let (token_a_deposit_amount, token_b_deposit_amount) = ctx.accounts.optimize_deposit_amounts(
         token_a_amount_desired,
@@ -157,39 +222,68 @@ pub fn handler(
         token_a_amount_min,
         token_b_amount_min,
     )?;
-    ctx.accounts.deposit(
-        token_a_deposit_amount
-            .try_into()
-            .map_err(|_| MathError::Overflow)?,
-        token_b_deposit_amount
-            .try_into()
-            .map_err(|_| MathError::Overflow)?,
-    )?;
-    //NOTE in Uniswap, they decide how many tokens to mint based on the minimum ratio, but since we have already optimized the deposits,
-    //NOTE it's safe to just go with token a, since it should be equal to token_b.
-    let lp_tokens_to_mint: u64 = token_a_deposit_amount
-        .checked_mul(ctx.accounts.lp_token_mint.supply as u128)
-        .ok_or(MathError::Overflow)?
-        .checked_div(ctx.accounts.liquidity_pool.token_a_reserves as u128)
-        .ok_or(MathError::DivisionByZero)?
+    let token_a_deposit_amount: u64 = token_a_deposit_amount
         .try_into()
         .map_err(|_| MathError::Overflow)?;
+    let token_b_deposit_amount: u64 = token_b_deposit_amount
+        .try_into()
+        .map_err(|_| MathError::Overflow)?;
+    let pool_before = *ctx.accounts.liquidity_pool.load()?;
+    ctx.accounts
+        .deposit(token_a_deposit_amount, token_b_deposit_amount)?;
+    let lp_tokens = ctx
+        .accounts
+        .get_lp_tokens_to_mint(token_a_deposit_amount, token_b_deposit_amount)?;
+    let deposit_fee = pool_before.deposit_fee(lp_tokens);
+    let lp_tokens_to_mint = lp_tokens - deposit_fee;
     ctx.accounts.mint_lp_tokens(
         &ctx.accounts.lp_token_signer_token_account,
         lp_tokens_to_mint,
-        ctx.bumps.lp_token_mint,
+        lp_mint_bump,
     )?;
+    // Unminted deposit fees raise the value of every existing LP token instead
+    if deposit_fee > 0 && pool_before.deposit_fee_to_protocol != 0 {
+        ctx.accounts.mint_lp_tokens(
+            &ctx.accounts.fee_lp_token_account,
+            deposit_fee,
+            lp_mint_bump,
+        )?;
+        ctx.accounts.pool_stats.record_protocol_fee(deposit_fee)?;
+    }
 
     // Reload vaults and sync reserves
     ctx.accounts.token_a_vault.reload()?;
     ctx.accounts.token_b_vault.reload()?;
-    ctx.accounts.sync_reserves();
-    // Update k_last for protocol fee tracking
-    ctx.accounts.liquidity_pool.k_last = (ctx.accounts.liquidity_pool.token_a_reserves as u128)
-        .checked_mul(ctx.accounts.liquidity_pool.token_b_reserves as u128)
-        .ok_or(MathError::Overflow)?;
+    ctx.accounts.sync_reserves()?;
+    let pool = {
+        let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+        pool.scale_pmm_targets(pool_before.token_a_reserves, pool_before.token_b_reserves)?;
+        // Update k_last for protocol fee tracking
+        pool.k_last = pool.invariant(now)?;
+        pool.locked = 0;
+        *pool
+    };
+    ctx.accounts.pool_stats.record_deposit()?;
 
-    Ok(())
+    emit_cpi!(LiquidityAdded {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        user: ctx.accounts.signer.key(),
+        token_a_amount: token_a_deposit_amount,
+        token_b_amount: token_b_deposit_amount,
+        lp_tokens_minted: lp_tokens_to_mint,
+        deposit_fee,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    });
+
+    Ok(DepositResult {
+        token_a_amount: token_a_deposit_amount,
+        token_b_amount: token_b_deposit_amount,
+        lp_tokens_minted: lp_tokens_to_mint,
+        deposit_fee,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    })
 }
 
 impl<'info> Deposit<'info> {
@@ -224,11 +318,12 @@ impl<'info> Deposit<'info> {
         token_a_amount_min: u128,
         token_b_amount_min: u128,
     ) -> Result<(u128, u128)> {
+        let pool = self.liquidity_pool.load()?;
         // let's say we want to use all of our token_amount_a_desired, so we have to see what the optimal is for token b
         let token_b_optimal_amount = quote(
             token_a_amount_desired,
-            self.liquidity_pool.token_a_reserves as u128,
-            self.liquidity_pool.token_b_reserves as u128,
+            pool.token_a_reserves as u128,
+            pool.token_b_reserves as u128,
         )?;
         // if the optimal amount is the same as we desired or more favorable
         if token_b_optimal_amount <= token_b_amount_desired {
@@ -236,20 +331,41 @@ impl<'info> Deposit<'info> {
                 token_b_optimal_amount >= token_b_amount_min,
                 AmmError::SlippageExceeded
             );
-            return Ok((token_a_amount_desired, token_b_optimal_amount));
+            Ok((token_a_amount_desired, token_b_optimal_amount))
         } else {
             let token_a_optimal_amount = quote(
                 token_b_amount_desired,
-                self.liquidity_pool.token_b_reserves as u128,
-                self.liquidity_pool.token_a_reserves as u128,
+                pool.token_b_reserves as u128,
+                pool.token_a_reserves as u128,
             )?;
             require!(
                 token_a_optimal_amount >= token_a_amount_min,
                 AmmError::SlippageExceeded
             );
-            return Ok((token_a_optimal_amount, token_b_amount_desired));
+            Ok((token_a_optimal_amount, token_b_amount_desired))
         }
     }
+
+    /// Calculates the LP tokens owed for a deposit that hasn't been synced into the reserves yet.
+    ///
+    /// Delegates to the pool's `SwapCurve`, so the first deposit mints the curve's root
+    /// invariant (`sqrt(a * b)` for constant product) and later deposits mint pro rata.
+    ///
+    /// # Errors
+    /// * `MathError::Overflow` - If the LP amount doesn't fit in a u64
+    pub fn get_lp_tokens_to_mint(&self, token_a_amount: u64, token_b_amount: u64) -> Result<u64> {
+        let pool = self.liquidity_pool.load()?;
+        pool.swap_curve(Clock::get()?.unix_timestamp)
+            .deposit_lp_tokens(
+                token_a_amount as u128,
+                token_b_amount as u128,
+                pool.token_a_reserves as u128,
+                pool.token_b_reserves as u128,
+                pool.lp_supply(self.lp_token_mint.supply) as u128,
+            )?
+            .try_into()
+            .map_err(|_| MathError::Overflow.into())
+    }
 }
 impl<'info> LPMinter<'info> for Deposit<'info> {
     fn token_program(&self) -> &Program<'info, Token> {
@@ -268,11 +384,17 @@ impl<'info> LPMinter<'info> for Deposit<'info> {
         &self.lp_token_mint
     }
 }
-impl<'info> VaultDepositor<'info> for Deposit<'info> {
+impl<'info> TokenDepositor<'info> for Deposit<'info> {
     fn token_program(&self) -> &Program<'info, Token> {
         &self.token_program
     }
 
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> VaultDepositor<'info> for Deposit<'info> {
     fn token_a_signer_token_account(&self) -> &Account<'info, TokenAccount> {
         &self.token_a_signer_token_account
     }
@@ -296,14 +418,10 @@ impl<'info> VaultDepositor<'info> for Deposit<'info> {
     fn token_b_vault(&self) -> &Account<'info, TokenAccount> {
         &self.token_b_vault
     }
-
-    fn signer(&self) -> &Signer<'info> {
-        &self.signer
-    }
 }
 impl<'info> ReserveSyncer<'info> for Deposit<'info> {
-    fn liquidity_pool(&mut self) -> &mut Account<'info, LiquidityPool> {
-        &mut self.liquidity_pool
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
+        &self.liquidity_pool
     }
 
     fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
@@ -320,7 +438,7 @@ impl<'info> ProtocolFeeMinter<'info> for Deposit<'info> {
         &self.fee_lp_token_account
     }
 
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool> {
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
         &self.liquidity_pool
     }
 }
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{compute_multi_weighted_invariant, mul_div, MultiAssetLPMinter, TokenDepositor},
+    MultiAssetLiquidityAdded, MultiAssetPool, MULTI_ASSET_POOL_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositMultiAsset<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub lp_token_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = multi_asset_pool.lp_token_mint)]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [MULTI_ASSET_POOL_SEED.as_bytes(), multi_asset_pool.lp_token_mint.as_ref()],
+        bump = multi_asset_pool.bump
+    )]
+    pub multi_asset_pool: Box<Account<'info, MultiAssetPool>>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = system_program,
+        associated_token::token_program = token_program
+    )]
+    // this is a dead address, sending here won't reduce the supply, but still effectively burn tokens
+    pub lp_token_system_program_token_account: Box<Account<'info, TokenAccount>>,
+}
+
+/// Deposits every pool token in proportion to the reserves.
+///
+/// `remaining_accounts` holds `[mint, signer token account, vault]` for each token
+/// in pool order. Mints exactly `lp_amount` LP tokens, pulling at most `max_amounts`.
+/// The first deposit instead takes all of `max_amounts`, sets the prices, and mints
+/// the weighted invariant minus the locked minimum, which must be at least `lp_amount`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DepositMultiAsset<'info>>,
+    lp_amount: u64,
+    max_amounts: Vec<u64>,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let pool = &ctx.accounts.multi_asset_pool;
+    require_eq!(
+        max_amounts.len(),
+        pool.token_count as usize,
+        AmmError::InvalidTokenCount
+    );
+    let token_accounts = pool.load_token_accounts(ctx.remaining_accounts)?;
+    let lp_supply = ctx.accounts.lp_token_mint.supply as u128;
+
+    let is_first_deposit = lp_supply == 0;
+    let (amounts, lp_tokens_to_mint) = if is_first_deposit {
+        require!(
+            max_amounts.iter().all(|amount| *amount > 0),
+            AmmError::ZeroAmount
+        );
+        let reserves: Vec<u128> = max_amounts.iter().map(|amount| *amount as u128).collect();
+        let lp_tokens: u64 = compute_multi_weighted_invariant(&reserves, pool.weights())?
+            .try_into()
+            .map_err(|_| MathError::Overflow)?;
+        require_gt!(lp_tokens, 1000, AmmError::InsufficientInitialLiquidity);
+        require_gte!(lp_tokens - 1000, lp_amount, AmmError::SlippageExceeded);
+        (max_amounts, lp_tokens - 1000)
+    } else {
+        require_gt!(lp_amount, 0, AmmError::ZeroAmount);
+        let amounts = pool
+            .reserves()
+            .iter()
+            .zip(&max_amounts)
+            .map(|(reserve, max_amount)| {
+                let amount: u64 = mul_div(*reserve as u128, lp_amount as u128, lp_supply, true)?
+                    .try_into()
+                    .map_err(|_| MathError::Overflow)?;
+                require_gte!(*max_amount, amount, AmmError::SlippageExceeded);
+                Ok(amount)
+            })
+            .collect::<Result<Vec<u64>>>()?;
+        (amounts, lp_amount)
+    };
+
+    for (accounts, amount) in token_accounts.iter().zip(&amounts) {
+        ctx.accounts.deposit_token(
+            &accounts.mint,
+            &accounts.signer_token_account,
+            &accounts.vault,
+            *amount,
+        )?;
+    }
+    let pool = &mut ctx.accounts.multi_asset_pool;
+    for (reserve, amount) in pool.reserves.iter_mut().zip(&amounts) {
+        *reserve = reserve.checked_add(*amount).ok_or(MathError::Overflow)?;
+    }
+
+    if is_first_deposit {
+        ctx.accounts
+            .mint_lp_tokens(&ctx.accounts.lp_token_system_program_token_account, 1000)?;
+    }
+    ctx.accounts.mint_lp_tokens(
+        &ctx.accounts.lp_token_signer_token_account,
+        lp_tokens_to_mint,
+    )?;
+
+    emit_cpi!(MultiAssetLiquidityAdded {
+        multi_asset_pool: ctx.accounts.multi_asset_pool.key(),
+        user: ctx.accounts.signer.key(),
+        amounts,
+        lp_tokens_minted: lp_tokens_to_mint,
+    });
+    Ok(())
+}
+
+impl<'info> TokenDepositor<'info> for DepositMultiAsset<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> MultiAssetLPMinter<'info> for DepositMultiAsset<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool> {
+        &self.multi_asset_pool
+    }
+
+    fn lp_token_mint(&self) -> &Account<'info, Mint> {
+        &self.lp_token_mint
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{get_single_asset_deposit_lp, MultiAssetLPMinter, TokenDepositor},
+    MultiAssetLiquidityAdded, MultiAssetPool, MULTI_ASSET_POOL_SEED, MULTI_ASSET_SWAP_FEE_BPS,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositMultiAssetSingle<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = multi_asset_pool
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    pub token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub lp_token_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = multi_asset_pool.lp_token_mint)]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [MULTI_ASSET_POOL_SEED.as_bytes(), multi_asset_pool.lp_token_mint.as_ref()],
+        bump = multi_asset_pool.bump
+    )]
+    pub multi_asset_pool: Box<Account<'info, MultiAssetPool>>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits a single pool token and mints LP tokens against the weighted invariant.
+///
+/// Only available once the pool has been seeded by a proportional deposit.
+pub fn handler(
+    ctx: Context<DepositMultiAssetSingle>,
+    amount_in: u64,
+    min_lp_amount: u64,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(amount_in, 0, AmmError::ZeroAmount);
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let pool = &ctx.accounts.multi_asset_pool;
+    let index = pool.token_index(&ctx.accounts.token_mint.key())?;
+    let lp_tokens_to_mint: u64 = get_single_asset_deposit_lp(
+        amount_in as u128,
+        pool.reserves[index] as u128,
+        pool.weights[index],
+        ctx.accounts.lp_token_mint.supply as u128,
+        MULTI_ASSET_SWAP_FEE_BPS,
+    )?
+    .try_into()
+    .map_err(|_| MathError::Overflow)?;
+    require_gte!(lp_tokens_to_mint, min_lp_amount, AmmError::SlippageExceeded);
+    require_gt!(lp_tokens_to_mint, 0, AmmError::ZeroAmount);
+
+    ctx.accounts.deposit_token(
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_signer_token_account,
+        &ctx.accounts.token_vault,
+        amount_in,
+    )?;
+    let pool = &mut ctx.accounts.multi_asset_pool;
+    pool.reserves[index] = pool.reserves[index]
+        .checked_add(amount_in)
+        .ok_or(MathError::Overflow)?;
+    ctx.accounts.mint_lp_tokens(
+        &ctx.accounts.lp_token_signer_token_account,
+        lp_tokens_to_mint,
+    )?;
+
+    let mut amounts = vec![0; ctx.accounts.multi_asset_pool.token_count as usize];
+    amounts[index] = amount_in;
+    emit_cpi!(MultiAssetLiquidityAdded {
+        multi_asset_pool: ctx.accounts.multi_asset_pool.key(),
+        user: ctx.accounts.signer.key(),
+        amounts,
+        lp_tokens_minted: lp_tokens_to_mint,
+    });
+    Ok(())
+}
+
+impl<'info> TokenDepositor<'info> for DepositMultiAssetSingle<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> MultiAssetLPMinter<'info> for DepositMultiAssetSingle<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool> {
+        &self.multi_asset_pool
+    }
+
+    fn lp_token_mint(&self) -> &Account<'info, Mint> {
+        &self.lp_token_mint
+    }
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{LiquidityModifier, TokenDepositor, VaultDepositor},
+    ConcentratedPool, Position, PositionLiquidityChanged, TickArray, CONCENTRATED_POOL_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct IncreaseLiquidity<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            CONCENTRATED_POOL_SEED.as_bytes(),
+            concentrated_pool.token_a_mint.as_ref(),
+            concentrated_pool.token_b_mint.as_ref(),
+            concentrated_pool.tick_spacing.to_le_bytes().as_ref()
+        ],
+        bump = concentrated_pool.bump
+    )]
+    pub concentrated_pool: Box<Account<'info, ConcentratedPool>>,
+    #[account(
+        mut,
+        has_one = concentrated_pool,
+        constraint = position.owner == signer.key()
+    )]
+    pub position: Box<Account<'info, Position>>,
+    #[account(mut)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+    #[account(
+        mut,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_a_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_b_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = concentrated_pool
+    )]
+    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = concentrated_pool
+    )]
+    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    #[account(address = concentrated_pool.token_a_mint @ AmmError::MintMismatch)]
+    pub token_a_mint: Box<Account<'info, Mint>>,
+    #[account(address = concentrated_pool.token_b_mint @ AmmError::MintMismatch)]
+    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<IncreaseLiquidity>,
+    liquidity_amount: u128,
+    token_a_amount_max: u64,
+    token_b_amount_max: u64,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(liquidity_amount, 0, AmmError::ZeroAmount);
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let liquidity_delta: i128 = liquidity_amount
+        .try_into()
+        .map_err(|_| MathError::Overflow)?;
+
+    let (token_a_amount, token_b_amount) = ctx.accounts.modify_liquidity(liquidity_delta)?;
+    let token_a_amount: u64 = token_a_amount.try_into().map_err(|_| MathError::Overflow)?;
+    let token_b_amount: u64 = token_b_amount.try_into().map_err(|_| MathError::Overflow)?;
+    require!(
+        token_a_amount <= token_a_amount_max && token_b_amount <= token_b_amount_max,
+        AmmError::SlippageExceeded
+    );
+
+    ctx.accounts.deposit(token_a_amount, token_b_amount)?;
+
+    emit_cpi!(PositionLiquidityChanged {
+        concentrated_pool: ctx.accounts.concentrated_pool.key(),
+        position: ctx.accounts.position.key(),
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        position_liquidity: ctx.accounts.position.liquidity,
+    });
+    Ok(())
+}
+
+impl<'info> LiquidityModifier<'info> for IncreaseLiquidity<'info> {
+    fn concentrated_pool(&mut self) -> &mut Account<'info, ConcentratedPool> {
+        &mut self.concentrated_pool
+    }
+
+    fn position(&mut self) -> &mut Account<'info, Position> {
+        &mut self.position
+    }
+
+    fn tick_array_lower(&self) -> &AccountLoader<'info, TickArray> {
+        &self.tick_array_lower
+    }
+
+    fn tick_array_upper(&self) -> &AccountLoader<'info, TickArray> {
+        &self.tick_array_upper
+    }
+}
+
+impl<'info> TokenDepositor<'info> for IncreaseLiquidity<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> VaultDepositor<'info> for IncreaseLiquidity<'info> {
+    fn token_a_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.token_a_signer_token_account
+    }
+
+    fn token_b_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.token_b_signer_token_account
+    }
+
+    fn token_a_mint(&self) -> &Account<'info, Mint> {
+        &self.token_a_mint
+    }
+
+    fn token_b_mint(&self) -> &Account<'info, Mint> {
+        &self.token_b_mint
+    }
+
+    fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
+        &self.token_a_vault
+    }
+
+    fn token_b_vault(&self) -> &Account<'info, TokenAccount> {
+        &self.token_b_vault
+    }
+}
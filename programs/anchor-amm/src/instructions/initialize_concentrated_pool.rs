@@ -0,0 +1,89 @@
+use crate::error::AmmError;
+use crate::helpers::tick_at_sqrt_price;
+use crate::{ConcentratedPool, ConcentratedPoolInitialized, CONCENTRATED_POOL_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(tick_spacing: u16)]
+pub struct InitializeConcentratedPool<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = concentrated_pool,
+        associated_token::token_program = token_program
+    )]
+    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = concentrated_pool,
+        associated_token::token_program = token_program
+    )]
+    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            CONCENTRATED_POOL_SEED.as_bytes(),
+            token_a_mint.key().as_ref(),
+            token_b_mint.key().as_ref(),
+            tick_spacing.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = ConcentratedPool::DISCRIMINATOR.len() + ConcentratedPool::INIT_SPACE,
+    )]
+    pub concentrated_pool: Box<Account<'info, ConcentratedPool>>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeConcentratedPool>,
+    tick_spacing: u16,
+    fee_rate_bps: u16,
+    initial_sqrt_price_x64: u128,
+) -> Result<()> {
+    require_keys_neq!(
+        ctx.accounts.token_a_mint.key(),
+        ctx.accounts.token_b_mint.key(),
+        AmmError::IdenticalMints
+    );
+    require!(
+        (1..=16384).contains(&tick_spacing),
+        AmmError::InvalidTickSpacing
+    );
+    require_gt!(10_000, fee_rate_bps, AmmError::InvalidFeeRate);
+    let tick_current_index = tick_at_sqrt_price(initial_sqrt_price_x64)?;
+
+    **ctx.accounts.concentrated_pool = ConcentratedPool {
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        tick_spacing,
+        fee_rate_bps,
+        sqrt_price_x64: initial_sqrt_price_x64,
+        tick_current_index,
+        liquidity: 0,
+        fee_growth_global_a_x64: 0,
+        fee_growth_global_b_x64: 0,
+        bump: ctx.bumps.concentrated_pool,
+    };
+    emit_cpi!(ConcentratedPoolInitialized {
+        concentrated_pool: ctx.accounts.concentrated_pool.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        tick_spacing,
+        fee_rate_bps,
+        sqrt_price_x64: initial_sqrt_price_x64,
+    });
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::{AmmError, MathError},
+    CurveType, InitializePool, PoolInitialized, PRICE_BAND_DENOMINATOR,
+};
+
+/// Creates a constant-sum pool for assets that must trade exactly 1:1.
+///
+/// Swaps execute at par minus the swap fee until one side runs out. A non-zero
+/// `price_band_bps` rejects swaps that push either side's share of the reserves
+/// further than that from 50%. Uses the same accounts and decimal limits as
+/// `initialize_stable_pool`.
+///
+/// Creator and deposit fees work as in `initialize_pool`.
+pub fn handler(
+    ctx: Context<InitializePool>,
+    price_band_bps: u16,
+    creator_fee_bps: u16,
+    deposit_fee_bps: u16,
+    deposit_fee_to_protocol: bool,
+) -> Result<()> {
+    require_gt!(
+        PRICE_BAND_DENOMINATOR / 2,
+        price_band_bps,
+        AmmError::InvalidPriceBand
+    );
+    let token_a_decimals = ctx.accounts.token_a_mint.decimals;
+    let token_b_decimals = ctx.accounts.token_b_mint.decimals;
+    require!(
+        (1..=12).contains(&token_a_decimals) && (1..=12).contains(&token_b_decimals),
+        MathError::InvalidPrecision
+    );
+    let decimals = token_a_decimals.max(token_b_decimals);
+
+    let mut pool = ctx.accounts.initialize(
+        &ctx.bumps,
+        creator_fee_bps,
+        deposit_fee_bps,
+        deposit_fee_to_protocol,
+    )?;
+    pool.curve_type = CurveType::ConstantSum as u8;
+    pool.price_band_bps = price_band_bps;
+    pool.token_a_precision_multiplier = 10u64.pow((decimals - token_a_decimals) as u32);
+    pool.token_b_precision_multiplier = 10u64.pow((decimals - token_b_decimals) as u32);
+    drop(pool);
+
+    emit_cpi!(PoolInitialized {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        creator: ctx.accounts.signer.key(),
+    });
+    Ok(())
+}
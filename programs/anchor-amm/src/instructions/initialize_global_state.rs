@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, program::AnchorAmm, GlobalState, GLOBAL_STATE_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeGlobalState<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+        space = GlobalState::DISCRIMINATOR.len() + GlobalState::INIT_SPACE,
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AnchorAmm>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key()) @ AmmError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the global state with `admin` as its admin and nothing paused.
+///
+/// Only the program's upgrade authority may call this, so the singleton can't be
+/// claimed by whoever deploys first.
+pub fn handler(ctx: Context<InitializeGlobalState>, admin: Pubkey) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    global_state.admin = admin;
+    global_state.paused = 0;
+    global_state.bump = ctx.bumps.global_state;
+    Ok(())
+}
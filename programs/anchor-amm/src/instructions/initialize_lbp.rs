@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, CurveType, InitializePool, PoolInitialized, MIN_WEIGHT_BPS, WEIGHT_DENOMINATOR,
+};
+
+/// Creates a liquidity bootstrapping pool, e.g. 96/4 moving to 50/50 with
+/// `start_weight_a = 9600` and `end_weight_a = 5000`.
+///
+/// A weighted pool whose token A weight moves linearly from `start_weight_a` at
+/// `start_ts` to `end_weight_a` at `end_ts`, so the price of token A drifts down
+/// unless buyers push it up. Trading opens at `start_ts`. Only the creator can
+/// deposit, and can withdraw whatever is left once `end_ts` has passed.
+///
+/// Creator and deposit fees work as in `initialize_pool`.
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<InitializePool>,
+    start_weight_a: u16,
+    end_weight_a: u16,
+    start_ts: i64,
+    end_ts: i64,
+    creator_fee_bps: u16,
+    deposit_fee_bps: u16,
+    deposit_fee_to_protocol: bool,
+) -> Result<()> {
+    let valid_weight = MIN_WEIGHT_BPS..=WEIGHT_DENOMINATOR - MIN_WEIGHT_BPS;
+    require!(
+        valid_weight.contains(&start_weight_a) && valid_weight.contains(&end_weight_a),
+        AmmError::InvalidWeights
+    );
+    require_gt!(end_ts, start_ts, AmmError::InvalidLbpSchedule);
+
+    let mut pool = ctx.accounts.initialize(
+        &ctx.bumps,
+        creator_fee_bps,
+        deposit_fee_bps,
+        deposit_fee_to_protocol,
+    )?;
+    pool.curve_type = CurveType::LiquidityBootstrapping as u8;
+    pool.weight_a = start_weight_a;
+    pool.weight_b = WEIGHT_DENOMINATOR - start_weight_a;
+    pool.lbp_end_weight_a = end_weight_a;
+    pool.lbp_start_ts = start_ts;
+    pool.lbp_end_ts = end_ts;
+    pool.trading_starts_at = start_ts;
+    drop(pool);
+
+    emit_cpi!(PoolInitialized {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        creator: ctx.accounts.signer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create, AssociatedToken, Create},
+    token::{Mint, Token},
+};
+
+use crate::{
+    error::AmmError, MultiAssetPool, MultiAssetPoolInitialized, MAX_MULTI_ASSET_TOKENS,
+    MIN_WEIGHT_BPS, MULTI_ASSET_LP_DECIMALS, MULTI_ASSET_POOL_SEED, WEIGHT_DENOMINATOR,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeMultiAssetPool<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// Fresh keypair; the pool PDA is derived from it, so any basket can have several pools
+    #[account(
+        init,
+        payer = signer,
+        mint::decimals = MULTI_ASSET_LP_DECIMALS,
+        mint::authority = multi_asset_pool,
+    )]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MULTI_ASSET_POOL_SEED.as_bytes(), lp_token_mint.key().as_ref()],
+        bump,
+        space = MultiAssetPool::DISCRIMINATOR.len() + MultiAssetPool::INIT_SPACE,
+    )]
+    pub multi_asset_pool: Box<Account<'info, MultiAssetPool>>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a multi-asset pool and one vault per token.
+///
+/// `remaining_accounts` holds `[mint, vault]` for each token, where `vault` is the
+/// (not yet created) associated token account of the pool PDA for that mint.
+/// `weights` are in basis points, in the same order.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializeMultiAssetPool<'info>>,
+    weights: Vec<u16>,
+) -> Result<()> {
+    let token_count = weights.len();
+    require!(
+        (3..=MAX_MULTI_ASSET_TOKENS).contains(&token_count),
+        AmmError::InvalidTokenCount
+    );
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        token_count * 2,
+        AmmError::InvalidRemainingAccounts
+    );
+    require!(
+        weights.iter().all(|weight| *weight >= MIN_WEIGHT_BPS)
+            && weights.iter().map(|weight| *weight as u32).sum::<u32>()
+                == WEIGHT_DENOMINATOR as u32,
+        AmmError::InvalidWeights
+    );
+
+    let mut mints = [Pubkey::default(); MAX_MULTI_ASSET_TOKENS];
+    let mut vaults = [Pubkey::default(); MAX_MULTI_ASSET_TOKENS];
+    for (i, infos) in ctx.remaining_accounts.chunks(2).enumerate() {
+        let mint: Account<'info, Mint> = Account::try_from(&infos[0])?;
+        require!(!mints[..i].contains(&mint.key()), AmmError::IdenticalMints);
+        // The associated token program checks that the vault address matches the pool and mint
+        create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.signer.to_account_info(),
+                associated_token: infos[1].clone(),
+                authority: ctx.accounts.multi_asset_pool.to_account_info(),
+                mint: mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+        mints[i] = mint.key();
+        vaults[i] = infos[1].key();
+    }
+
+    let mut pool_weights = [0u16; MAX_MULTI_ASSET_TOKENS];
+    pool_weights[..token_count].copy_from_slice(&weights);
+    **ctx.accounts.multi_asset_pool = MultiAssetPool {
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        token_count: token_count as u8,
+        mints,
+        vaults,
+        reserves: [0; MAX_MULTI_ASSET_TOKENS],
+        weights: pool_weights,
+        bump: ctx.bumps.multi_asset_pool,
+    };
+
+    emit_cpi!(MultiAssetPoolInitialized {
+        multi_asset_pool: ctx.accounts.multi_asset_pool.key(),
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        mints: mints[..token_count].to_vec(),
+        weights,
+        creator: ctx.accounts.signer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::WAD,
+    CurveType, InitializePool, PoolInitialized,
+};
+
+/// Creates a DODO-style PMM pool that prices token A against an external oracle.
+///
+/// The oracle is a Pyth-style price account quoting token A in token B, passed
+/// as the first remaining account and pinned on the pool. Every swap must pass
+/// it again and is rejected if the price is older than `oracle_max_age` seconds
+/// or less precise than `oracle_max_confidence_bps`. `k` (WAD, at most 1)
+/// controls the slippage away from the oracle price. Uses the same accounts as
+/// `initialize_pool` and the same decimal limits as `initialize_stable_pool`.
+///
+/// Deposit fees work as in `initialize_pool`. There is no creator fee: it is minted
+/// from invariant growth, and a pool priced by its oracle has no invariant. The pool
+/// records no creator either, and `claim_creator_fees` and `transfer_creator` reject it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializePool<'info>>,
+    k: u64,
+    oracle_max_age: u64,
+    oracle_max_confidence_bps: u16,
+    deposit_fee_bps: u16,
+    deposit_fee_to_protocol: bool,
+) -> Result<()> {
+    require_gte!(WAD, k as u128, AmmError::InvalidPmmK);
+    let token_a_decimals = ctx.accounts.token_a_mint.decimals;
+    let token_b_decimals = ctx.accounts.token_b_mint.decimals;
+    require!(
+        (1..=12).contains(&token_a_decimals) && (1..=12).contains(&token_b_decimals),
+        MathError::InvalidPrecision
+    );
+    let decimals = token_a_decimals.max(token_b_decimals);
+    let oracle = ctx
+        .remaining_accounts
+        .first()
+        .ok_or(AmmError::InvalidOracle)?;
+
+    let mut pool =
+        ctx.accounts
+            .initialize(&ctx.bumps, 0, deposit_fee_bps, deposit_fee_to_protocol)?;
+    pool.curve_type = CurveType::OraclePmm as u8;
+    pool.creator = Pubkey::default();
+    pool.token_a_precision_multiplier = 10u64.pow((decimals - token_a_decimals) as u32);
+    pool.token_b_precision_multiplier = 10u64.pow((decimals - token_b_decimals) as u32);
+    pool.oracle = oracle.key();
+    pool.oracle_max_age = oracle_max_age;
+    pool.oracle_max_confidence_bps = oracle_max_confidence_bps;
+    pool.pmm_k = k;
+    // Fails early on an unusable oracle
+    pool.pmm_oracle_price =
+        pool.load_oracle_price_wad(Some(oracle), Clock::get()?.unix_timestamp)?;
+    drop(pool);
+
+    emit_cpi!(PoolInitialized {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        creator: Pubkey::default(),
+    });
+    Ok(())
+}
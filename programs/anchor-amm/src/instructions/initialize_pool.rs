@@ -1,11 +1,16 @@
 use crate::error::AmmError;
-use crate::{LiquidityPool, LIQUIDITY_POOL_SEED};
+use crate::{
+    LiquidityPool, PoolInitialized, PoolStats, LIQUIDITY_POOL_SEED, MAX_CREATOR_FEE_BPS,
+    POOL_STATS_SEED,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
+use std::cell::RefMut;
 
 // TODO remove token accounts for signers etc
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializePool<'info> {
     #[account(mut)]
@@ -33,12 +38,21 @@ pub struct InitializePool<'info> {
         payer = signer,
         seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
         bump,
-        space = LiquidityPool::DISCRIMINATOR.len() + LiquidityPool::INIT_SPACE,
+        space = LiquidityPool::LEN,
     )]
-    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
     #[account(
         init,
         payer = signer,
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump,
+        space = PoolStats::DISCRIMINATOR.len() + PoolStats::INIT_SPACE,
+    )]
+    pub pool_stats: Box<Account<'info, PoolStats>>,
+    /// Already exists if the pair had a pool before, see `close_pool`
+    #[account(
+        init_if_needed,
+        payer = signer,
         mint::decimals = token_a_mint.decimals.max(token_b_mint.decimals),
         mint::authority = lp_token_mint.key(),
         seeds = [b"lp_token_mint", token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
@@ -67,19 +81,75 @@ pub struct InitializePool<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializePool>) -> Result<()> {
-    require_keys_neq!(
-        ctx.accounts.token_a_mint.key(),
-        ctx.accounts.token_b_mint.key(),
-        AmmError::IdenticalMints
-    );
-    **ctx.accounts.liquidity_pool = LiquidityPool {
+/// Creates a constant-product pool whose creator takes `creator_fee_bps` of swap fees.
+///
+/// Deposits after the first one withhold `deposit_fee_bps` of the LP tokens they
+/// mint, for the protocol if `deposit_fee_to_protocol` and for existing LPs otherwise.
+pub fn handler(
+    ctx: Context<InitializePool>,
+    creator_fee_bps: u16,
+    deposit_fee_bps: u16,
+    deposit_fee_to_protocol: bool,
+) -> Result<()> {
+    ctx.accounts.initialize(
+        &ctx.bumps,
+        creator_fee_bps,
+        deposit_fee_bps,
+        deposit_fee_to_protocol,
+    )?;
+
+    emit_cpi!(PoolInitialized {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
         token_a_mint: ctx.accounts.token_a_mint.key(),
         token_b_mint: ctx.accounts.token_b_mint.key(),
-        token_a_reserves: 0,
-        token_b_reserves: 0,
-        k_last: 0,
-        bump: ctx.bumps.liquidity_pool,
-    };
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        creator: ctx.accounts.signer.key(),
+    });
     Ok(())
 }
+
+impl<'info> InitializePool<'info> {
+    /// Writes a fresh constant-product pool, linked to its mint and token accounts,
+    /// and its stats account, whose creator takes `creator_fee_bps` of swap fees.
+    ///
+    /// Deposits after the first one withhold `deposit_fee_bps` of the LP tokens they
+    /// mint, for the protocol if `deposit_fee_to_protocol` and for existing LPs otherwise.
+    ///
+    /// Other curve types start from this and overwrite their own fields in the
+    /// returned pool, which must be dropped before any CPI.
+    pub fn initialize(
+        &mut self,
+        bumps: &InitializePoolBumps,
+        creator_fee_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<RefMut<'_, LiquidityPool>> {
+        require_keys_neq!(
+            self.token_a_mint.key(),
+            self.token_b_mint.key(),
+            AmmError::IdenticalMints
+        );
+        require_gte!(
+            MAX_CREATOR_FEE_BPS,
+            creator_fee_bps,
+            AmmError::InvalidCreatorFee
+        );
+        self.pool_stats.liquidity_pool = self.liquidity_pool.key();
+        self.pool_stats.bump = bumps.pool_stats;
+        let mut pool = self.liquidity_pool.load_init()?;
+        *pool = LiquidityPool::new(
+            self.token_a_mint.key(),
+            self.token_b_mint.key(),
+            bumps.liquidity_pool,
+            self.signer.key(),
+        );
+        pool.lp_mint = self.lp_token_mint.key();
+        pool.lp_mint_bump = bumps.lp_token_mint;
+        pool.token_a_vault = self.token_a_vault.key();
+        pool.token_b_vault = self.token_b_vault.key();
+        pool.fee_lp_token_account = self.fee_lp_token_account.key();
+        pool.creator_fee_bps = creator_fee_bps;
+        pool.set_deposit_fee(deposit_fee_bps, deposit_fee_to_protocol)?;
+        Ok(pool)
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::{AmmError, MathError},
+    CurveType, InitializePool, PoolInitialized, MAX_AMP,
+};
+
+/// Creates a StableSwap pool for a pair of pegged assets.
+///
+/// Uses the same accounts as `initialize_pool`, so a pair gets either a
+/// constant-product pool or a stable pool, never both. Both mints must have
+/// between 1 and 12 decimals so normalized reserves stay well inside a u128.
+///
+/// Creator and deposit fees work as in `initialize_pool`.
+pub fn handler(
+    ctx: Context<InitializePool>,
+    amp: u64,
+    creator_fee_bps: u16,
+    deposit_fee_bps: u16,
+    deposit_fee_to_protocol: bool,
+) -> Result<()> {
+    require!((1..=MAX_AMP).contains(&amp), AmmError::InvalidAmp);
+    let token_a_decimals = ctx.accounts.token_a_mint.decimals;
+    let token_b_decimals = ctx.accounts.token_b_mint.decimals;
+    require!(
+        (1..=12).contains(&token_a_decimals) && (1..=12).contains(&token_b_decimals),
+        MathError::InvalidPrecision
+    );
+    let decimals = token_a_decimals.max(token_b_decimals);
+
+    let mut pool = ctx.accounts.initialize(
+        &ctx.bumps,
+        creator_fee_bps,
+        deposit_fee_bps,
+        deposit_fee_to_protocol,
+    )?;
+    pool.curve_type = CurveType::Stable as u8;
+    pool.amp_initial = amp;
+    pool.amp_target = amp;
+    pool.token_a_precision_multiplier = 10u64.pow((decimals - token_a_decimals) as u32);
+    pool.token_b_precision_multiplier = 10u64.pow((decimals - token_b_decimals) as u32);
+    drop(pool);
+
+    emit_cpi!(PoolInitialized {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        creator: ctx.accounts.signer.key(),
+    });
+    Ok(())
+}
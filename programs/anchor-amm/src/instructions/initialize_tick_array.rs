@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, ConcentratedPool, TickArray, MAX_TICK_INDEX, MIN_TICK_INDEX, TICK_ARRAY_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(start_tick_index: i32)]
+pub struct InitializeTickArray<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub concentrated_pool: Box<Account<'info, ConcentratedPool>>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            TICK_ARRAY_SEED.as_bytes(),
+            concentrated_pool.key().as_ref(),
+            start_tick_index.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = TickArray::DISCRIMINATOR.len() + std::mem::size_of::<TickArray>(),
+    )]
+    pub tick_array: AccountLoader<'info, TickArray>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTickArray>, start_tick_index: i32) -> Result<()> {
+    let tick_spacing = ctx.accounts.concentrated_pool.tick_spacing;
+    require_eq!(
+        TickArray::start_tick_index_for(start_tick_index, tick_spacing),
+        start_tick_index,
+        AmmError::InvalidTickIndex
+    );
+    // The array must cover at least one valid tick
+    require!(
+        start_tick_index <= MAX_TICK_INDEX
+            && TickArray::start_tick_index_for(MIN_TICK_INDEX, tick_spacing) <= start_tick_index,
+        AmmError::InvalidTickIndex
+    );
+
+    let mut tick_array = ctx.accounts.tick_array.load_init()?;
+    tick_array.concentrated_pool = ctx.accounts.concentrated_pool.key();
+    tick_array.start_tick_index = start_tick_index;
+    Ok(())
+}
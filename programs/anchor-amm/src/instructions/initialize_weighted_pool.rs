@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, CurveType, InitializePool, PoolInitialized, MIN_WEIGHT_BPS, WEIGHT_DENOMINATOR,
+};
+
+/// Creates a Balancer-style weighted pool, e.g. 80/20 with `weight_a = 8000`.
+///
+/// Uses the same accounts as `initialize_pool`, so a pair gets exactly one
+/// pool whatever its curve.
+///
+/// Creator and deposit fees work as in `initialize_pool`.
+pub fn handler(
+    ctx: Context<InitializePool>,
+    weight_a: u16,
+    weight_b: u16,
+    creator_fee_bps: u16,
+    deposit_fee_bps: u16,
+    deposit_fee_to_protocol: bool,
+) -> Result<()> {
+    require!(
+        weight_a >= MIN_WEIGHT_BPS
+            && weight_b >= MIN_WEIGHT_BPS
+            && weight_a.checked_add(weight_b) == Some(WEIGHT_DENOMINATOR),
+        AmmError::InvalidWeights
+    );
+
+    let mut pool = ctx.accounts.initialize(
+        &ctx.bumps,
+        creator_fee_bps,
+        deposit_fee_bps,
+        deposit_fee_to_protocol,
+    )?;
+    pool.curve_type = CurveType::Weighted as u8;
+    pool.weight_a = weight_a;
+    pool.weight_b = weight_b;
+    drop(pool);
+
+    emit_cpi!(PoolInitialized {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        lp_token_mint: ctx.accounts.lp_token_mint.key(),
+        creator: ctx.accounts.signer.key(),
+    });
+    Ok(())
+}
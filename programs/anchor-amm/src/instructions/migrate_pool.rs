@@ -0,0 +1,105 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::associated_token::get_associated_token_address;
+
+use crate::{
+    error::AmmError, GlobalState, LiquidityPool, PoolMigrated, PoolStats, GLOBAL_STATE_SEED,
+    LIQUIDITY_POOL_SEED, LIQUIDITY_POOL_VERSION, POOL_STATS_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump = global_state.bump,
+        has_one = admin @ AmmError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    /// CHECK: an old layout that `AccountLoader<LiquidityPool>` can't load; decoded
+    /// by `LiquidityPool::migrate_account_data` and checked against its seeds
+    #[account(mut, owner = crate::ID)]
+    pub liquidity_pool: UncheckedAccount<'info>,
+    /// Pools from before versioning predate pool stats; later ones already have them
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump,
+        space = PoolStats::DISCRIMINATOR.len() + PoolStats::INIT_SPACE,
+    )]
+    pub pool_stats: Account<'info, PoolStats>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a pool in an older layout to the current one in place, see
+/// `LiquidityPool::migrate_account_data` for the layouts it reads.
+///
+/// The global admin pays for the extra space. Pools from before versioning
+/// recorded no admin or creator, so the global admin becomes both.
+pub fn handler(ctx: Context<MigratePool>) -> Result<()> {
+    let pool_info = ctx.accounts.liquidity_pool.to_account_info();
+    let mut pool = LiquidityPool::migrate_account_data(
+        &pool_info.try_borrow_data()?,
+        ctx.accounts.admin.key(),
+    )?;
+    let expected_key = Pubkey::create_program_address(
+        &[
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            pool.token_a_mint.as_ref(),
+            pool.token_b_mint.as_ref(),
+            &[pool.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| AmmError::InvalidPoolLayout)?;
+    require_keys_eq!(pool_info.key(), expected_key, AmmError::InvalidPoolLayout);
+
+    let new_len = LiquidityPool::LEN;
+    let rent_top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(pool_info.lamports());
+    if rent_top_up > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: pool_info.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+    pool_info.resize(new_len)?;
+
+    // Old pools always used the canonical LP mint and associated token accounts
+    (pool.lp_mint, pool.lp_mint_bump) = Pubkey::find_program_address(
+        &[
+            b"lp_token_mint",
+            pool.token_a_mint.as_ref(),
+            pool.token_b_mint.as_ref(),
+        ],
+        &crate::ID,
+    );
+    pool.token_a_vault = get_associated_token_address(&pool_info.key(), &pool.token_a_mint);
+    pool.token_b_vault = get_associated_token_address(&pool_info.key(), &pool.token_b_mint);
+    pool.fee_lp_token_account = get_associated_token_address(&pool_info.key(), &pool.lp_mint);
+    let mut data = pool_info.try_borrow_mut_data()?;
+    let (discriminator, fields) = data.split_at_mut(LiquidityPool::DISCRIMINATOR.len());
+    discriminator.copy_from_slice(LiquidityPool::DISCRIMINATOR);
+    fields.copy_from_slice(bytemuck::bytes_of(&pool));
+    drop(data);
+    ctx.accounts.pool_stats.liquidity_pool = pool_info.key();
+    ctx.accounts.pool_stats.bump = ctx.bumps.pool_stats;
+
+    emit_cpi!(PoolMigrated {
+        liquidity_pool: pool_info.key(),
+        version: LIQUIDITY_POOL_VERSION,
+    });
+    Ok(())
+}
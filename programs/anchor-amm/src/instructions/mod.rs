@@ -2,15 +2,79 @@
 // ^ this is so that I can use instruction handlers
 // with the "deposit::handler" or "intitialize_pool::handler" format without warnings.
 // it shouldn't cause any issues because I'm always fully qualifying it.
+pub mod claim_creator_fees;
+pub mod close_pool;
+pub mod collect_fees;
+pub mod decrease_liquidity;
 pub mod deposit;
+pub mod deposit_multi_asset;
+pub mod deposit_multi_asset_single;
+pub mod increase_liquidity;
+pub mod initialize_concentrated_pool;
+pub mod initialize_constant_sum_pool;
+pub mod initialize_global_state;
+pub mod initialize_lbp;
+pub mod initialize_multi_asset_pool;
+pub mod initialize_pmm_pool;
 pub mod initialize_pool;
+pub mod initialize_stable_pool;
+pub mod initialize_tick_array;
+pub mod initialize_weighted_pool;
+pub mod migrate_pool;
+pub mod open_position;
+pub mod quote_deposit;
+pub mod quote_swap;
+pub mod ramp_amp;
+pub mod set_dynamic_fee;
+pub mod set_global_pause;
+pub mod set_launch_params;
+pub mod set_pool_pause;
+pub mod set_price_band;
+pub mod set_reference_oracle;
+pub mod set_slot_price_move_limit;
 pub mod skim_reserves;
+pub mod stop_ramp_amp;
 pub mod swap;
+pub mod swap_concentrated;
+pub mod swap_multi_asset;
+pub mod swap_v2;
 pub mod sync_reserves;
+pub mod transfer_creator;
 pub mod withdraw;
+pub mod withdraw_multi_asset;
+pub mod withdraw_multi_asset_single;
+pub use claim_creator_fees::*;
+pub use close_pool::*;
+pub use collect_fees::*;
+pub use decrease_liquidity::*;
 pub use deposit::*;
+pub use deposit_multi_asset::*;
+pub use deposit_multi_asset_single::*;
+pub use increase_liquidity::*;
+pub use initialize_concentrated_pool::*;
+pub use initialize_global_state::*;
+pub use initialize_multi_asset_pool::*;
 pub use initialize_pool::*;
+pub use initialize_tick_array::*;
+pub use migrate_pool::*;
+pub use open_position::*;
+pub use quote_deposit::*;
+pub use quote_swap::*;
+pub use ramp_amp::*;
+pub use set_dynamic_fee::*;
+pub use set_global_pause::*;
+pub use set_launch_params::*;
+pub use set_pool_pause::*;
+pub use set_price_band::*;
+pub use set_reference_oracle::*;
+pub use set_slot_price_move_limit::*;
 pub use skim_reserves::*;
 pub use swap::*;
+pub use swap_concentrated::*;
+pub use swap_multi_asset::*;
+pub use swap_v2::*;
 pub use sync_reserves::*;
+pub use transfer_creator::*;
 pub use withdraw::*;
+pub use withdraw_multi_asset::*;
+pub use withdraw_multi_asset_single::*;
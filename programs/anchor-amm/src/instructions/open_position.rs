@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, ConcentratedPool, Position, PositionOpened, TickArray, POSITION_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(tick_lower_index: i32, tick_upper_index: i32)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub concentrated_pool: Box<Account<'info, ConcentratedPool>>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            POSITION_SEED.as_bytes(),
+            concentrated_pool.key().as_ref(),
+            signer.key().as_ref(),
+            tick_lower_index.to_le_bytes().as_ref(),
+            tick_upper_index.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = Position::DISCRIMINATOR.len() + Position::INIT_SPACE,
+    )]
+    pub position: Box<Account<'info, Position>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<OpenPosition>,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Result<()> {
+    let tick_spacing = ctx.accounts.concentrated_pool.tick_spacing;
+    TickArray::validate_tick_index(tick_lower_index, tick_spacing)?;
+    TickArray::validate_tick_index(tick_upper_index, tick_spacing)?;
+    require_gt!(
+        tick_upper_index,
+        tick_lower_index,
+        AmmError::InvalidTickRange
+    );
+
+    **ctx.accounts.position = Position {
+        concentrated_pool: ctx.accounts.concentrated_pool.key(),
+        owner: ctx.accounts.signer.key(),
+        tick_lower_index,
+        tick_upper_index,
+        liquidity: 0,
+        fee_growth_inside_a_last_x64: 0,
+        fee_growth_inside_b_last_x64: 0,
+        fees_owed_a: 0,
+        fees_owed_b: 0,
+        bump: ctx.bumps.position,
+    };
+    emit_cpi!(PositionOpened {
+        concentrated_pool: ctx.accounts.concentrated_pool.key(),
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.signer.key(),
+        tick_lower_index,
+        tick_upper_index,
+    });
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::quote,
+    DepositResult, LiquidityPool,
+};
+
+#[derive(Accounts)]
+pub struct QuoteDeposit<'info> {
+    #[account(constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked)]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+    #[account(address = liquidity_pool.load()?.lp_mint)]
+    pub lp_token_mint: Account<'info, Mint>,
+}
+
+/// Quotes a `deposit` of up to the desired amounts without changing any state.
+///
+/// Meant to be simulated: the result is what `deposit` would return, including
+/// the deposit fee and the dilution from protocol fees minted first.
+pub fn handler(
+    ctx: Context<QuoteDeposit>,
+    token_a_amount_desired: u64,
+    token_b_amount_desired: u64,
+) -> Result<DepositResult> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool = ctx.accounts.liquidity_pool.load()?;
+    let mut lp_supply = pool.lp_supply(ctx.accounts.lp_token_mint.supply);
+    let (token_a_amount, token_b_amount) = if lp_supply == 0 {
+        (token_a_amount_desired, token_b_amount_desired)
+    } else {
+        let fee_mint = pool.accrued_fee_lp_tokens(lp_supply, now)?;
+        lp_supply += fee_mint.protocol_lp_tokens + fee_mint.creator_lp_tokens;
+
+        let token_b_optimal_amount = quote(
+            token_a_amount_desired as u128,
+            pool.token_a_reserves as u128,
+            pool.token_b_reserves as u128,
+        )?;
+        if token_b_optimal_amount <= token_b_amount_desired as u128 {
+            (token_a_amount_desired, token_b_optimal_amount as u64)
+        } else {
+            let token_a_optimal_amount = quote(
+                token_b_amount_desired as u128,
+                pool.token_b_reserves as u128,
+                pool.token_a_reserves as u128,
+            )?;
+            (token_a_optimal_amount as u64, token_b_amount_desired)
+        }
+    };
+    let lp_tokens: u64 = pool
+        .swap_curve(now)
+        .deposit_lp_tokens(
+            token_a_amount as u128,
+            token_b_amount as u128,
+            pool.token_a_reserves as u128,
+            pool.token_b_reserves as u128,
+            lp_supply as u128,
+        )?
+        .try_into()
+        .map_err(|_| MathError::Overflow)?;
+    // The first deposit locks the minimum liquidity and pays no deposit fee
+    let (lp_tokens_minted, deposit_fee) = if lp_supply == 0 {
+        (lp_tokens.saturating_sub(1000), 0)
+    } else {
+        let deposit_fee = pool.deposit_fee(lp_tokens);
+        (lp_tokens - deposit_fee, deposit_fee)
+    };
+
+    Ok(DepositResult {
+        token_a_amount,
+        token_b_amount,
+        lp_tokens_minted,
+        deposit_fee,
+        token_a_reserves: pool
+            .token_a_reserves
+            .checked_add(token_a_amount)
+            .ok_or(MathError::Overflow)?,
+        token_b_reserves: pool
+            .token_b_reserves
+            .checked_add(token_b_amount)
+            .ok_or(MathError::Overflow)?,
+    })
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, CurveType, LiquidityPool, MAX_REFERRAL_FEE_BPS};
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    #[account(constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked)]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Price of an exact-input swap, written as return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// Part of `amount_in` kept by the pool
+    pub fee: u64,
+    /// Effective swap fee in basis points, including any dynamic component
+    pub fee_bps: u64,
+    /// Part of `amount_in` paid to the referrer before the swap fee applies
+    pub referral_fee: u64,
+}
+
+/// Quotes a `swap` of `amount_in` without changing any state.
+///
+/// Meant to be simulated: the result is the return data. Oracle PMM pools take
+/// their oracle as the first remaining account, like `swap`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, QuoteSwap<'info>>,
+    amount_in: u64,
+    is_token_a_in: bool,
+    referral_fee_bps: u16,
+) -> Result<SwapQuote> {
+    require_gte!(
+        MAX_REFERRAL_FEE_BPS,
+        referral_fee_bps,
+        AmmError::InvalidReferralFee
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let mut pool = *ctx.accounts.liquidity_pool.load()?;
+    if pool.curve_type() == CurveType::OraclePmm {
+        let oracle_price = pool.load_oracle_price_wad(ctx.remaining_accounts.first(), now)?;
+        pool.refresh_pmm_targets(oracle_price)?;
+    }
+    pool.quote_swap(amount_in, is_token_a_in, referral_fee_bps, now)
+}
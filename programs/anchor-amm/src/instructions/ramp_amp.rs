@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, AmpRampStarted, LiquidityPool, MAX_AMP, MAX_AMP_CHANGE, MIN_AMP_RAMP_DURATION,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RampAmp<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ AmmError::Unauthorized,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Starts a linear ramp of the amplification coefficient towards `amp_target`.
+///
+/// Follows Curve's `ramp_A` limits: ramps last at least `MIN_AMP_RAMP_DURATION`,
+/// can't start within `MIN_AMP_RAMP_DURATION` of the previous ramp, and move
+/// the coefficient by at most `MAX_AMP_CHANGE` in either direction.
+pub fn handler(ctx: Context<RampAmp>, amp_target: u64, ramp_stop_ts: i64) -> Result<()> {
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    require!(pool.is_stable(), AmmError::NotStablePool);
+    require!((1..=MAX_AMP).contains(&amp_target), AmmError::InvalidAmp);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= pool.amp_ramp_start_ts.saturating_add(MIN_AMP_RAMP_DURATION)
+            && ramp_stop_ts >= now.saturating_add(MIN_AMP_RAMP_DURATION),
+        AmmError::InvalidAmpRamp
+    );
+
+    let amp_current = pool.current_amp(now);
+    require!(
+        amp_target <= amp_current.saturating_mul(MAX_AMP_CHANGE)
+            && amp_target.saturating_mul(MAX_AMP_CHANGE) >= amp_current,
+        AmmError::InvalidAmpRamp
+    );
+
+    pool.amp_initial = amp_current;
+    pool.amp_target = amp_target;
+    pool.amp_ramp_start_ts = now;
+    pool.amp_ramp_stop_ts = ramp_stop_ts;
+    drop(pool);
+
+    emit_cpi!(AmpRampStarted {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        amp_initial: amp_current,
+        amp_target,
+        ramp_start_ts: now,
+        ramp_stop_ts,
+    });
+    Ok(())
+}
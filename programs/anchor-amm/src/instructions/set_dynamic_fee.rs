@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, DynamicFeeUpdated, GlobalState, LiquidityPool, GLOBAL_STATE_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDynamicFee<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump = global_state.bump,
+        has_one = admin @ AmmError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        mut,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Configures volatility-based swap fees; a `volatility_fee_factor_bps` of zero disables them.
+///
+/// Every swap adds its spot price move (in basis points) to a volatility
+/// accumulator that halves every `volatility_half_life` seconds. The swap fee is
+/// `SWAP_FEE_BPS` plus `volatility_fee_factor_bps` of the accumulator, capped at
+/// `max_swap_fee_bps`. Not available on PMM pools, whose spot price depends on the oracle.
+///
+/// Only the protocol admin may configure it; otherwise whoever initialized the pair
+/// first could make every swap pay up to `MAX_SWAP_FEE_BPS`.
+pub fn handler(
+    ctx: Context<SetDynamicFee>,
+    volatility_fee_factor_bps: u16,
+    max_swap_fee_bps: u16,
+    volatility_half_life: i64,
+) -> Result<()> {
+    ctx.accounts.liquidity_pool.load_mut()?.set_dynamic_fee(
+        volatility_fee_factor_bps,
+        max_swap_fee_bps,
+        volatility_half_life,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    emit_cpi!(DynamicFeeUpdated {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        volatility_fee_factor_bps,
+        max_swap_fee_bps,
+        volatility_half_life,
+    });
+    Ok(())
+}
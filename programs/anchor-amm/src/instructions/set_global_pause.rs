@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, GlobalPauseUpdated, GlobalState, GLOBAL_STATE_SEED, PAUSE_ALL};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGlobalPause<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump = global_state.bump,
+        has_one = admin @ AmmError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Replaces the `PAUSE_*` flags applied to every pool; zero resumes everything.
+pub fn handler(ctx: Context<SetGlobalPause>, paused: u8) -> Result<()> {
+    require!(paused & !PAUSE_ALL == 0, AmmError::InvalidPauseFlags);
+    ctx.accounts.global_state.paused = paused;
+
+    emit_cpi!(GlobalPauseUpdated { paused });
+    Ok(())
+}
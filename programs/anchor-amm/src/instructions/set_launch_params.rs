@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, LaunchParamsUpdated, LiquidityPool, MAX_LAUNCH_FEE_BPS};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetLaunchParams<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ AmmError::Unauthorized,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Sets anti-snipe launch parameters on a pool that doesn't hold liquidity yet.
+///
+/// Meant to run between `initialize_pool` and the first `deposit`. Swaps fail
+/// before `trading_starts_at`. From then on the swap fee starts at
+/// `launch_fee_bps` and decays linearly to the regular fee over
+/// `launch_fee_duration` seconds, during which no single swap may pay out more
+/// than `launch_max_buy` (zero for no limit).
+pub fn handler(
+    ctx: Context<SetLaunchParams>,
+    trading_starts_at: i64,
+    launch_fee_bps: u16,
+    launch_fee_duration: i64,
+    launch_max_buy: u64,
+) -> Result<()> {
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    require!(
+        pool.token_a_reserves == 0 && pool.token_b_reserves == 0,
+        AmmError::LaunchAlreadyStarted
+    );
+    require!(
+        launch_fee_bps <= MAX_LAUNCH_FEE_BPS && launch_fee_duration >= 0,
+        AmmError::InvalidLaunchParams
+    );
+
+    pool.trading_starts_at = trading_starts_at;
+    pool.launch_fee_bps = launch_fee_bps;
+    pool.launch_fee_duration = launch_fee_duration;
+    pool.launch_max_buy = launch_max_buy;
+    drop(pool);
+
+    emit_cpi!(LaunchParamsUpdated {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        trading_starts_at,
+        launch_fee_bps,
+        launch_fee_duration,
+        launch_max_buy,
+    });
+    Ok(())
+}
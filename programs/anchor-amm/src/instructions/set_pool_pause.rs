@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, LiquidityPool, PoolPauseUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPoolPause<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ AmmError::Unauthorized,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Replaces the pool's own `PAUSE_*` flags; zero resumes everything not paused globally.
+///
+/// `PAUSE_WITHDRAW` is rejected: anyone can create a pool and become its admin,
+/// so only the protocol admin may stop LPs from exiting, through `set_global_pause`.
+pub fn handler(ctx: Context<SetPoolPause>, paused: u8) -> Result<()> {
+    ctx.accounts.liquidity_pool.load_mut()?.set_paused(paused)?;
+
+    emit_cpi!(PoolPauseUpdated {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        paused,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, CurveType, LiquidityPool, PriceBandUpdated, PRICE_BAND_DENOMINATOR};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPriceBand<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ AmmError::Unauthorized,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Updates the price band of a constant-sum pool; zero disables it.
+///
+/// Only constrains future swaps, so a pool already outside the new band can
+/// still be traded back towards balance.
+pub fn handler(ctx: Context<SetPriceBand>, price_band_bps: u16) -> Result<()> {
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    require!(
+        pool.curve_type() == CurveType::ConstantSum,
+        AmmError::NotConstantSumPool
+    );
+    require_gt!(
+        PRICE_BAND_DENOMINATOR / 2,
+        price_band_bps,
+        AmmError::InvalidPriceBand
+    );
+    pool.price_band_bps = price_band_bps;
+    drop(pool);
+
+    emit_cpi!(PriceBandUpdated {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        price_band_bps,
+    });
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, CurveType, GlobalState, LiquidityPool, ReferenceOracleUpdated,
+    GLOBAL_STATE_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferenceOracle<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump = global_state.bump,
+        has_one = admin @ AmmError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        mut,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+    /// CHECK: Parsed as a Pyth-style price account unless the guard is being disabled
+    pub oracle: UncheckedAccount<'info>,
+}
+
+/// Sets the reference oracle that swaps are checked against.
+///
+/// After every swap the pool's spot price must stay within `max_deviation_bps`
+/// of the oracle price, so thin pools can't be pushed far from fair value to
+/// manipulate protocols that read their price. A `max_deviation_bps` of zero
+/// disables the guard and clears the oracle. Not available on PMM pools, which
+/// already price against their oracle.
+///
+/// Only the protocol admin may set it, since a pool's own admin is just whoever
+/// initialized it first and could point the guard at a price no swap can meet.
+pub fn handler(
+    ctx: Context<SetReferenceOracle>,
+    max_deviation_bps: u16,
+    max_age: u64,
+    max_confidence_bps: u16,
+) -> Result<()> {
+    require!(
+        ctx.accounts.liquidity_pool.load()?.curve_type() != CurveType::OraclePmm,
+        AmmError::UnsupportedCurveOperation
+    );
+    require_gte!(10_000, max_deviation_bps, AmmError::InvalidOracleDeviation);
+
+    let oracle = if max_deviation_bps == 0 {
+        Pubkey::default()
+    } else {
+        ctx.accounts.oracle.key()
+    };
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    pool.oracle = oracle;
+    pool.max_oracle_deviation_bps = max_deviation_bps;
+    pool.oracle_max_age = max_age;
+    pool.oracle_max_confidence_bps = max_confidence_bps;
+    if max_deviation_bps > 0 {
+        // Fails early on an unusable oracle
+        pool.load_oracle_price_wad(
+            Some(ctx.accounts.oracle.as_ref()),
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+    drop(pool);
+
+    emit_cpi!(ReferenceOracleUpdated {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        oracle,
+        max_deviation_bps,
+        max_age,
+        max_confidence_bps,
+    });
+    Ok(())
+}
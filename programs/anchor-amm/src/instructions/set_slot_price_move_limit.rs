@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::AmmError, GlobalState, LiquidityPool, SlotPriceMoveLimitUpdated, GLOBAL_STATE_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSlotPriceMoveLimit<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump = global_state.bump,
+        has_one = admin @ AmmError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        mut,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Caps the spot price moves, in basis points, that the swaps of a single slot may add up to.
+///
+/// Every move counts whatever its direction, so a sandwich landing in one slot pays
+/// for both legs. Zero disables the limit. Not available on PMM pools, whose spot
+/// price depends on the oracle.
+///
+/// Only the protocol admin may set it: a tight limit makes ordinary swaps fail, and
+/// the first caller of a pool initializer would otherwise control it.
+pub fn handler(ctx: Context<SetSlotPriceMoveLimit>, max_slot_price_move_bps: u16) -> Result<()> {
+    ctx.accounts
+        .liquidity_pool
+        .load_mut()?
+        .set_slot_price_move_limit(max_slot_price_move_bps)?;
+
+    emit_cpi!(SlotPriceMoveLimitUpdated {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        max_slot_price_move_bps,
+    });
+    Ok(())
+}
@@ -1,8 +1,12 @@
 pub use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::{error::AmmError, helpers::VaultWithdrawer, LiquidityPool, LIQUIDITY_POOL_SEED};
+use crate::{
+    error::AmmError, helpers::VaultWithdrawer, GlobalState, LiquidityPool, ReservesSkimmed,
+    GLOBAL_STATE_SEED, LIQUIDITY_POOL_SEED, PAUSE_SKIM,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SkimReserves<'info> {
     #[account(mut)]
@@ -10,9 +14,12 @@ pub struct SkimReserves<'info> {
     #[account(
         mut,
         seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
-        bump
+        bump,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
     )]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+    #[account(seeds = [GLOBAL_STATE_SEED.as_bytes()], bump = global_state.bump)]
+    pub global_state: Account<'info, GlobalState>,
     pub token_a_mint: Account<'info, Mint>,
     pub token_b_mint: Account<'info, Mint>,
     #[account(
@@ -45,23 +52,33 @@ pub struct SkimReserves<'info> {
 }
 
 pub fn handler(ctx: Context<SkimReserves>) -> Result<()> {
+    let pool = *ctx.accounts.liquidity_pool.load()?;
+    pool.check_not_paused(&ctx.accounts.global_state, PAUSE_SKIM)?;
     let token_a_excess = ctx
         .accounts
         .token_a_vault
         .amount
-        .saturating_sub(ctx.accounts.liquidity_pool.token_a_reserves);
+        .saturating_sub(pool.token_a_reserves);
     let token_b_excess = ctx
         .accounts
         .token_b_vault
         .amount
-        .saturating_sub(ctx.accounts.liquidity_pool.token_b_reserves);
+        .saturating_sub(pool.token_b_reserves);
 
     require!(
         token_a_excess > 0 || token_b_excess > 0,
         AmmError::NoExcessTokens
     );
 
+    ctx.accounts.liquidity_pool.load_mut()?.locked = 1;
     ctx.accounts.withdraw(token_a_excess, token_b_excess)?;
+    ctx.accounts.liquidity_pool.load_mut()?.locked = 0;
+    emit_cpi!(ReservesSkimmed {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        recipient: ctx.accounts.signer.key(),
+        token_a_amount: token_a_excess,
+        token_b_amount: token_b_excess,
+    });
     Ok(())
 }
 
@@ -94,7 +111,7 @@ impl<'info> VaultWithdrawer<'info> for SkimReserves<'info> {
         &self.token_b_vault
     }
 
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool> {
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
         &self.liquidity_pool
     }
 }
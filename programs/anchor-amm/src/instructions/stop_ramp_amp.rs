@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, AmpRampStopped, RampAmp};
+
+/// Freezes the amplification coefficient at its current interpolated value.
+pub fn handler(ctx: Context<RampAmp>) -> Result<()> {
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    require!(pool.is_stable(), AmmError::NotStablePool);
+
+    let now = Clock::get()?.unix_timestamp;
+    let amp_current = pool.current_amp(now);
+    pool.amp_initial = amp_current;
+    pool.amp_target = amp_current;
+    pool.amp_ramp_start_ts = now;
+    pool.amp_ramp_stop_ts = now;
+    drop(pool);
+
+    emit_cpi!(AmpRampStopped {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        amp: amp_current,
+    });
+    Ok(())
+}
@@ -5,10 +5,15 @@ use anchor_spl::{
 };
 
 use crate::{
-    error::{AmmError, MathError},
-    helpers::{get_amount_out, ReserveSyncer, VaultDepositor, VaultWithdrawer},
-    LiquidityPool, LIQUIDITY_POOL_SEED,
+    error::AmmError,
+    helpers::{
+        check_no_opposite_swap, check_oracle_deviation, split_swap_remaining_accounts,
+        TokenDepositor, VaultDepositor, VaultWithdrawer,
+    },
+    CurveType, GlobalState, LiquidityPool, PoolStats, SwapQuote, Swapped, GLOBAL_STATE_SEED,
+    LIQUIDITY_POOL_SEED, MAX_REFERRAL_FEE_BPS, PAUSE_SWAP, POOL_STATS_SEED,
 };
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(mut)]
@@ -27,78 +32,136 @@ pub struct Swap<'info> {
         associated_token::token_program = token_program
     )]
     pub token_1_signer_token_account: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        associated_token::mint = token_0_mint,
-        associated_token::authority = liquidity_pool
-    )]
+    /// The pool's vault for `token_0_mint`
+    #[account(mut)]
     pub token_0_vault: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        associated_token::mint = token_1_mint,
-        associated_token::authority = liquidity_pool
-    )]
+    /// The pool's vault for `token_1_mint`
+    #[account(mut)]
     pub token_1_vault: Account<'info, TokenAccount>,
     pub token_0_mint: Account<'info, Mint>,
     pub token_1_mint: Account<'info, Mint>,
+    /// The pool's LP mint
+    #[account(mut)]
+    pub lp_token_mint: Account<'info, Mint>,
     #[account(
         mut,
-        seeds = [
-            b"lp_token_mint", 
-            liquidity_pool.token_a_mint.as_ref(), 
-            liquidity_pool.token_b_mint.as_ref()
-        ],
-        bump
+        seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_0_mint.key().as_ref(), token_1_mint.key().as_ref()],
+        bump = liquidity_pool.load()?.bump
     )]
-    pub lp_token_mint: Account<'info, Mint>,
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
     #[account(
         mut,
-        seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_0_mint.key().as_ref(), token_1_mint.key().as_ref()],
-        bump
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump = pool_stats.bump
     )]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
+    pub pool_stats: Box<Account<'info, PoolStats>>,
+    #[account(seeds = [GLOBAL_STATE_SEED.as_bytes()], bump = global_state.bump)]
+    pub global_state: Box<Account<'info, GlobalState>>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    /// Receives the referral fee, in the input mint
+    #[account(mut, token::mint = token_0_mint)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
 }
 
-pub fn handler(
-    ctx: Context<Swap>,
+/// Swap outcome, written as return data so CPI callers don't have to diff balances.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SwapResult {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
+/// Swaps an exact amount of `token_0` for `token_1`, failing unless at least
+/// `token_1_min_amount` comes out.
+///
+/// `referral_fee_bps` of `token_0_amount` (at most `MAX_REFERRAL_FEE_BPS`) goes
+/// straight to `referrer_token_account`; the rest is swapped.
+///
+/// Oracle PMM pools price against their oracle, and pools with a reference oracle
+/// check the post-trade spot price against it. Either oracle is passed as a
+/// remaining account. Passing the instructions sysvar among the remaining
+/// accounts, before or after the oracle, opts the swap into
+/// `check_no_opposite_swap`, as in `swap_v2`.
+///
+/// The pool is copied out once, updated in memory and written back twice: with
+/// the lock held before the token CPIs, and with the synced reserves after them.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Swap<'info>>,
     token_0_amount: u64,
     token_1_min_amount: u64,
     expiration: i64,
-) -> Result<()> {
-    let is_token_a = ctx.accounts.token_0_mint.key() == ctx.accounts.liquidity_pool.token_a_mint;
-    ctx.accounts.validate(token_0_amount, token_1_min_amount, expiration, is_token_a)?;
-    let token_0_amount_with_fees = (token_0_amount as u128)
-        .checked_mul(997)
-        .ok_or(MathError::Overflow)?
-        .checked_div(1000)
-        .ok_or(MathError::Overflow)?;
-
-    let token_1_out: u64 = if is_token_a {
-        get_amount_out(
-            token_0_amount_with_fees,
-            ctx.accounts.liquidity_pool.token_a_reserves as u128,
-            ctx.accounts.liquidity_pool.token_b_reserves as u128,
-        )
+    referral_fee_bps: u16,
+) -> Result<SwapResult> {
+    let mut pool = *ctx.accounts.liquidity_pool.load()?;
+    pool.check_not_paused(&ctx.accounts.global_state, PAUSE_SWAP)?;
+    let is_token_a = ctx.accounts.token_0_mint.key() == pool.token_a_mint;
+    ctx.accounts.validate(
+        &pool,
+        token_0_amount,
+        token_1_min_amount,
+        expiration,
+        is_token_a,
+    )?;
+    require!(
+        referral_fee_bps <= MAX_REFERRAL_FEE_BPS
+            && (referral_fee_bps == 0 || ctx.accounts.referrer_token_account.is_some()),
+        AmmError::InvalidReferralFee
+    );
+    let (oracle, instructions_sysvar) = split_swap_remaining_accounts(ctx.remaining_accounts);
+    if let Some(instructions_sysvar) = instructions_sysvar {
+        check_no_opposite_swap(
+            instructions_sysvar,
+            &ctx.accounts.liquidity_pool.key(),
+            &pool.token_a_mint,
+            is_token_a,
+        )?;
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if pool.curve_type() == CurveType::OraclePmm {
+        let oracle_price = pool.load_oracle_price_wad(oracle, now)?;
+        pool.refresh_pmm_targets(oracle_price)?;
+    }
+
+    let price_before = if pool.tracks_price_moves() {
+        Some(pool.spot_price(now)?)
     } else {
-        get_amount_out(
-            token_0_amount_with_fees,
-            ctx.accounts.liquidity_pool.token_b_reserves as u128,
-            ctx.accounts.liquidity_pool.token_a_reserves as u128,
-        )
-    }?
-    .try_into()
-    .map_err(|_| MathError::Overflow)?;
-    require_gt!(token_1_out, token_1_min_amount, AmmError::SlippageExceeded);
+        None
+    };
+    let SwapQuote {
+        amount_out: token_1_out,
+        fee,
+        referral_fee,
+        ..
+    } = pool.quote_swap(token_0_amount, is_token_a, referral_fee_bps, now)?;
+    require_gt!(token_1_out, 0, AmmError::InsufficientLiquidity);
+    require_gte!(token_1_out, token_1_min_amount, AmmError::SlippageExceeded);
+    pool.check_launch_limits(token_1_out, now)?;
+
+    // Held until the token CPIs below are done
+    pool.locked = 1;
+    *ctx.accounts.liquidity_pool.load_mut()? = pool;
+
+    // Pay the referrer out of the input before it reaches the vault
+    if let Some(referrer_token_account) = &ctx.accounts.referrer_token_account {
+        if referral_fee > 0 {
+            ctx.accounts.deposit_token(
+                &ctx.accounts.token_0_mint,
+                &ctx.accounts.token_0_signer_token_account,
+                referrer_token_account,
+                referral_fee,
+            )?;
+        }
+    }
 
     // Deposit token_0 from user into vault
     ctx.accounts.deposit_token(
         &ctx.accounts.token_0_mint,
         &ctx.accounts.token_0_signer_token_account,
         &ctx.accounts.token_0_vault,
-        token_0_amount,
+        token_0_amount - referral_fee,
     )?;
 
     // Withdraw token_1 from vault to user
@@ -114,19 +177,86 @@ pub fn handler(
     ctx.accounts.token_1_vault.reload()?;
 
     // Sync reserves with actual vault balances
-    ctx.accounts.sync_reserves();
+    let (token_a_vault, token_b_vault) = if is_token_a {
+        (&ctx.accounts.token_0_vault, &ctx.accounts.token_1_vault)
+    } else {
+        (&ctx.accounts.token_1_vault, &ctx.accounts.token_0_vault)
+    };
+    pool.token_a_reserves = token_a_vault.amount;
+    pool.token_b_reserves = token_b_vault.amount;
+    pool.locked = 0;
+    if let Some(price_before) = price_before {
+        let price_after = pool.spot_price(now)?;
+        pool.record_price_move(price_before, price_after, now, Clock::get()?.slot)?;
+    }
+    *ctx.accounts.liquidity_pool.load_mut()? = pool;
+
+    // Reject trades that leave the pool too far from its reference oracle
+    if pool.max_oracle_deviation_bps > 0 {
+        let oracle_price = pool.load_oracle_price_wad(oracle, now)?;
+        check_oracle_deviation(
+            pool.spot_price(now)?,
+            oracle_price,
+            pool.max_oracle_deviation_bps,
+        )?;
+    }
+
+    ctx.accounts.pool_stats.record_swap(
+        is_token_a,
+        token_0_amount - referral_fee,
+        token_1_out,
+        pool.lp_fee(fee),
+        Clock::get()?.slot,
+    )?;
 
-    Ok(())
+    emit_cpi!(Swapped {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        user: ctx.accounts.signer.key(),
+        input_mint: ctx.accounts.token_0_mint.key(),
+        output_mint: ctx.accounts.token_1_mint.key(),
+        amount_in: token_0_amount,
+        amount_out: token_1_out,
+        fee,
+        referral_fee,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    });
+
+    Ok(SwapResult {
+        amount_in: token_0_amount,
+        amount_out: token_1_out,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    })
 }
 
 impl<'info> Swap<'info> {
+    /// Checks the arguments and the accounts against `pool`, the handler's copy of
+    /// `liquidity_pool`.
     pub fn validate(
         &self,
+        pool: &LiquidityPool,
         token_0_amount: u64,
         token_1_min_amount: u64,
         expiration: i64,
         is_token_a: bool,
     ) -> Result<()> {
+        require!(!pool.is_locked(), AmmError::PoolLocked);
+        require_keys_eq!(
+            self.token_0_vault.key(),
+            pool.vault(&self.token_0_mint.key())?,
+            ErrorCode::ConstraintAddress
+        );
+        require_keys_eq!(
+            self.token_1_vault.key(),
+            pool.vault(&self.token_1_mint.key())?,
+            ErrorCode::ConstraintAddress
+        );
+        require_keys_eq!(
+            self.lp_token_mint.key(),
+            pool.lp_mint,
+            ErrorCode::ConstraintAddress
+        );
         require!(
             token_0_amount > 0 || token_1_min_amount > 0,
             AmmError::ZeroAmount
@@ -144,18 +274,18 @@ impl<'info> Swap<'info> {
         if is_token_a {
             require_keys_eq!(
                 self.token_1_mint.key(),
-                self.liquidity_pool.token_b_mint,
+                pool.token_b_mint,
                 AmmError::MintMismatch
             );
         } else {
             require_keys_eq!(
                 self.token_0_mint.key(),
-                self.liquidity_pool.token_b_mint,
+                pool.token_b_mint,
                 AmmError::MintMismatch
             );
             require_keys_eq!(
                 self.token_1_mint.key(),
-                self.liquidity_pool.token_a_mint,
+                pool.token_a_mint,
                 AmmError::MintMismatch
             );
         }
@@ -193,16 +323,22 @@ impl<'info> VaultWithdrawer<'info> for Swap<'info> {
         &self.token_1_vault
     }
 
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool> {
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
         &self.liquidity_pool
     }
 }
 
-impl<'info> VaultDepositor<'info> for Swap<'info> {
+impl<'info> TokenDepositor<'info> for Swap<'info> {
     fn token_program(&self) -> &Program<'info, Token> {
         &self.token_program
     }
 
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> VaultDepositor<'info> for Swap<'info> {
     fn token_a_signer_token_account(&self) -> &Account<'info, TokenAccount> {
         &self.token_0_signer_token_account
     }
@@ -226,22 +362,4 @@ impl<'info> VaultDepositor<'info> for Swap<'info> {
     fn token_b_vault(&self) -> &Account<'info, TokenAccount> {
         &self.token_1_vault
     }
-
-    fn signer(&self) -> &Signer<'info> {
-        &self.signer
-    }
-}
-
-impl<'info> ReserveSyncer<'info> for Swap<'info> {
-    fn liquidity_pool(&mut self) -> &mut Account<'info, LiquidityPool> {
-        &mut self.liquidity_pool
-    }
-
-    fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
-        &self.token_0_vault
-    }
-
-    fn token_b_vault(&self) -> &Account<'info, TokenAccount> {
-        &self.token_1_vault
-    }
 }
@@ -0,0 +1,347 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{
+        compute_swap_step, mul_div, sqrt_price_at_tick, tick_at_sqrt_price,
+        ConcentratedVaultWithdrawer, TokenDepositor, VaultDepositor, MAX_SQRT_PRICE_X64,
+        MIN_SQRT_PRICE_X64, Q64,
+    },
+    ConcentratedPool, ConcentratedSwapped, TickArray, CONCENTRATED_POOL_SEED, MAX_TICK_INDEX,
+    MIN_TICK_INDEX, TICK_ARRAY_SIZE,
+};
+
+/// Swaps against a concentrated pool, crossing initialized ticks along the way.
+///
+/// The tick arrays the price may move through are passed in `tick_array_0..2`, starting
+/// with the one containing the current tick. The same account can be passed more than
+/// once; the swap simply stops (partially filled) once the price leaves the supplied range.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapConcentrated<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = token_0_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_0_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_1_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_1_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_0_mint,
+        associated_token::authority = concentrated_pool
+    )]
+    pub token_0_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_1_mint,
+        associated_token::authority = concentrated_pool
+    )]
+    pub token_1_vault: Box<Account<'info, TokenAccount>>,
+    pub token_0_mint: Box<Account<'info, Mint>>,
+    pub token_1_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [
+            CONCENTRATED_POOL_SEED.as_bytes(),
+            concentrated_pool.token_a_mint.as_ref(),
+            concentrated_pool.token_b_mint.as_ref(),
+            concentrated_pool.tick_spacing.to_le_bytes().as_ref()
+        ],
+        bump = concentrated_pool.bump
+    )]
+    pub concentrated_pool: Box<Account<'info, ConcentratedPool>>,
+    #[account(mut)]
+    pub tick_array_0: AccountLoader<'info, TickArray>,
+    #[account(mut)]
+    pub tick_array_1: AccountLoader<'info, TickArray>,
+    #[account(mut)]
+    pub tick_array_2: AccountLoader<'info, TickArray>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<SwapConcentrated>,
+    token_0_amount: u64,
+    token_1_min_amount: u64,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(token_0_amount, 0, AmmError::ZeroAmount);
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let a_to_b = ctx.accounts.validate_mints()?;
+
+    let pool = &ctx.accounts.concentrated_pool;
+    let fee_rate_bps = pool.fee_rate_bps;
+    let mut sqrt_price_x64 = pool.sqrt_price_x64;
+    let mut tick_current_index = pool.tick_current_index;
+    let mut liquidity = pool.liquidity;
+    let mut fee_growth_global_a_x64 = pool.fee_growth_global_a_x64;
+    let mut fee_growth_global_b_x64 = pool.fee_growth_global_b_x64;
+    let sqrt_price_limit_x64 = if a_to_b {
+        MIN_SQRT_PRICE_X64
+    } else {
+        MAX_SQRT_PRICE_X64
+    };
+
+    let mut amount_remaining = token_0_amount as u128;
+    let mut amount_out = 0u128;
+    let mut fee_total = 0u128;
+
+    while amount_remaining > 0 && sqrt_price_x64 != sqrt_price_limit_x64 {
+        let Some((next_tick_index, initialized)) =
+            ctx.accounts.next_tick(tick_current_index, a_to_b)?
+        else {
+            break;
+        };
+        let next_tick_index = next_tick_index.clamp(MIN_TICK_INDEX, MAX_TICK_INDEX);
+        let sqrt_price_target_x64 = sqrt_price_at_tick(next_tick_index)?;
+
+        let step = compute_swap_step(
+            sqrt_price_x64,
+            sqrt_price_target_x64,
+            liquidity,
+            amount_remaining,
+            fee_rate_bps,
+            a_to_b,
+        )?;
+        amount_remaining = amount_remaining
+            .checked_sub(step.amount_in + step.fee_amount)
+            .ok_or(MathError::Overflow)?;
+        amount_out = amount_out
+            .checked_add(step.amount_out)
+            .ok_or(MathError::Overflow)?;
+        fee_total += step.fee_amount;
+
+        // Fees are distributed to the liquidity active during this step
+        if liquidity > 0 {
+            let fee_growth = mul_div(step.fee_amount, Q64, liquidity, false)?;
+            if a_to_b {
+                fee_growth_global_a_x64 = fee_growth_global_a_x64.wrapping_add(fee_growth);
+            } else {
+                fee_growth_global_b_x64 = fee_growth_global_b_x64.wrapping_add(fee_growth);
+            }
+        }
+
+        if step.sqrt_price_next_x64 == sqrt_price_target_x64 {
+            if initialized {
+                let liquidity_net = ctx.accounts.cross_tick(
+                    next_tick_index,
+                    fee_growth_global_a_x64,
+                    fee_growth_global_b_x64,
+                )?;
+                // Moving down crosses ticks in reverse, so their net liquidity is negated
+                let liquidity_net = if a_to_b {
+                    liquidity_net.checked_neg().ok_or(MathError::Overflow)?
+                } else {
+                    liquidity_net
+                };
+                liquidity = liquidity
+                    .checked_add_signed(liquidity_net)
+                    .ok_or(MathError::Overflow)?;
+            }
+            tick_current_index = if a_to_b {
+                next_tick_index - 1
+            } else {
+                next_tick_index
+            };
+        } else if step.sqrt_price_next_x64 != sqrt_price_x64 {
+            tick_current_index = tick_at_sqrt_price(step.sqrt_price_next_x64)?;
+        }
+        sqrt_price_x64 = step.sqrt_price_next_x64;
+    }
+
+    let token_0_amount_in: u64 = (token_0_amount as u128 - amount_remaining)
+        .try_into()
+        .map_err(|_| MathError::Overflow)?;
+    let token_1_out: u64 = amount_out.try_into().map_err(|_| MathError::Overflow)?;
+    require_gt!(token_1_out, 0, AmmError::InsufficientLiquidity);
+    require_gte!(token_1_out, token_1_min_amount, AmmError::SlippageExceeded);
+
+    let pool = &mut ctx.accounts.concentrated_pool;
+    pool.sqrt_price_x64 = sqrt_price_x64;
+    pool.tick_current_index = tick_current_index;
+    pool.liquidity = liquidity;
+    pool.fee_growth_global_a_x64 = fee_growth_global_a_x64;
+    pool.fee_growth_global_b_x64 = fee_growth_global_b_x64;
+
+    ctx.accounts.deposit_token(
+        &ctx.accounts.token_0_mint,
+        &ctx.accounts.token_0_signer_token_account,
+        &ctx.accounts.token_0_vault,
+        token_0_amount_in,
+    )?;
+    ctx.accounts.withdraw_from_vault(
+        &ctx.accounts.token_1_mint,
+        &ctx.accounts.token_1_vault,
+        &ctx.accounts.token_1_signer_token_account,
+        token_1_out,
+    )?;
+
+    emit_cpi!(ConcentratedSwapped {
+        concentrated_pool: ctx.accounts.concentrated_pool.key(),
+        user: ctx.accounts.signer.key(),
+        input_mint: ctx.accounts.token_0_mint.key(),
+        output_mint: ctx.accounts.token_1_mint.key(),
+        amount_in: token_0_amount_in,
+        amount_out: token_1_out,
+        fee: fee_total as u64,
+        sqrt_price_x64,
+        tick_current_index,
+        liquidity,
+    });
+    Ok(())
+}
+
+impl<'info> SwapConcentrated<'info> {
+    /// Checks the mints match the pool and returns whether token A is the input.
+    pub fn validate_mints(&self) -> Result<bool> {
+        let token_0_mint = self.token_0_mint.key();
+        let token_1_mint = self.token_1_mint.key();
+        let pool = &self.concentrated_pool;
+        if token_0_mint == pool.token_a_mint && token_1_mint == pool.token_b_mint {
+            Ok(true)
+        } else if token_0_mint == pool.token_b_mint && token_1_mint == pool.token_a_mint {
+            Ok(false)
+        } else {
+            err!(AmmError::MintMismatch)
+        }
+    }
+
+    /// Returns the supplied tick array starting at `start_tick_index`, if any.
+    fn tick_array_at(
+        &self,
+        start_tick_index: i32,
+    ) -> Result<Option<&AccountLoader<'info, TickArray>>> {
+        for tick_array in [&self.tick_array_0, &self.tick_array_1, &self.tick_array_2] {
+            let loaded = tick_array.load()?;
+            require_keys_eq!(
+                loaded.concentrated_pool,
+                self.concentrated_pool.key(),
+                AmmError::InvalidTickArray
+            );
+            if loaded.start_tick_index == start_tick_index {
+                return Ok(Some(tick_array));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Finds the next tick the price moves to in the swap direction.
+    ///
+    /// Searches from the current tick towards lower ticks (`a_to_b`) or higher ticks,
+    /// stopping at the first initialized tick or the edge of the tick array.
+    ///
+    /// # Returns
+    /// `Some((tick_index, initialized))`, or `None` if the required tick array wasn't supplied
+    fn next_tick(&self, tick_current_index: i32, a_to_b: bool) -> Result<Option<(i32, bool)>> {
+        let tick_spacing = self.concentrated_pool.tick_spacing;
+        let spacing = tick_spacing as i32;
+        let search_start = if a_to_b {
+            tick_current_index.div_euclid(spacing) * spacing
+        } else {
+            (tick_current_index.div_euclid(spacing) + 1) * spacing
+        };
+        let start_tick_index = TickArray::start_tick_index_for(search_start, tick_spacing);
+        let Some(tick_array) = self.tick_array_at(start_tick_index)? else {
+            return Ok(None);
+        };
+        let tick_array = tick_array.load()?;
+
+        let mut offset = tick_array.tick_offset(search_start, tick_spacing)?;
+        loop {
+            if tick_array.ticks[offset].initialized != 0 {
+                return Ok(Some((start_tick_index + offset as i32 * spacing, true)));
+            }
+            let at_edge = if a_to_b {
+                offset == 0
+            } else {
+                offset == TICK_ARRAY_SIZE - 1
+            };
+            if at_edge {
+                return Ok(Some((start_tick_index + offset as i32 * spacing, false)));
+            }
+            if a_to_b {
+                offset -= 1;
+            } else {
+                offset += 1;
+            }
+        }
+    }
+
+    /// Crosses an initialized tick and returns its `liquidity_net`.
+    fn cross_tick(
+        &self,
+        tick_index: i32,
+        fee_growth_global_a_x64: u128,
+        fee_growth_global_b_x64: u128,
+    ) -> Result<i128> {
+        let tick_spacing = self.concentrated_pool.tick_spacing;
+        let start_tick_index = TickArray::start_tick_index_for(tick_index, tick_spacing);
+        let tick_array = self
+            .tick_array_at(start_tick_index)?
+            .ok_or(AmmError::InvalidTickArray)?;
+        let mut tick_array = tick_array.load_mut()?;
+        Ok(tick_array
+            .tick_mut(tick_index, tick_spacing)?
+            .cross(fee_growth_global_a_x64, fee_growth_global_b_x64))
+    }
+}
+
+impl<'info> TokenDepositor<'info> for SwapConcentrated<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> VaultDepositor<'info> for SwapConcentrated<'info> {
+    fn token_a_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.token_0_signer_token_account
+    }
+
+    fn token_b_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.token_1_signer_token_account
+    }
+
+    fn token_a_mint(&self) -> &Account<'info, Mint> {
+        &self.token_0_mint
+    }
+
+    fn token_b_mint(&self) -> &Account<'info, Mint> {
+        &self.token_1_mint
+    }
+
+    fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
+        &self.token_0_vault
+    }
+
+    fn token_b_vault(&self) -> &Account<'info, TokenAccount> {
+        &self.token_1_vault
+    }
+}
+
+impl<'info> ConcentratedVaultWithdrawer<'info> for SwapConcentrated<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn concentrated_pool(&self) -> &Account<'info, ConcentratedPool> {
+        &self.concentrated_pool
+    }
+}
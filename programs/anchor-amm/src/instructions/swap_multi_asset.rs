@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{get_weighted_amount_out, MultiAssetVaultWithdrawer, TokenDepositor},
+    MultiAssetPool, MultiAssetSwapped, MULTI_ASSET_POOL_SEED, MULTI_ASSET_SWAP_FEE_BPS,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapMultiAsset<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_in_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_out_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = multi_asset_pool
+    )]
+    pub token_in_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = multi_asset_pool
+    )]
+    pub token_out_vault: Box<Account<'info, TokenAccount>>,
+    pub token_in_mint: Box<Account<'info, Mint>>,
+    pub token_out_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [MULTI_ASSET_POOL_SEED.as_bytes(), multi_asset_pool.lp_token_mint.as_ref()],
+        bump = multi_asset_pool.bump
+    )]
+    pub multi_asset_pool: Box<Account<'info, MultiAssetPool>>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Swaps between any two tokens of a multi-asset pool using the weighted-product formula.
+pub fn handler(
+    ctx: Context<SwapMultiAsset>,
+    amount_in: u64,
+    min_amount_out: u64,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(amount_in, 0, AmmError::ZeroAmount);
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let pool = &ctx.accounts.multi_asset_pool;
+    let index_in = pool.token_index(&ctx.accounts.token_in_mint.key())?;
+    let index_out = pool.token_index(&ctx.accounts.token_out_mint.key())?;
+    require_neq!(index_in, index_out, AmmError::IdenticalMints);
+
+    let amount_in_with_fees = (amount_in as u128)
+        .checked_mul(10_000 - MULTI_ASSET_SWAP_FEE_BPS as u128)
+        .ok_or(MathError::Overflow)?
+        / 10_000;
+    let amount_out: u64 = get_weighted_amount_out(
+        amount_in_with_fees,
+        pool.reserves[index_in] as u128,
+        pool.reserves[index_out] as u128,
+        pool.weights[index_in],
+        pool.weights[index_out],
+    )?
+    .try_into()
+    .map_err(|_| MathError::Overflow)?;
+    require_gte!(amount_out, min_amount_out, AmmError::SlippageExceeded);
+    require_gt!(amount_out, 0, AmmError::InsufficientLiquidity);
+
+    ctx.accounts.deposit_token(
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_in_signer_token_account,
+        &ctx.accounts.token_in_vault,
+        amount_in,
+    )?;
+    ctx.accounts.withdraw_from_vault(
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_out_vault,
+        &ctx.accounts.token_out_signer_token_account,
+        amount_out,
+    )?;
+
+    let pool = &mut ctx.accounts.multi_asset_pool;
+    pool.reserves[index_in] = pool.reserves[index_in]
+        .checked_add(amount_in)
+        .ok_or(MathError::Overflow)?;
+    pool.reserves[index_out] -= amount_out;
+
+    emit_cpi!(MultiAssetSwapped {
+        multi_asset_pool: ctx.accounts.multi_asset_pool.key(),
+        user: ctx.accounts.signer.key(),
+        input_mint: ctx.accounts.token_in_mint.key(),
+        output_mint: ctx.accounts.token_out_mint.key(),
+        amount_in,
+        amount_out,
+        fee: amount_in - amount_in_with_fees as u64,
+    });
+    Ok(())
+}
+
+impl<'info> TokenDepositor<'info> for SwapMultiAsset<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> MultiAssetVaultWithdrawer<'info> for SwapMultiAsset<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool> {
+        &self.multi_asset_pool
+    }
+}
@@ -0,0 +1,248 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as sysvar_instructions};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::AmmError,
+    helpers::{check_no_opposite_swap, check_oracle_deviation, TokenDepositor, VaultWithdrawer},
+    CurveType, GlobalState, LiquidityPool, PoolStats, SwapQuote, SwapResult, Swapped,
+    GLOBAL_STATE_SEED, MAX_REFERRAL_FEE_BPS, PAUSE_SWAP, POOL_STATS_SEED,
+};
+
+/// `Swap` without the accounts it never uses, for routers packing many swaps.
+///
+/// The vaults and mints are checked against the pool rather than re-derived, and
+/// the signer's token accounts are left to the token program, which checks their
+/// mints and the signer's authority over the input account.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapV2<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub token_a_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_b_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump = pool_stats.bump
+    )]
+    pub pool_stats: Box<Account<'info, PoolStats>>,
+    #[account(seeds = [GLOBAL_STATE_SEED.as_bytes()], bump = global_state.bump)]
+    pub global_state: Box<Account<'info, GlobalState>>,
+    pub token_program: Program<'info, Token>,
+    /// Receives the referral fee, in the input mint
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// CHECK: the instructions sysvar, by address. Passing it opts the swap into
+    /// `check_no_opposite_swap`.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+/// Swaps an exact `amount_in` of token A for token B if `is_token_a_in`, and of
+/// token B for token A otherwise, failing unless at least `min_amount_out` comes out.
+///
+/// Prices, fees, referrals and oracles work as in `swap`. Passing the instructions
+/// sysvar rejects the swap if its transaction also swaps the pool the other way.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SwapV2<'info>>,
+    amount_in: u64,
+    min_amount_out: u64,
+    is_token_a_in: bool,
+    expiration: i64,
+    referral_fee_bps: u16,
+) -> Result<SwapResult> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut pool = *ctx.accounts.liquidity_pool.load()?;
+    pool.check_not_paused(&ctx.accounts.global_state, PAUSE_SWAP)?;
+    require!(amount_in > 0, AmmError::ZeroAmount);
+    require_gt!(expiration, now, AmmError::DeadlineExceeded);
+    require!(
+        referral_fee_bps <= MAX_REFERRAL_FEE_BPS
+            && (referral_fee_bps == 0 || ctx.accounts.referrer_token_account.is_some()),
+        AmmError::InvalidReferralFee
+    );
+    if let Some(instructions_sysvar) = &ctx.accounts.instructions_sysvar {
+        check_no_opposite_swap(
+            instructions_sysvar,
+            &ctx.accounts.liquidity_pool.key(),
+            &pool.token_a_mint,
+            is_token_a_in,
+        )?;
+    }
+    if pool.curve_type() == CurveType::OraclePmm {
+        let oracle_price = pool.load_oracle_price_wad(ctx.remaining_accounts.first(), now)?;
+        pool.refresh_pmm_targets(oracle_price)?;
+    }
+
+    let price_before = if pool.tracks_price_moves() {
+        Some(pool.spot_price(now)?)
+    } else {
+        None
+    };
+    let SwapQuote {
+        amount_out,
+        fee,
+        referral_fee,
+        ..
+    } = pool.quote_swap(amount_in, is_token_a_in, referral_fee_bps, now)?;
+    require_gt!(amount_out, 0, AmmError::InsufficientLiquidity);
+    require_gte!(amount_out, min_amount_out, AmmError::SlippageExceeded);
+    pool.check_launch_limits(amount_out, now)?;
+
+    // Held until the token CPIs below are done
+    pool.locked = 1;
+    *ctx.accounts.liquidity_pool.load_mut()? = pool;
+
+    let accounts = &ctx.accounts;
+    let (input_mint, input_account, input_vault, output_mint, output_vault, output_account) =
+        if is_token_a_in {
+            (
+                &accounts.token_a_mint,
+                &accounts.token_a_signer_token_account,
+                &accounts.token_a_vault,
+                &accounts.token_b_mint,
+                &accounts.token_b_vault,
+                &accounts.token_b_signer_token_account,
+            )
+        } else {
+            (
+                &accounts.token_b_mint,
+                &accounts.token_b_signer_token_account,
+                &accounts.token_b_vault,
+                &accounts.token_a_mint,
+                &accounts.token_a_vault,
+                &accounts.token_a_signer_token_account,
+            )
+        };
+
+    // Pay the referrer out of the input before it reaches the vault
+    if let Some(referrer_token_account) = &accounts.referrer_token_account {
+        if referral_fee > 0 {
+            accounts.deposit_token(
+                input_mint,
+                input_account,
+                referrer_token_account,
+                referral_fee,
+            )?;
+        }
+    }
+    accounts.deposit_token(
+        input_mint,
+        input_account,
+        input_vault,
+        amount_in - referral_fee,
+    )?;
+    accounts.withdraw_token(output_mint, output_vault, output_account, amount_out)?;
+    let input_mint = input_mint.key();
+    let output_mint = output_mint.key();
+
+    // Reload vault accounts to get updated balances after transfers
+    ctx.accounts.token_a_vault.reload()?;
+    ctx.accounts.token_b_vault.reload()?;
+    pool.token_a_reserves = ctx.accounts.token_a_vault.amount;
+    pool.token_b_reserves = ctx.accounts.token_b_vault.amount;
+    pool.locked = 0;
+    if let Some(price_before) = price_before {
+        let price_after = pool.spot_price(now)?;
+        pool.record_price_move(price_before, price_after, now, Clock::get()?.slot)?;
+    }
+    *ctx.accounts.liquidity_pool.load_mut()? = pool;
+
+    // Reject trades that leave the pool too far from its reference oracle
+    if pool.max_oracle_deviation_bps > 0 {
+        let oracle_price = pool.load_oracle_price_wad(ctx.remaining_accounts.first(), now)?;
+        check_oracle_deviation(
+            pool.spot_price(now)?,
+            oracle_price,
+            pool.max_oracle_deviation_bps,
+        )?;
+    }
+
+    ctx.accounts.pool_stats.record_swap(
+        is_token_a_in,
+        amount_in - referral_fee,
+        amount_out,
+        pool.lp_fee(fee),
+        Clock::get()?.slot,
+    )?;
+
+    emit_cpi!(Swapped {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        user: ctx.accounts.signer.key(),
+        input_mint,
+        output_mint,
+        amount_in,
+        amount_out,
+        fee,
+        referral_fee,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    });
+
+    Ok(SwapResult {
+        amount_in,
+        amount_out,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    })
+}
+
+impl<'info> TokenDepositor<'info> for SwapV2<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> VaultWithdrawer<'info> for SwapV2<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn token_a_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.token_a_signer_token_account
+    }
+
+    fn token_b_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.token_b_signer_token_account
+    }
+
+    fn token_a_mint(&self) -> &Account<'info, Mint> {
+        &self.token_a_mint
+    }
+
+    fn token_b_mint(&self) -> &Account<'info, Mint> {
+        &self.token_b_mint
+    }
+
+    fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
+        &self.token_a_vault
+    }
+
+    fn token_b_vault(&self) -> &Account<'info, TokenAccount> {
+        &self.token_b_vault
+    }
+
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
+        &self.liquidity_pool
+    }
+}
@@ -1,15 +1,19 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::{helpers::ReserveSyncer, LiquidityPool, LIQUIDITY_POOL_SEED};
+use crate::{
+    error::AmmError, helpers::ReserveSyncer, LiquidityPool, ReservesSynced, LIQUIDITY_POOL_SEED,
+};
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SyncReserves<'info> {
     #[account(
         mut,
         seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
-        bump
+        bump,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
     )]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
     pub token_a_mint: Account<'info, Mint>,
     pub token_b_mint: Account<'info, Mint>,
     #[account(
@@ -28,13 +32,19 @@ pub struct SyncReserves<'info> {
 }
 
 pub fn handler(ctx: Context<SyncReserves>) -> Result<()> {
-    ctx.accounts.sync_reserves();
+    ctx.accounts.sync_reserves()?;
+    let pool = *ctx.accounts.liquidity_pool.load()?;
+    emit_cpi!(ReservesSynced {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    });
     Ok(())
 }
 
 impl<'info> ReserveSyncer<'info> for SyncReserves<'info> {
-    fn liquidity_pool(&mut self) -> &mut Account<'info, LiquidityPool> {
-        &mut self.liquidity_pool
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
+        &self.liquidity_pool
     }
     fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
         &self.token_a_vault
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, CreatorTransferred, CurveType, LiquidityPool};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferCreator<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        has_one = creator @ AmmError::Unauthorized,
+        constraint = liquidity_pool.load()?.curve_type() != CurveType::OraclePmm
+            @ AmmError::UnsupportedCurveOperation,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
+    )]
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
+}
+
+/// Hands the creator role, including any unclaimed creator fees, to `new_creator`.
+pub fn handler(ctx: Context<TransferCreator>, new_creator: Pubkey) -> Result<()> {
+    let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+    let old_creator = pool.creator;
+    pool.creator = new_creator;
+    drop(pool);
+
+    emit_cpi!(CreatorTransferred {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        old_creator,
+        new_creator,
+    });
+    Ok(())
+}
@@ -6,12 +6,12 @@ use anchor_spl::{
 
 use crate::{
     error::{AmmError, MathError},
-    helpers::{
-        get_withdraw_amount, LPBurner, LPMinter, ProtocolFeeMinter, ReserveSyncer, VaultWithdrawer,
-    },
-    LiquidityPool, LIQUIDITY_POOL_SEED,
+    helpers::{FeeMint, LPBurner, LPMinter, ProtocolFeeMinter, ReserveSyncer, VaultWithdrawer},
+    GlobalState, LiquidityPool, LiquidityRemoved, PoolStats, ProtocolFeeMinted, GLOBAL_STATE_SEED,
+    LIQUIDITY_POOL_SEED, PAUSE_WITHDRAW, POOL_STATS_SEED,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(mut)]
@@ -37,78 +37,103 @@ pub struct Withdraw<'info> {
         associated_token::token_program = token_program
     )]
     pub token_b_signer_token_account: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        associated_token::mint = token_a_mint,
-        associated_token::authority = liquidity_pool
-    )]
+    #[account(mut)]
     pub token_a_vault: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        associated_token::mint = token_b_mint,
-        associated_token::authority = liquidity_pool
-    )]
+    #[account(mut)]
     pub token_b_vault: Account<'info, TokenAccount>,
     pub token_a_mint: Account<'info, Mint>,
     pub token_b_mint: Account<'info, Mint>,
-    #[account(
-        mut,
-        seeds = [b"lp_token_mint", token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
-        bump
-    )]
+    #[account(mut, address = liquidity_pool.load()?.lp_mint)]
     pub lp_token_mint: Account<'info, Mint>,
     #[account(
         mut,
         seeds = [LIQUIDITY_POOL_SEED.as_bytes(), token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
-        bump
+        bump = liquidity_pool.load()?.bump,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        has_one = fee_lp_token_account,
+        constraint = !liquidity_pool.load()?.is_locked() @ AmmError::PoolLocked
     )]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
+    pub liquidity_pool: AccountLoader<'info, LiquidityPool>,
     #[account(
         mut,
-        associated_token::mint = lp_token_mint,
-        associated_token::authority = liquidity_pool,
-        associated_token::token_program = token_program
+        seeds = [POOL_STATS_SEED.as_bytes(), liquidity_pool.key().as_ref()],
+        bump = pool_stats.bump
     )]
+    pub pool_stats: Box<Account<'info, PoolStats>>,
+    #[account(seeds = [GLOBAL_STATE_SEED.as_bytes()], bump = global_state.bump)]
+    pub global_state: Box<Account<'info, GlobalState>>,
+    #[account(mut)]
     pub fee_lp_token_account: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
+
+/// Withdrawal outcome, written as return data so CPI callers don't have to diff balances.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawResult {
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub lp_tokens_burned: u64,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+}
+
 pub fn handler(
     ctx: Context<Withdraw>,
     lp_amount_to_burn: u64,
     amount_a_min: u64,
     amount_b_min: u64,
     expiration: i64,
-) -> Result<()> {
+) -> Result<WithdrawResult> {
+    let now = Clock::get()?.unix_timestamp;
+    let lp_mint_bump = {
+        let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+        pool.check_not_paused(&ctx.accounts.global_state, PAUSE_WITHDRAW)?;
+        pool.check_lbp_liquidity_change(&ctx.accounts.signer.key(), true, now)?;
+        // Held until the last token CPI below is done
+        pool.locked = 1;
+        pool.lp_mint_bump
+    };
     require!(lp_amount_to_burn > 0, AmmError::ZeroAmount);
-    require_gt!(
-        expiration,
-        Clock::get()?.unix_timestamp,
-        AmmError::DeadlineExceeded,
-    );
+    require_gt!(expiration, now, AmmError::DeadlineExceeded);
 
     // Mint protocol fees before removing liquidity
-    ctx.accounts.mint_protocol_fee(ctx.bumps.lp_token_mint)?;
+    let fee_mint = ctx.accounts.mint_protocol_fee(lp_mint_bump)?;
+    if fee_mint != FeeMint::default() {
+        ctx.accounts
+            .pool_stats
+            .record_protocol_fee(fee_mint.protocol_lp_tokens)?;
+        let pool = {
+            let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+            pool.creator_fee_lp_tokens = pool
+                .creator_fee_lp_tokens
+                .checked_add(fee_mint.creator_lp_tokens)
+                .ok_or(MathError::Overflow)?;
+            *pool
+        };
+        emit_cpi!(ProtocolFeeMinted {
+            liquidity_pool: ctx.accounts.liquidity_pool.key(),
+            lp_tokens_minted: fee_mint.protocol_lp_tokens,
+            creator_lp_tokens_minted: fee_mint.creator_lp_tokens,
+            token_a_reserves: pool.token_a_reserves,
+            token_b_reserves: pool.token_b_reserves,
+        });
+    }
     ctx.accounts.lp_token_mint.reload()?;
     let lp_supply = ctx.accounts.lp_token_mint.supply as u128;
     let lp_amount = lp_amount_to_burn as u128;
 
-    let token_a_out: u64 = get_withdraw_amount(
-        ctx.accounts.liquidity_pool.token_a_reserves as u128,
-        lp_amount,
-        lp_supply,
-    )?
-    .try_into()
-    .map_err(|_| MathError::Overflow)?;
-
-    let token_b_out: u64 = get_withdraw_amount(
-        ctx.accounts.liquidity_pool.token_b_reserves as u128,
+    let pool_before = *ctx.accounts.liquidity_pool.load()?;
+    let (token_a_out, token_b_out) = pool_before.swap_curve(now).withdraw_amounts(
         lp_amount,
+        pool_before.token_a_reserves as u128,
+        pool_before.token_b_reserves as u128,
         lp_supply,
-    )?
-    .try_into()
-    .map_err(|_| MathError::Overflow)?;
+    )?;
+    let token_a_out: u64 = token_a_out.try_into().map_err(|_| MathError::Overflow)?;
+    let token_b_out: u64 = token_b_out.try_into().map_err(|_| MathError::Overflow)?;
 
     require!(
         token_a_out >= amount_a_min && token_b_out >= amount_b_min,
@@ -126,14 +151,35 @@ pub fn handler(
     // Reload vaults and sync reserves
     ctx.accounts.token_a_vault.reload()?;
     ctx.accounts.token_b_vault.reload()?;
-    ctx.accounts.sync_reserves();
+    ctx.accounts.sync_reserves()?;
+    let pool = {
+        let mut pool = ctx.accounts.liquidity_pool.load_mut()?;
+        pool.scale_pmm_targets(pool_before.token_a_reserves, pool_before.token_b_reserves)?;
+
+        // Update k_last for protocol fee tracking
+        pool.k_last = pool.invariant(now)?;
+        pool.locked = 0;
+        *pool
+    };
+    ctx.accounts.pool_stats.record_withdraw()?;
 
-    // Update k_last for protocol fee tracking
-    ctx.accounts.liquidity_pool.k_last = (ctx.accounts.liquidity_pool.token_a_reserves as u128)
-        .checked_mul(ctx.accounts.liquidity_pool.token_b_reserves as u128)
-        .ok_or(MathError::Overflow)?;
+    emit_cpi!(LiquidityRemoved {
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        user: ctx.accounts.signer.key(),
+        token_a_amount: token_a_out,
+        token_b_amount: token_b_out,
+        lp_tokens_burned: lp_amount_to_burn,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    });
 
-    Ok(())
+    Ok(WithdrawResult {
+        token_a_amount: token_a_out,
+        token_b_amount: token_b_out,
+        lp_tokens_burned: lp_amount_to_burn,
+        token_a_reserves: pool.token_a_reserves,
+        token_b_reserves: pool.token_b_reserves,
+    })
 }
 impl<'info> LPBurner<'info> for Withdraw<'info> {
     fn token_program(&self) -> &Program<'info, Token> {
@@ -181,14 +227,14 @@ impl<'info> VaultWithdrawer<'info> for Withdraw<'info> {
         &self.token_b_vault
     }
 
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool> {
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
         &self.liquidity_pool
     }
 }
 
 impl<'info> ReserveSyncer<'info> for Withdraw<'info> {
-    fn liquidity_pool(&mut self) -> &mut Account<'info, LiquidityPool> {
-        &mut self.liquidity_pool
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
+        &self.liquidity_pool
     }
 
     fn token_a_vault(&self) -> &Account<'info, TokenAccount> {
@@ -223,7 +269,7 @@ impl<'info> ProtocolFeeMinter<'info> for Withdraw<'info> {
         &self.fee_lp_token_account
     }
 
-    fn liquidity_pool(&self) -> &Account<'info, LiquidityPool> {
+    fn liquidity_pool(&self) -> &AccountLoader<'info, LiquidityPool> {
         &self.liquidity_pool
     }
 }
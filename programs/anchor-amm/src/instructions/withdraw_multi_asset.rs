@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{get_withdraw_amount, LPBurner, MultiAssetVaultWithdrawer},
+    MultiAssetLiquidityRemoved, MultiAssetPool, MULTI_ASSET_POOL_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawMultiAsset<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub lp_token_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = multi_asset_pool.lp_token_mint)]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [MULTI_ASSET_POOL_SEED.as_bytes(), multi_asset_pool.lp_token_mint.as_ref()],
+        bump = multi_asset_pool.bump
+    )]
+    pub multi_asset_pool: Box<Account<'info, MultiAssetPool>>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burns LP tokens for a proportional share of every pool token.
+///
+/// `remaining_accounts` holds `[mint, signer token account, vault]` for each token
+/// in pool order; `min_amounts` follows the same order.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawMultiAsset<'info>>,
+    lp_amount: u64,
+    min_amounts: Vec<u64>,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(lp_amount, 0, AmmError::ZeroAmount);
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let pool = &ctx.accounts.multi_asset_pool;
+    require_eq!(
+        min_amounts.len(),
+        pool.token_count as usize,
+        AmmError::InvalidTokenCount
+    );
+    let token_accounts = pool.load_token_accounts(ctx.remaining_accounts)?;
+    let lp_supply = ctx.accounts.lp_token_mint.supply as u128;
+
+    let amounts = pool
+        .reserves()
+        .iter()
+        .zip(&min_amounts)
+        .map(|(reserve, min_amount)| {
+            let amount: u64 = get_withdraw_amount(*reserve as u128, lp_amount as u128, lp_supply)?
+                .try_into()
+                .map_err(|_| MathError::Overflow)?;
+            require_gte!(amount, *min_amount, AmmError::SlippageExceeded);
+            Ok(amount)
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    require!(
+        amounts.iter().any(|amount| *amount > 0),
+        AmmError::InsufficientLiquidity
+    );
+
+    ctx.accounts.burn_lp_tokens(lp_amount)?;
+    for (accounts, amount) in token_accounts.iter().zip(&amounts) {
+        ctx.accounts.withdraw_from_vault(
+            &accounts.mint,
+            &accounts.vault,
+            &accounts.signer_token_account,
+            *amount,
+        )?;
+    }
+    let pool = &mut ctx.accounts.multi_asset_pool;
+    for (reserve, amount) in pool.reserves.iter_mut().zip(&amounts) {
+        *reserve -= amount;
+    }
+
+    emit_cpi!(MultiAssetLiquidityRemoved {
+        multi_asset_pool: ctx.accounts.multi_asset_pool.key(),
+        user: ctx.accounts.signer.key(),
+        amounts,
+        lp_tokens_burned: lp_amount,
+    });
+    Ok(())
+}
+
+impl<'info> LPBurner<'info> for WithdrawMultiAsset<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn lp_token_mint(&self) -> &Account<'info, Mint> {
+        &self.lp_token_mint
+    }
+
+    fn lp_token_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.lp_token_signer_token_account
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> MultiAssetVaultWithdrawer<'info> for WithdrawMultiAsset<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool> {
+        &self.multi_asset_pool
+    }
+}
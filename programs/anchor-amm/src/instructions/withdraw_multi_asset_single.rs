@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{get_single_asset_withdraw_amount, LPBurner, MultiAssetVaultWithdrawer},
+    MultiAssetLiquidityRemoved, MultiAssetPool, MULTI_ASSET_POOL_SEED, MULTI_ASSET_SWAP_FEE_BPS,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawMultiAssetSingle<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub token_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = multi_asset_pool
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    pub token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub lp_token_signer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = multi_asset_pool.lp_token_mint)]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [MULTI_ASSET_POOL_SEED.as_bytes(), multi_asset_pool.lp_token_mint.as_ref()],
+        bump = multi_asset_pool.bump
+    )]
+    pub multi_asset_pool: Box<Account<'info, MultiAssetPool>>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burns LP tokens for a single pool token, priced against the weighted invariant.
+pub fn handler(
+    ctx: Context<WithdrawMultiAssetSingle>,
+    lp_amount: u64,
+    min_amount_out: u64,
+    expiration: i64,
+) -> Result<()> {
+    require_gt!(lp_amount, 0, AmmError::ZeroAmount);
+    require_gt!(
+        expiration,
+        Clock::get()?.unix_timestamp,
+        AmmError::DeadlineExceeded,
+    );
+    let pool = &ctx.accounts.multi_asset_pool;
+    let index = pool.token_index(&ctx.accounts.token_mint.key())?;
+    let amount_out: u64 = get_single_asset_withdraw_amount(
+        lp_amount as u128,
+        pool.reserves[index] as u128,
+        pool.weights[index],
+        ctx.accounts.lp_token_mint.supply as u128,
+        MULTI_ASSET_SWAP_FEE_BPS,
+    )?
+    .try_into()
+    .map_err(|_| MathError::Overflow)?;
+    require_gte!(amount_out, min_amount_out, AmmError::SlippageExceeded);
+    require_gt!(amount_out, 0, AmmError::InsufficientLiquidity);
+
+    ctx.accounts.burn_lp_tokens(lp_amount)?;
+    ctx.accounts.withdraw_from_vault(
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.token_signer_token_account,
+        amount_out,
+    )?;
+    ctx.accounts.multi_asset_pool.reserves[index] -= amount_out;
+
+    let mut amounts = vec![0; ctx.accounts.multi_asset_pool.token_count as usize];
+    amounts[index] = amount_out;
+    emit_cpi!(MultiAssetLiquidityRemoved {
+        multi_asset_pool: ctx.accounts.multi_asset_pool.key(),
+        user: ctx.accounts.signer.key(),
+        amounts,
+        lp_tokens_burned: lp_amount,
+    });
+    Ok(())
+}
+
+impl<'info> LPBurner<'info> for WithdrawMultiAssetSingle<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn lp_token_mint(&self) -> &Account<'info, Mint> {
+        &self.lp_token_mint
+    }
+
+    fn lp_token_signer_token_account(&self) -> &Account<'info, TokenAccount> {
+        &self.lp_token_signer_token_account
+    }
+
+    fn signer(&self) -> &Signer<'info> {
+        &self.signer
+    }
+}
+
+impl<'info> MultiAssetVaultWithdrawer<'info> for WithdrawMultiAssetSingle<'info> {
+    fn token_program(&self) -> &Program<'info, Token> {
+        &self.token_program
+    }
+
+    fn multi_asset_pool(&self) -> &Account<'info, MultiAssetPool> {
+        &self.multi_asset_pool
+    }
+}
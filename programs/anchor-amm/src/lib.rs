@@ -1,10 +1,12 @@
 pub mod constants;
 pub mod error;
+pub mod events;
 mod helpers;
 pub mod instructions;
 pub mod state;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -15,8 +17,18 @@ pub mod anchor_amm {
 
     use super::*;
     #[instruction(discriminator = 1)]
-    pub fn initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
-        initialize_pool::handler(ctx)
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        creator_fee_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<()> {
+        initialize_pool::handler(
+            ctx,
+            creator_fee_bps,
+            deposit_fee_bps,
+            deposit_fee_to_protocol,
+        )
     }
     #[instruction(discriminator = 2)]
     pub fn deposit(
@@ -26,7 +38,7 @@ pub mod anchor_amm {
         token_a_amount_min: u64,
         token_b_amount_min: u64,
         expiration: i64,
-    ) -> Result<()> {
+    ) -> Result<DepositResult> {
         deposit::handler(
             ctx,
             token_a_amount_desired,
@@ -37,13 +49,20 @@ pub mod anchor_amm {
         )
     }
     #[instruction(discriminator = 3)]
-    pub fn swap(
-        ctx: Context<Swap>,
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Swap<'info>>,
         token_0_amount: u64,
         token_1_min_amount: u64,
         expiration: i64,
-    ) -> Result<()> {
-        swap::handler(ctx, token_0_amount, token_1_min_amount, expiration)
+        referral_fee_bps: u16,
+    ) -> Result<SwapResult> {
+        swap::handler(
+            ctx,
+            token_0_amount,
+            token_1_min_amount,
+            expiration,
+            referral_fee_bps,
+        )
     }
     #[instruction(discriminator = 4)]
     pub fn withdraw(
@@ -52,7 +71,7 @@ pub mod anchor_amm {
         amount_a_min: u64,
         amount_b_min: u64,
         expiration: i64,
-    ) -> Result<()> {
+    ) -> Result<WithdrawResult> {
         withdraw::handler(
             ctx,
             lp_amount_to_burn,
@@ -69,4 +88,345 @@ pub mod anchor_amm {
     pub fn skim(ctx: Context<SkimReserves>) -> Result<()> {
         skim_reserves::handler(ctx)
     }
+    #[instruction(discriminator = 7)]
+    pub fn initialize_concentrated_pool(
+        ctx: Context<InitializeConcentratedPool>,
+        tick_spacing: u16,
+        fee_rate_bps: u16,
+        initial_sqrt_price_x64: u128,
+    ) -> Result<()> {
+        initialize_concentrated_pool::handler(
+            ctx,
+            tick_spacing,
+            fee_rate_bps,
+            initial_sqrt_price_x64,
+        )
+    }
+    #[instruction(discriminator = 8)]
+    pub fn initialize_tick_array(
+        ctx: Context<InitializeTickArray>,
+        start_tick_index: i32,
+    ) -> Result<()> {
+        initialize_tick_array::handler(ctx, start_tick_index)
+    }
+    #[instruction(discriminator = 9)]
+    pub fn open_position(
+        ctx: Context<OpenPosition>,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        open_position::handler(ctx, tick_lower_index, tick_upper_index)
+    }
+    #[instruction(discriminator = 10)]
+    pub fn increase_liquidity(
+        ctx: Context<IncreaseLiquidity>,
+        liquidity_amount: u128,
+        token_a_amount_max: u64,
+        token_b_amount_max: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        increase_liquidity::handler(
+            ctx,
+            liquidity_amount,
+            token_a_amount_max,
+            token_b_amount_max,
+            expiration,
+        )
+    }
+    #[instruction(discriminator = 11)]
+    pub fn decrease_liquidity(
+        ctx: Context<DecreaseLiquidity>,
+        liquidity_amount: u128,
+        token_a_amount_min: u64,
+        token_b_amount_min: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        decrease_liquidity::handler(
+            ctx,
+            liquidity_amount,
+            token_a_amount_min,
+            token_b_amount_min,
+            expiration,
+        )
+    }
+    #[instruction(discriminator = 12)]
+    pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
+        collect_fees::handler(ctx)
+    }
+    #[instruction(discriminator = 13)]
+    pub fn swap_concentrated(
+        ctx: Context<SwapConcentrated>,
+        token_0_amount: u64,
+        token_1_min_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        swap_concentrated::handler(ctx, token_0_amount, token_1_min_amount, expiration)
+    }
+    #[instruction(discriminator = 14)]
+    pub fn initialize_stable_pool(
+        ctx: Context<InitializePool>,
+        amp: u64,
+        creator_fee_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<()> {
+        initialize_stable_pool::handler(
+            ctx,
+            amp,
+            creator_fee_bps,
+            deposit_fee_bps,
+            deposit_fee_to_protocol,
+        )
+    }
+    #[instruction(discriminator = 15)]
+    pub fn ramp_amp(ctx: Context<RampAmp>, amp_target: u64, ramp_stop_ts: i64) -> Result<()> {
+        ramp_amp::handler(ctx, amp_target, ramp_stop_ts)
+    }
+    #[instruction(discriminator = 16)]
+    pub fn stop_ramp_amp(ctx: Context<RampAmp>) -> Result<()> {
+        stop_ramp_amp::handler(ctx)
+    }
+    #[instruction(discriminator = 17)]
+    pub fn initialize_weighted_pool(
+        ctx: Context<InitializePool>,
+        weight_a: u16,
+        weight_b: u16,
+        creator_fee_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<()> {
+        initialize_weighted_pool::handler(
+            ctx,
+            weight_a,
+            weight_b,
+            creator_fee_bps,
+            deposit_fee_bps,
+            deposit_fee_to_protocol,
+        )
+    }
+    #[instruction(discriminator = 18)]
+    pub fn initialize_multi_asset_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeMultiAssetPool<'info>>,
+        weights: Vec<u16>,
+    ) -> Result<()> {
+        initialize_multi_asset_pool::handler(ctx, weights)
+    }
+    #[instruction(discriminator = 19)]
+    pub fn swap_multi_asset(
+        ctx: Context<SwapMultiAsset>,
+        amount_in: u64,
+        min_amount_out: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        swap_multi_asset::handler(ctx, amount_in, min_amount_out, expiration)
+    }
+    #[instruction(discriminator = 20)]
+    pub fn deposit_multi_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositMultiAsset<'info>>,
+        lp_amount: u64,
+        max_amounts: Vec<u64>,
+        expiration: i64,
+    ) -> Result<()> {
+        deposit_multi_asset::handler(ctx, lp_amount, max_amounts, expiration)
+    }
+    #[instruction(discriminator = 21)]
+    pub fn deposit_multi_asset_single(
+        ctx: Context<DepositMultiAssetSingle>,
+        amount_in: u64,
+        min_lp_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        deposit_multi_asset_single::handler(ctx, amount_in, min_lp_amount, expiration)
+    }
+    #[instruction(discriminator = 22)]
+    pub fn withdraw_multi_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawMultiAsset<'info>>,
+        lp_amount: u64,
+        min_amounts: Vec<u64>,
+        expiration: i64,
+    ) -> Result<()> {
+        withdraw_multi_asset::handler(ctx, lp_amount, min_amounts, expiration)
+    }
+    #[instruction(discriminator = 23)]
+    pub fn withdraw_multi_asset_single(
+        ctx: Context<WithdrawMultiAssetSingle>,
+        lp_amount: u64,
+        min_amount_out: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        withdraw_multi_asset_single::handler(ctx, lp_amount, min_amount_out, expiration)
+    }
+    #[instruction(discriminator = 24)]
+    pub fn initialize_constant_sum_pool(
+        ctx: Context<InitializePool>,
+        price_band_bps: u16,
+        creator_fee_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<()> {
+        initialize_constant_sum_pool::handler(
+            ctx,
+            price_band_bps,
+            creator_fee_bps,
+            deposit_fee_bps,
+            deposit_fee_to_protocol,
+        )
+    }
+    #[instruction(discriminator = 25)]
+    pub fn set_price_band(ctx: Context<SetPriceBand>, price_band_bps: u16) -> Result<()> {
+        set_price_band::handler(ctx, price_band_bps)
+    }
+    #[instruction(discriminator = 26)]
+    pub fn initialize_pmm_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializePool<'info>>,
+        k: u64,
+        oracle_max_age: u64,
+        oracle_max_confidence_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<()> {
+        initialize_pmm_pool::handler(
+            ctx,
+            k,
+            oracle_max_age,
+            oracle_max_confidence_bps,
+            deposit_fee_bps,
+            deposit_fee_to_protocol,
+        )
+    }
+    #[instruction(discriminator = 27)]
+    pub fn set_reference_oracle(
+        ctx: Context<SetReferenceOracle>,
+        max_deviation_bps: u16,
+        max_age: u64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        set_reference_oracle::handler(ctx, max_deviation_bps, max_age, max_confidence_bps)
+    }
+    #[instruction(discriminator = 28)]
+    pub fn set_dynamic_fee(
+        ctx: Context<SetDynamicFee>,
+        volatility_fee_factor_bps: u16,
+        max_swap_fee_bps: u16,
+        volatility_half_life: i64,
+    ) -> Result<()> {
+        set_dynamic_fee::handler(
+            ctx,
+            volatility_fee_factor_bps,
+            max_swap_fee_bps,
+            volatility_half_life,
+        )
+    }
+    #[instruction(discriminator = 29)]
+    pub fn quote_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, QuoteSwap<'info>>,
+        amount_in: u64,
+        is_token_a_in: bool,
+        referral_fee_bps: u16,
+    ) -> Result<SwapQuote> {
+        quote_swap::handler(ctx, amount_in, is_token_a_in, referral_fee_bps)
+    }
+    #[instruction(discriminator = 30)]
+    pub fn set_launch_params(
+        ctx: Context<SetLaunchParams>,
+        trading_starts_at: i64,
+        launch_fee_bps: u16,
+        launch_fee_duration: i64,
+        launch_max_buy: u64,
+    ) -> Result<()> {
+        set_launch_params::handler(
+            ctx,
+            trading_starts_at,
+            launch_fee_bps,
+            launch_fee_duration,
+            launch_max_buy,
+        )
+    }
+    #[instruction(discriminator = 31)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_lbp(
+        ctx: Context<InitializePool>,
+        start_weight_a: u16,
+        end_weight_a: u16,
+        start_ts: i64,
+        end_ts: i64,
+        creator_fee_bps: u16,
+        deposit_fee_bps: u16,
+        deposit_fee_to_protocol: bool,
+    ) -> Result<()> {
+        initialize_lbp::handler(
+            ctx,
+            start_weight_a,
+            end_weight_a,
+            start_ts,
+            end_ts,
+            creator_fee_bps,
+            deposit_fee_bps,
+            deposit_fee_to_protocol,
+        )
+    }
+    #[instruction(discriminator = 32)]
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        claim_creator_fees::handler(ctx)
+    }
+    #[instruction(discriminator = 33)]
+    pub fn transfer_creator(ctx: Context<TransferCreator>, new_creator: Pubkey) -> Result<()> {
+        transfer_creator::handler(ctx, new_creator)
+    }
+    #[instruction(discriminator = 34)]
+    pub fn quote_deposit(
+        ctx: Context<QuoteDeposit>,
+        token_a_amount_desired: u64,
+        token_b_amount_desired: u64,
+    ) -> Result<DepositResult> {
+        quote_deposit::handler(ctx, token_a_amount_desired, token_b_amount_desired)
+    }
+    #[instruction(discriminator = 35)]
+    pub fn initialize_global_state(
+        ctx: Context<InitializeGlobalState>,
+        admin: Pubkey,
+    ) -> Result<()> {
+        initialize_global_state::handler(ctx, admin)
+    }
+    #[instruction(discriminator = 36)]
+    pub fn set_global_pause(ctx: Context<SetGlobalPause>, paused: u8) -> Result<()> {
+        set_global_pause::handler(ctx, paused)
+    }
+    #[instruction(discriminator = 37)]
+    pub fn set_pool_pause(ctx: Context<SetPoolPause>, paused: u8) -> Result<()> {
+        set_pool_pause::handler(ctx, paused)
+    }
+    #[instruction(discriminator = 38)]
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        close_pool::handler(ctx)
+    }
+    #[instruction(discriminator = 39)]
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        migrate_pool::handler(ctx)
+    }
+    #[instruction(discriminator = 40)]
+    pub fn swap_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SwapV2<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+        is_token_a_in: bool,
+        expiration: i64,
+        referral_fee_bps: u16,
+    ) -> Result<SwapResult> {
+        swap_v2::handler(
+            ctx,
+            amount_in,
+            min_amount_out,
+            is_token_a_in,
+            expiration,
+            referral_fee_bps,
+        )
+    }
+    #[instruction(discriminator = 41)]
+    pub fn set_slot_price_move_limit(
+        ctx: Context<SetSlotPriceMoveLimit>,
+        max_slot_price_move_bps: u16,
+    ) -> Result<()> {
+        set_slot_price_move_limit::handler(ctx, max_slot_price_move_bps)
+    }
 }
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::Tick;
+
+/// A concentrated liquidity pool where LPs provide liquidity within tick ranges.
+///
+/// Prices are tracked as `sqrt(price)` in Q64.64 fixed point, where price is
+/// token B per token A. Tick `i` corresponds to a price of `1.0001^i`.
+#[account(discriminator = 3)]
+#[derive(InitSpace)]
+pub struct ConcentratedPool {
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub tick_spacing: u16,
+    pub fee_rate_bps: u16,
+    pub sqrt_price_x64: u128,
+    pub tick_current_index: i32,
+    pub liquidity: u128, // liquidity active in the current tick range
+    pub fee_growth_global_a_x64: u128,
+    pub fee_growth_global_b_x64: u128,
+    pub bump: u8,
+}
+
+impl ConcentratedPool {
+    /// Calculates the fees earned per unit of liquidity inside `[tick_lower, tick_upper)`.
+    ///
+    /// Fee growth outside a tick is relative to the side the price was on when the tick was
+    /// last crossed, so the inside growth is `global - below(lower) - above(upper)`.
+    /// All arithmetic wraps, as only differences between snapshots are meaningful.
+    ///
+    /// # Returns
+    /// `(fee_growth_inside_a_x64, fee_growth_inside_b_x64)`
+    pub fn fee_growth_inside(
+        &self,
+        tick_lower: &Tick,
+        tick_lower_index: i32,
+        tick_upper: &Tick,
+        tick_upper_index: i32,
+    ) -> (u128, u128) {
+        let (lower_outside_a, lower_outside_b) = (
+            tick_lower.fee_growth_outside_a_x64,
+            tick_lower.fee_growth_outside_b_x64,
+        );
+        let (upper_outside_a, upper_outside_b) = (
+            tick_upper.fee_growth_outside_a_x64,
+            tick_upper.fee_growth_outside_b_x64,
+        );
+
+        let (below_a, below_b) = if self.tick_current_index >= tick_lower_index {
+            (lower_outside_a, lower_outside_b)
+        } else {
+            (
+                self.fee_growth_global_a_x64.wrapping_sub(lower_outside_a),
+                self.fee_growth_global_b_x64.wrapping_sub(lower_outside_b),
+            )
+        };
+        let (above_a, above_b) = if self.tick_current_index < tick_upper_index {
+            (upper_outside_a, upper_outside_b)
+        } else {
+            (
+                self.fee_growth_global_a_x64.wrapping_sub(upper_outside_a),
+                self.fee_growth_global_b_x64.wrapping_sub(upper_outside_b),
+            )
+        };
+
+        (
+            self.fee_growth_global_a_x64
+                .wrapping_sub(below_a)
+                .wrapping_sub(above_a),
+            self.fee_growth_global_b_x64
+                .wrapping_sub(below_b)
+                .wrapping_sub(above_b),
+        )
+    }
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide settings, a singleton at `GLOBAL_STATE_SEED`.
+#[account(discriminator = 7)]
+#[derive(InitSpace)]
+pub struct GlobalState {
+    /// Allowed to pause every pool at once
+    pub admin: Pubkey,
+    /// `PAUSE_*` flags applied to every `LiquidityPool` on top of its own
+    pub paused: u8,
+    pub bump: u8,
+}
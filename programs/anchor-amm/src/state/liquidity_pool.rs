@@ -1,12 +1,1007 @@
 use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
 
-#[account(discriminator = 1)]
-#[derive(InitSpace)]
+use crate::{
+    error::{AmmError, MathError},
+    helpers::{
+        inverse_wad, load_oracle_price, mul_div, pmm_solve_target, ConstantProductCurve,
+        ConstantSumCurve, FeeMint, PmmCurve, PoolCurve, StableCurve, WeightedCurve,
+        FEE_SHARE_DENOMINATOR, PROTOCOL_FEE_SHARE,
+    },
+    GlobalState, SwapQuote, LIQUIDITY_POOL_VERSION, MAX_DEPOSIT_FEE_BPS, MAX_SWAP_FEE_BPS,
+    PAUSE_ALL, PAUSE_WITHDRAW, SWAP_FEE_BPS, WEIGHT_DENOMINATOR,
+};
+
+/// Pricing curve of a `LiquidityPool`, see `helpers::SwapCurve`.
+///
+/// Stored in the pool as its `u8` discriminant, see `LiquidityPool::curve_type`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+#[repr(u8)]
+pub enum CurveType {
+    ConstantProduct,
+    Stable,
+    Weighted,
+    ConstantSum,
+    OraclePmm,
+    LiquidityBootstrapping,
+}
+
+/// A two-token pool, loaded zero-copy through `AccountLoader`.
+///
+/// The discriminator is padded to 8 bytes so the fields stay aligned, and the
+/// fields are grouped by size so the layout has no implicit padding.
+#[account(zero_copy, discriminator = [1, 0, 0, 0, 0, 0, 0, 0])]
 pub struct LiquidityPool {
+    /// `SwapCurve::invariant` after the last liquidity event
+    pub k_last: u128,
+    /// Raw token B units per raw token A unit (WAD) as of the last PMM oracle refresh
+    pub pmm_oracle_price: u128,
     pub token_a_mint: Pubkey,
     pub token_b_mint: Pubkey,
+    /// Allowed to ramp the amplification coefficient and set the price band
+    pub admin: Pubkey,
+    /// Pyth-style price account quoting token A in token B: the pricing oracle of PMM pools
+    /// or the reference oracle guarding swaps on other pools; default for pools without one
+    pub oracle: Pubkey,
+    /// Receives `creator_fee_bps` of swap fees and is the only depositor of liquidity
+    /// bootstrapping pools; can hand the role to another key. Default for PMM pools,
+    /// which earn no creator fees
+    pub creator: Pubkey,
+    /// Paid the rent of the pool's accounts, refunded by `close_pool`
+    pub payer: Pubkey,
+    /// LP token mint, the PDA `["lp_token_mint", token_a_mint, token_b_mint]`
+    pub lp_mint: Pubkey,
+    /// Pool-owned token account holding the token A reserves
+    pub token_a_vault: Pubkey,
+    /// Pool-owned token account holding the token B reserves
+    pub token_b_vault: Pubkey,
+    /// Protocol fee LP token account owned by the pool PDA
+    pub fee_lp_token_account: Pubkey,
     pub token_a_reserves: u64, // be careful because the reserves are not normalized!
     pub token_b_reserves: u64,
+    /// StableSwap amplification at `amp_ramp_start_ts`; zero for other pools
+    pub amp_initial: u64,
+    /// StableSwap amplification reached at `amp_ramp_stop_ts`
+    pub amp_target: u64,
+    pub amp_ramp_start_ts: i64,
+    pub amp_ramp_stop_ts: i64,
+    /// Scales token A amounts up to the larger of the two mint precisions
+    pub token_a_precision_multiplier: u64,
+    /// Scales token B amounts up to the larger of the two mint precisions
+    pub token_b_precision_multiplier: u64,
+    /// Largest accepted age of an oracle price in seconds
+    pub oracle_max_age: u64,
+    /// PMM liquidity parameter (WAD); zero for other pools
+    pub pmm_k: u64,
+    /// PMM equilibrium reserves of token A
+    pub token_a_target: u64,
+    /// PMM equilibrium reserves of token B
+    pub token_b_target: u64,
+    /// Seconds it takes the volatility accumulator to halve
+    pub volatility_half_life: i64,
+    /// Recent spot price moves in basis points, summed per swap and decayed over time
+    pub volatility_accumulator: u64,
+    pub volatility_updated_at: i64,
+    /// Swaps fail before this unix timestamp
+    pub trading_starts_at: i64,
+    pub launch_fee_duration: i64,
+    /// Largest output of a single swap while the launch fee decays; zero for no limit
+    pub launch_max_buy: u64,
+    /// Liquidity bootstrapping weights start moving at this unix timestamp
+    pub lbp_start_ts: i64,
+    /// Liquidity bootstrapping weights stop moving at this unix timestamp
+    pub lbp_end_ts: i64,
+    /// Creator fee LP tokens held in the fee account and not yet claimed
+    pub creator_fee_lp_tokens: u64,
+    /// Weighted pool share of token A in basis points; zero for other pools.
+    /// Liquidity bootstrapping pools keep their starting weights here.
+    pub weight_a: u16,
+    /// Weighted pool share of token B in basis points; zero for other pools
+    pub weight_b: u16,
+    /// Constant-sum pool band around an even split of the reserves, in basis points; zero disables it
+    pub price_band_bps: u16,
+    /// Largest accepted oracle confidence interval as a share of the price, in basis points
+    pub oracle_max_confidence_bps: u16,
+    /// Largest accepted gap between the post-swap spot price and the reference oracle,
+    /// in basis points; zero disables the guard
+    pub max_oracle_deviation_bps: u16,
+    /// Share of the volatility accumulator charged on top of `SWAP_FEE_BPS`, in basis points;
+    /// zero disables dynamic fees
+    pub volatility_fee_factor_bps: u16,
+    /// Cap on the dynamic swap fee in basis points
+    pub max_swap_fee_bps: u16,
+    /// Swap fee at `trading_starts_at`, decaying linearly to the regular fee over
+    /// `launch_fee_duration`; zero for no launch fee
+    pub launch_fee_bps: u16,
+    /// Liquidity bootstrapping pool weight of token A at `lbp_end_ts`
+    pub lbp_end_weight_a: u16,
+    /// Creator's share of swap fees in basis points, minted alongside the protocol fee
+    pub creator_fee_bps: u16,
+    /// Share of the LP tokens minted by a deposit that the depositor doesn't receive
+    pub deposit_fee_bps: u16,
+    pub bump: u8,
+    /// Bump of `lp_mint`, so instructions check and sign for it without searching
+    pub lp_mint_bump: u8,
+    /// `CurveType` discriminant
+    pub curve_type: u8,
+    /// Non-zero if withheld deposit fees are minted to the fee account rather than left
+    /// unminted, which spreads them over existing LPs
+    pub deposit_fee_to_protocol: u8,
+    /// `PAUSE_*` flags other than `PAUSE_WITHDRAW` set by the admin, applied on top of
+    /// `GlobalState::paused`
+    pub paused: u8,
+    /// Layout version, `LIQUIDITY_POOL_VERSION` once written by this program
+    pub version: u8,
+    pub padding: [u8; 12],
+    // Fields below are taken from the start of `reserved`, so pools written
+    // before they existed read them as zero and keep their size and version
+    /// Non-zero while an instruction that moves tokens is changing the pool, see `is_locked`
+    pub locked: u8,
+    pub padding_1: [u8; 1],
+    /// Largest `slot_price_move_bps` the swaps of a slot may reach; zero disables the limit
+    pub max_slot_price_move_bps: u16,
+    pub padding_2: [u8; 4],
+    /// Spot price moves in basis points summed over the swaps of `price_move_slot`
+    pub slot_price_move_bps: u64,
+    pub price_move_slot: u64,
+    /// Zeroed space for future fields, so they don't need a `migrate_pool` realloc
+    pub reserved: [u8; 40],
+}
+
+impl LiquidityPool {
+    /// Account size, discriminator included
+    pub const LEN: usize =
+        LiquidityPool::DISCRIMINATOR.len() + std::mem::size_of::<LiquidityPool>();
+
+    /// Decodes a pool account in any older layout and upgrades it to this one.
+    /// Layouts are told apart by account length, then checked against their
+    /// discriminator and version. Linked accounts are left for the caller to fill in.
+    ///
+    /// # Errors
+    /// * `AmmError::InvalidPoolLayout` - If `data` isn't a pool account in a layout older
+    ///   than the current one
+    pub fn migrate_account_data(data: &[u8], admin: Pubkey) -> Result<Self> {
+        match data.len() {
+            LiquidityPoolV0::LEN => {
+                Ok(LiquidityPoolV0::try_from_account_data(data)?.migrate(admin))
+            }
+            LiquidityPoolV1Borsh::LEN => {
+                Ok(LiquidityPoolV1Borsh::try_from_account_data(data)?.migrate())
+            }
+            LiquidityPoolV1::LEN => Ok(LiquidityPoolV1::try_from_account_data(data)?.migrate()),
+            _ => err!(AmmError::InvalidPoolLayout),
+        }
+    }
+
+    /// A fresh, empty constant-product pool with `signer` as admin, creator and payer.
+    pub fn new(token_a_mint: Pubkey, token_b_mint: Pubkey, bump: u8, signer: Pubkey) -> Self {
+        LiquidityPool {
+            token_a_mint,
+            token_b_mint,
+            bump,
+            admin: signer,
+            token_a_precision_multiplier: 1,
+            token_b_precision_multiplier: 1,
+            curve_type: CurveType::ConstantProduct as u8,
+            creator: signer,
+            payer: signer,
+            version: LIQUIDITY_POOL_VERSION,
+            ..Zeroable::zeroed()
+        }
+    }
+
+    /// The pool's pricing curve. Zero, and any value this program never writes,
+    /// is constant product.
+    pub fn curve_type(&self) -> CurveType {
+        match self.curve_type {
+            1 => CurveType::Stable,
+            2 => CurveType::Weighted,
+            3 => CurveType::ConstantSum,
+            4 => CurveType::OraclePmm,
+            5 => CurveType::LiquidityBootstrapping,
+            _ => CurveType::ConstantProduct,
+        }
+    }
+
+    /// The pool's vault for `mint`.
+    pub fn vault(&self, mint: &Pubkey) -> Result<Pubkey> {
+        if *mint == self.token_a_mint {
+            Ok(self.token_a_vault)
+        } else if *mint == self.token_b_mint {
+            Ok(self.token_b_vault)
+        } else {
+            err!(AmmError::MintMismatch)
+        }
+    }
+
+    pub fn is_stable(&self) -> bool {
+        self.curve_type() == CurveType::Stable
+    }
+
+    /// Amplification coefficient at `now`, linearly interpolated while a ramp is in progress.
+    pub fn current_amp(&self, now: i64) -> u64 {
+        if now >= self.amp_ramp_stop_ts || self.amp_ramp_stop_ts <= self.amp_ramp_start_ts {
+            return self.amp_target;
+        }
+        let elapsed = now.saturating_sub(self.amp_ramp_start_ts).max(0) as u128;
+        let duration = (self.amp_ramp_stop_ts - self.amp_ramp_start_ts) as u128;
+        let initial = self.amp_initial as u128;
+        let target = self.amp_target as u128;
+        let amp = if target > initial {
+            initial + (target - initial) * elapsed / duration
+        } else {
+            initial - (initial - target) * elapsed / duration
+        };
+        amp as u64
+    }
+
+    /// Builds the pool's pricing curve with its parameters as of `now`.
+    pub fn swap_curve(&self, now: i64) -> PoolCurve {
+        match self.curve_type() {
+            CurveType::ConstantProduct => PoolCurve::ConstantProduct(ConstantProductCurve),
+            CurveType::Stable => PoolCurve::Stable(StableCurve {
+                amp: self.current_amp(now),
+                token_a_precision_multiplier: self.token_a_precision_multiplier as u128,
+                token_b_precision_multiplier: self.token_b_precision_multiplier as u128,
+            }),
+            CurveType::Weighted => PoolCurve::Weighted(WeightedCurve {
+                weight_a: self.weight_a,
+                weight_b: self.weight_b,
+            }),
+            CurveType::LiquidityBootstrapping => {
+                let weight_a = self.lbp_weight_a(now);
+                PoolCurve::Weighted(WeightedCurve {
+                    weight_a,
+                    weight_b: WEIGHT_DENOMINATOR - weight_a,
+                })
+            }
+            CurveType::ConstantSum => PoolCurve::ConstantSum(ConstantSumCurve {
+                token_a_precision_multiplier: self.token_a_precision_multiplier as u128,
+                token_b_precision_multiplier: self.token_b_precision_multiplier as u128,
+                price_band_bps: self.price_band_bps,
+            }),
+            CurveType::OraclePmm => PoolCurve::Pmm(PmmCurve {
+                oracle_price: self.pmm_oracle_price,
+                k: self.pmm_k as u128,
+                token_a_target: self.token_a_target as u128,
+                token_b_target: self.token_b_target as u128,
+            }),
+        }
+    }
+
+    /// Liquidity bootstrapping weight of token A at `now`, moving linearly from
+    /// `weight_a` at `lbp_start_ts` to `lbp_end_weight_a` at `lbp_end_ts`.
+    pub fn lbp_weight_a(&self, now: i64) -> u16 {
+        if now <= self.lbp_start_ts {
+            return self.weight_a;
+        }
+        if now >= self.lbp_end_ts {
+            return self.lbp_end_weight_a;
+        }
+        let elapsed = (now - self.lbp_start_ts) as i128;
+        let duration = (self.lbp_end_ts - self.lbp_start_ts) as i128;
+        let start = self.weight_a as i128;
+        let end = self.lbp_end_weight_a as i128;
+        (start + (end - start) * elapsed / duration) as u16
+    }
+
+    /// Current value of the pool invariant in the same form as `k_last`.
+    ///
+    /// Always zero for liquidity bootstrapping pools: their invariant moves with the
+    /// weights rather than with fees, so they don't pay the protocol fee.
+    pub fn invariant(&self, now: i64) -> Result<u128> {
+        if self.curve_type() == CurveType::LiquidityBootstrapping {
+            return Ok(0);
+        }
+        self.swap_curve(now)
+            .invariant(self.token_a_reserves as u128, self.token_b_reserves as u128)
+    }
+
+    /// Current marginal price of token A as raw token B per raw token A (WAD).
+    pub fn spot_price(&self, now: i64) -> Result<u128> {
+        self.swap_curve(now)
+            .spot_price(self.token_a_reserves as u128, self.token_b_reserves as u128)
+    }
+
+    pub fn has_dynamic_fee(&self) -> bool {
+        self.volatility_fee_factor_bps > 0
+    }
+
+    /// Whether swaps need to measure their spot price move, see `record_price_move`.
+    pub fn tracks_price_moves(&self) -> bool {
+        self.has_dynamic_fee() || self.max_slot_price_move_bps > 0
+    }
+
+    /// Volatility accumulator at `now`, halved once per elapsed `volatility_half_life`.
+    pub fn decayed_volatility(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.volatility_updated_at).max(0);
+        let halvings = elapsed / self.volatility_half_life.max(1);
+        if halvings >= 64 {
+            return 0;
+        }
+        self.volatility_accumulator >> halvings
+    }
+
+    /// Whether `now` falls in the anti-snipe window after `trading_starts_at`.
+    pub fn in_launch_window(&self, now: i64) -> bool {
+        now < self
+            .trading_starts_at
+            .saturating_add(self.launch_fee_duration)
+    }
+
+    /// Swap fee in basis points at `now`.
+    ///
+    /// `SWAP_FEE_BPS`, plus `volatility_fee_factor_bps` of the decayed volatility
+    /// accumulator for dynamic-fee pools, capped at `max_swap_fee_bps`. During the
+    /// launch window the decaying launch fee applies instead if it's higher.
+    pub fn swap_fee_bps(&self, now: i64) -> u64 {
+        let fee = if self.has_dynamic_fee() {
+            let variable_fee = (self.decayed_volatility(now) as u128)
+                * self.volatility_fee_factor_bps as u128
+                / 10_000;
+            (SWAP_FEE_BPS as u128 + variable_fee).min(self.max_swap_fee_bps as u128) as u64
+        } else {
+            SWAP_FEE_BPS
+        };
+        fee.max(self.launch_fee_bps(now))
+    }
+
+    /// Launch fee at `now`, interpolated from `launch_fee_bps` down to `SWAP_FEE_BPS`.
+    fn launch_fee_bps(&self, now: i64) -> u64 {
+        let launch_fee = self.launch_fee_bps as u64;
+        if launch_fee <= SWAP_FEE_BPS || !self.in_launch_window(now) {
+            return 0;
+        }
+        let elapsed = now.saturating_sub(self.trading_starts_at).max(0) as u128;
+        let decay =
+            (launch_fee - SWAP_FEE_BPS) as u128 * elapsed / self.launch_fee_duration.max(1) as u128;
+        launch_fee - decay as u64
+    }
+
+    /// Only the creator may add liquidity to a liquidity bootstrapping pool, and
+    /// only remove it once the weights have stopped moving.
+    pub fn check_lbp_liquidity_change(
+        &self,
+        signer: &Pubkey,
+        is_withdrawal: bool,
+        now: i64,
+    ) -> Result<()> {
+        if self.curve_type() != CurveType::LiquidityBootstrapping {
+            return Ok(());
+        }
+        require_keys_eq!(*signer, self.creator, AmmError::Unauthorized);
+        if is_withdrawal {
+            require_gte!(now, self.lbp_end_ts, AmmError::LbpNotEnded);
+        }
+        Ok(())
+    }
+
+    /// Enforces the trading start time and the launch window's max buy.
+    pub fn check_launch_limits(&self, amount_out: u64, now: i64) -> Result<()> {
+        require_gte!(now, self.trading_starts_at, AmmError::TradingNotStarted);
+        if self.launch_max_buy > 0 && self.in_launch_window(now) {
+            require_gte!(
+                self.launch_max_buy,
+                amount_out,
+                AmmError::LaunchMaxBuyExceeded
+            );
+        }
+        Ok(())
+    }
+
+    /// Configures volatility-based swap fees, see `swap_fee_bps`, starting from a calm
+    /// market at `now`. A `volatility_fee_factor_bps` of zero disables them. Not
+    /// available on PMM pools, whose spot price depends on the oracle.
+    pub fn set_dynamic_fee(
+        &mut self,
+        volatility_fee_factor_bps: u16,
+        max_swap_fee_bps: u16,
+        volatility_half_life: i64,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.curve_type() != CurveType::OraclePmm,
+            AmmError::UnsupportedCurveOperation
+        );
+        if volatility_fee_factor_bps > 0 {
+            require!(
+                (SWAP_FEE_BPS as u16..=MAX_SWAP_FEE_BPS).contains(&max_swap_fee_bps)
+                    && volatility_half_life > 0,
+                AmmError::InvalidDynamicFee
+            );
+        }
+        self.volatility_fee_factor_bps = volatility_fee_factor_bps;
+        self.max_swap_fee_bps = max_swap_fee_bps;
+        self.volatility_half_life = volatility_half_life;
+        self.volatility_accumulator = 0;
+        self.volatility_updated_at = now;
+        Ok(())
+    }
+
+    /// Caps the price moves of a slot at `max_slot_price_move_bps`, starting the current
+    /// slot from zero. Not available on PMM pools, whose spot price depends on the oracle.
+    pub fn set_slot_price_move_limit(&mut self, max_slot_price_move_bps: u16) -> Result<()> {
+        require!(
+            self.curve_type() != CurveType::OraclePmm,
+            AmmError::UnsupportedCurveOperation
+        );
+        self.max_slot_price_move_bps = max_slot_price_move_bps;
+        self.slot_price_move_bps = 0;
+        Ok(())
+    }
+
+    /// Adds a swap's spot price move, in basis points of the old price, to the decayed
+    /// volatility accumulator and to the moves of the current slot.
+    ///
+    /// Fails with `AmmError::SlotPriceMoveExceeded` if the slot's moves add up to more
+    /// than `max_slot_price_move_bps`. Both legs of a same-slot sandwich count, even
+    /// though they cancel out.
+    pub fn record_price_move(
+        &mut self,
+        price_before: u128,
+        price_after: u128,
+        now: i64,
+        slot: u64,
+    ) -> Result<()> {
+        let move_bps = price_before
+            .abs_diff(price_after)
+            .saturating_mul(10_000)
+            .checked_div(price_before)
+            .unwrap_or(0)
+            .min(u64::MAX as u128) as u64;
+        self.volatility_accumulator = self.decayed_volatility(now).saturating_add(move_bps);
+        self.volatility_updated_at = now;
+
+        if self.price_move_slot != slot {
+            self.price_move_slot = slot;
+            self.slot_price_move_bps = 0;
+        }
+        self.slot_price_move_bps = self.slot_price_move_bps.saturating_add(move_bps);
+        if self.max_slot_price_move_bps > 0 {
+            require_gte!(
+                self.max_slot_price_move_bps as u64,
+                self.slot_price_move_bps,
+                AmmError::SlotPriceMoveExceeded
+            );
+        }
+        Ok(())
+    }
+
+    /// Part of a swap fee that stays with LPs, once the protocol and creator shares
+    /// are minted out of it by `ProtocolFeeMinter`.
+    pub fn lp_fee(&self, fee: u64) -> u64 {
+        // Without k_last no fee LP tokens are ever minted
+        if self.k_last == 0 {
+            return fee;
+        }
+        (fee as u128 * (FEE_SHARE_DENOMINATOR - self.fee_share()) / FEE_SHARE_DENOMINATOR) as u64
+    }
+
+    /// Protocol and creator share of the fees, out of `FEE_SHARE_DENOMINATOR`
+    fn fee_share(&self) -> u128 {
+        PROTOCOL_FEE_SHARE + self.creator_fee_bps as u128 * FEE_SHARE_DENOMINATOR / 10_000
+    }
+
+    /// Protocol and creator fee LP tokens accrued since `k_last`, as `ProtocolFeeMinter`
+    /// would mint them for an LP supply of `lp_supply`.
+    pub fn accrued_fee_lp_tokens(&self, lp_supply: u64, now: i64) -> Result<FeeMint> {
+        // If k_last is 0, this is either first deposit or fees are disabled
+        if self.k_last == 0 {
+            return Ok(FeeMint::default());
+        }
+        let curve = self.swap_curve(now);
+        let k = curve.invariant(self.token_a_reserves as u128, self.token_b_reserves as u128)?;
+        let root_k = curve.root_invariant(k);
+        let root_k_last = curve.root_invariant(self.k_last);
+
+        // Only mint if k has grown (fees accumulated from swaps)
+        if root_k <= root_k_last {
+            return Ok(FeeMint::default());
+        }
+        let share = self.fee_share();
+        let creator_share = share - PROTOCOL_FEE_SHARE;
+
+        // numerator = totalSupply * φ * (rootK - rootKLast)
+        // denominator = rootK * (1 - φ) + rootKLast * φ
+        let denominator = root_k
+            .checked_mul(FEE_SHARE_DENOMINATOR - share)
+            .and_then(|a| a.checked_add(root_k_last.checked_mul(share)?))
+            .ok_or(MathError::Overflow)?;
+        let liquidity = mul_div(
+            lp_supply as u128 * share,
+            root_k - root_k_last,
+            denominator,
+            false,
+        )?;
+        let liquidity: u64 = liquidity.try_into().map_err(|_| MathError::Overflow)?;
+        let creator_lp_tokens = (liquidity as u128 * creator_share / share) as u64;
+        Ok(FeeMint {
+            protocol_lp_tokens: liquidity - creator_lp_tokens,
+            creator_lp_tokens,
+        })
+    }
+
+    /// Replaces the pool's own `PAUSE_*` flags. `PAUSE_WITHDRAW` can only be set
+    /// globally, so whoever created the pool can't trap its LPs.
+    pub fn set_paused(&mut self, paused: u8) -> Result<()> {
+        require!(paused & !PAUSE_ALL == 0, AmmError::InvalidPauseFlags);
+        require!(paused & PAUSE_WITHDRAW == 0, AmmError::PoolWithdrawPause);
+        self.paused = paused;
+        Ok(())
+    }
+
+    /// Fails with `AmmError::Paused` if `flag` is set on this pool or globally.
+    pub fn check_not_paused(&self, global_state: &GlobalState, flag: u8) -> Result<()> {
+        require!(
+            (self.paused | global_state.paused) & flag == 0,
+            AmmError::Paused
+        );
+        Ok(())
+    }
+
+    /// Whether an instruction is partway through changing the pool, i.e. inside one of
+    /// its token CPIs. Every instruction on the pool fails while it is locked, and
+    /// programs reading the account directly should refuse to price off it.
+    pub fn is_locked(&self) -> bool {
+        self.locked != 0
+    }
+
+    /// LP supply that deposits are priced against, zero until the first deposit.
+    ///
+    /// A pool reopened after `close_pool` reuses the old LP mint, whose supply is the
+    /// minimum locked by the old pool's first deposit, but starts without reserves.
+    pub fn lp_supply(&self, lp_mint_supply: u64) -> u64 {
+        if self.token_a_reserves == 0 && self.token_b_reserves == 0 {
+            0
+        } else {
+            lp_mint_supply
+        }
+    }
+
+    /// Withholds `deposit_fee_bps` of the LP tokens minted by every deposit after the
+    /// first, for the protocol if `to_protocol` and for existing LPs otherwise.
+    pub fn set_deposit_fee(&mut self, deposit_fee_bps: u16, to_protocol: bool) -> Result<()> {
+        require_gte!(
+            MAX_DEPOSIT_FEE_BPS,
+            deposit_fee_bps,
+            AmmError::InvalidDepositFee
+        );
+        self.deposit_fee_bps = deposit_fee_bps;
+        self.deposit_fee_to_protocol = to_protocol as u8;
+        Ok(())
+    }
+
+    /// Part of `lp_tokens` withheld from a depositor, rounded in the pool's favor.
+    pub fn deposit_fee(&self, lp_tokens: u64) -> u64 {
+        (lp_tokens as u128 * self.deposit_fee_bps as u128).div_ceil(10_000) as u64
+    }
+
+    /// Prices an exact-input swap, fees included, at `now`.
+    ///
+    /// The referral fee comes off `amount_in` first; the swap fee applies to the rest.
+    /// PMM pools must have refreshed their oracle price first.
+    pub fn quote_swap(
+        &self,
+        amount_in: u64,
+        is_token_a_in: bool,
+        referral_fee_bps: u16,
+        now: i64,
+    ) -> Result<SwapQuote> {
+        let referral_fee = (amount_in as u128 * referral_fee_bps as u128 / 10_000) as u64;
+        let amount_to_pool = amount_in - referral_fee;
+        let fee_bps = self.swap_fee_bps(now);
+        let amount_in_after_fees = (amount_to_pool as u128)
+            .checked_mul(10_000 - fee_bps as u128)
+            .ok_or(MathError::Overflow)?
+            / 10_000;
+        let (reserve_in, reserve_out) = if is_token_a_in {
+            (self.token_a_reserves, self.token_b_reserves)
+        } else {
+            (self.token_b_reserves, self.token_a_reserves)
+        };
+        let amount_out = self
+            .swap_curve(now)
+            .swap_exact_in(
+                amount_in_after_fees,
+                reserve_in as u128,
+                reserve_out as u128,
+                is_token_a_in,
+            )?
+            .try_into()
+            .map_err(|_| MathError::Overflow)?;
+        Ok(SwapQuote {
+            amount_in,
+            amount_out,
+            fee: amount_to_pool - amount_in_after_fees as u64,
+            fee_bps,
+            referral_fee,
+        })
+    }
+
+    /// Reads the pool's oracle, checked against `oracle`, as raw token B per raw token A (WAD).
+    pub fn load_oracle_price_wad(&self, oracle: Option<&AccountInfo>, now: i64) -> Result<u128> {
+        let oracle = oracle.ok_or(AmmError::InvalidOracle)?;
+        require_keys_eq!(oracle.key(), self.oracle, AmmError::InvalidOracle);
+        load_oracle_price(
+            oracle,
+            now,
+            self.oracle_max_age,
+            self.oracle_max_confidence_bps,
+        )?
+        .price_wad(
+            self.token_a_precision_multiplier as u128,
+            self.token_b_precision_multiplier as u128,
+        )
+    }
+
+    /// Stores a fresh oracle price and re-derives the PMM equilibrium targets from it.
+    ///
+    /// Follows DODO V2's `adjustedTarget`: the target of the side in excess is kept,
+    /// and the short side's target becomes whatever the excess is worth at the new
+    /// price. When neither side is short, swap fees have pushed both reserves above
+    /// their targets and the targets catch up with the reserves.
+    pub fn refresh_pmm_targets(&mut self, oracle_price: u128) -> Result<()> {
+        self.pmm_oracle_price = oracle_price;
+        let k = self.pmm_k as u128;
+        if self.token_a_reserves < self.token_a_target {
+            let excess = self.token_b_reserves.saturating_sub(self.token_b_target);
+            self.token_a_target = pmm_solve_target(
+                self.token_a_reserves as u128,
+                excess as u128,
+                inverse_wad(oracle_price)?,
+                k,
+            )?
+            .try_into()
+            .map_err(|_| MathError::Overflow)?;
+        } else if self.token_b_reserves < self.token_b_target {
+            let excess = self.token_a_reserves.saturating_sub(self.token_a_target);
+            self.token_b_target = pmm_solve_target(
+                self.token_b_reserves as u128,
+                excess as u128,
+                oracle_price,
+                k,
+            )?
+            .try_into()
+            .map_err(|_| MathError::Overflow)?;
+        } else {
+            self.token_a_target = self.token_a_reserves;
+            self.token_b_target = self.token_b_reserves;
+        }
+        Ok(())
+    }
+
+    /// Scales the PMM targets along with the reserves after a proportional deposit or withdrawal.
+    ///
+    /// The first deposit sets both targets to the initial reserves.
+    pub fn scale_pmm_targets(
+        &mut self,
+        token_a_reserves_before: u64,
+        token_b_reserves_before: u64,
+    ) -> Result<()> {
+        if self.curve_type() != CurveType::OraclePmm {
+            return Ok(());
+        }
+        if token_a_reserves_before == 0 || token_b_reserves_before == 0 {
+            self.token_a_target = self.token_a_reserves;
+            self.token_b_target = self.token_b_reserves;
+            return Ok(());
+        }
+        self.token_a_target = (self.token_a_target as u128)
+            .checked_mul(self.token_a_reserves as u128)
+            .and_then(|target| target.checked_div(token_a_reserves_before as u128))
+            .and_then(|target| u64::try_from(target).ok())
+            .ok_or(MathError::Overflow)?;
+        self.token_b_target = (self.token_b_target as u128)
+            .checked_mul(self.token_b_reserves as u128)
+            .and_then(|target| target.checked_div(token_b_reserves_before as u128))
+            .and_then(|target| u64::try_from(target).ok())
+            .ok_or(MathError::Overflow)?;
+        Ok(())
+    }
+}
+
+/// `LiquidityPool` layout from before versioning, upgraded in place by `migrate_pool`.
+///
+/// These accounts have a 1-byte discriminator and none of the later fields, so
+/// they don't load as a `LiquidityPool`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LiquidityPoolV0 {
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
     pub k_last: u128,
     pub bump: u8,
 }
+
+impl LiquidityPoolV0 {
+    /// The 1-byte discriminator `LiquidityPool` had before it was padded to 8 bytes
+    pub const DISCRIMINATOR: &'static [u8] = &[1];
+    /// Account size, discriminator included
+    pub const LEN: usize = Self::DISCRIMINATOR.len() + 32 + 32 + 8 + 8 + 16 + 1;
+
+    /// Decodes a pre-versioning pool account.
+    ///
+    /// # Errors
+    /// * `AmmError::InvalidPoolLayout` - If `data` isn't a pool account of this layout
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() == Self::LEN && data.starts_with(Self::DISCRIMINATOR),
+            AmmError::InvalidPoolLayout
+        );
+        Self::try_from_slice(&data[Self::DISCRIMINATOR.len()..])
+            .map_err(|_| AmmError::InvalidPoolLayout.into())
+    }
+
+    /// The current layout of this pool. Fields the old layout lacked take their
+    /// `LiquidityPool::new` defaults, with `admin` as admin, creator and payer.
+    pub fn migrate(self, admin: Pubkey) -> LiquidityPool {
+        LiquidityPool {
+            token_a_reserves: self.token_a_reserves,
+            token_b_reserves: self.token_b_reserves,
+            k_last: self.k_last,
+            ..LiquidityPool::new(self.token_a_mint, self.token_b_mint, self.bump, admin)
+        }
+    }
+}
+
+/// Version 1 as first written: Borsh-encoded behind the 1-byte discriminator, before
+/// pools were loaded zero-copy. Upgraded in place by `migrate_pool`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct LiquidityPoolV1Borsh {
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+    pub k_last: u128,
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub amp_initial: u64,
+    pub amp_target: u64,
+    pub amp_ramp_start_ts: i64,
+    pub amp_ramp_stop_ts: i64,
+    pub token_a_precision_multiplier: u64,
+    pub token_b_precision_multiplier: u64,
+    pub weight_a: u16,
+    pub weight_b: u16,
+    pub curve_type: CurveType,
+    pub price_band_bps: u16,
+    pub oracle: Pubkey,
+    pub oracle_max_age: u64,
+    pub oracle_max_confidence_bps: u16,
+    pub max_oracle_deviation_bps: u16,
+    pub pmm_k: u64,
+    pub pmm_oracle_price: u128,
+    pub token_a_target: u64,
+    pub token_b_target: u64,
+    pub volatility_fee_factor_bps: u16,
+    pub max_swap_fee_bps: u16,
+    pub volatility_half_life: i64,
+    pub volatility_accumulator: u64,
+    pub volatility_updated_at: i64,
+    pub trading_starts_at: i64,
+    pub launch_fee_bps: u16,
+    pub launch_fee_duration: i64,
+    pub launch_max_buy: u64,
+    pub lbp_end_weight_a: u16,
+    pub lbp_start_ts: i64,
+    pub lbp_end_ts: i64,
+    pub creator: Pubkey,
+    pub creator_fee_bps: u16,
+    pub creator_fee_lp_tokens: u64,
+    pub deposit_fee_bps: u16,
+    pub deposit_fee_to_protocol: bool,
+    pub paused: u8,
+    pub payer: Pubkey,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl LiquidityPoolV1Borsh {
+    /// Account size, discriminator included
+    pub const LEN: usize = LiquidityPoolV0::DISCRIMINATOR.len() + Self::INIT_SPACE;
+
+    /// Decodes a Borsh-encoded version 1 pool account.
+    ///
+    /// # Errors
+    /// * `AmmError::InvalidPoolLayout` - If `data` isn't a pool account of this layout
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() == Self::LEN && data.starts_with(LiquidityPoolV0::DISCRIMINATOR),
+            AmmError::InvalidPoolLayout
+        );
+        let pool = Self::try_from_slice(&data[LiquidityPoolV0::DISCRIMINATOR.len()..])
+            .map_err(|_| AmmError::InvalidPoolLayout)?;
+        require_eq!(
+            pool.version,
+            LiquidityPoolV1::VERSION,
+            AmmError::InvalidPoolLayout
+        );
+        Ok(pool)
+    }
+
+    /// The current layout of this pool. Fields the old layout lacked are zero; the
+    /// caller fills in the linked accounts.
+    pub fn migrate(self) -> LiquidityPool {
+        LiquidityPool {
+            k_last: self.k_last,
+            pmm_oracle_price: self.pmm_oracle_price,
+            token_a_mint: self.token_a_mint,
+            token_b_mint: self.token_b_mint,
+            admin: self.admin,
+            oracle: self.oracle,
+            creator: self.creator,
+            payer: self.payer,
+            token_a_reserves: self.token_a_reserves,
+            token_b_reserves: self.token_b_reserves,
+            amp_initial: self.amp_initial,
+            amp_target: self.amp_target,
+            amp_ramp_start_ts: self.amp_ramp_start_ts,
+            amp_ramp_stop_ts: self.amp_ramp_stop_ts,
+            token_a_precision_multiplier: self.token_a_precision_multiplier,
+            token_b_precision_multiplier: self.token_b_precision_multiplier,
+            oracle_max_age: self.oracle_max_age,
+            pmm_k: self.pmm_k,
+            token_a_target: self.token_a_target,
+            token_b_target: self.token_b_target,
+            volatility_half_life: self.volatility_half_life,
+            volatility_accumulator: self.volatility_accumulator,
+            volatility_updated_at: self.volatility_updated_at,
+            trading_starts_at: self.trading_starts_at,
+            launch_fee_duration: self.launch_fee_duration,
+            launch_max_buy: self.launch_max_buy,
+            lbp_start_ts: self.lbp_start_ts,
+            lbp_end_ts: self.lbp_end_ts,
+            creator_fee_lp_tokens: self.creator_fee_lp_tokens,
+            weight_a: self.weight_a,
+            weight_b: self.weight_b,
+            price_band_bps: self.price_band_bps,
+            oracle_max_confidence_bps: self.oracle_max_confidence_bps,
+            max_oracle_deviation_bps: self.max_oracle_deviation_bps,
+            volatility_fee_factor_bps: self.volatility_fee_factor_bps,
+            max_swap_fee_bps: self.max_swap_fee_bps,
+            launch_fee_bps: self.launch_fee_bps,
+            lbp_end_weight_a: self.lbp_end_weight_a,
+            creator_fee_bps: self.creator_fee_bps,
+            deposit_fee_bps: self.deposit_fee_bps,
+            bump: self.bump,
+            curve_type: self.curve_type as u8,
+            deposit_fee_to_protocol: self.deposit_fee_to_protocol as u8,
+            paused: self.paused,
+            version: LIQUIDITY_POOL_VERSION,
+            ..Zeroable::zeroed()
+        }
+    }
+}
+
+/// Version 1 once pools were loaded zero-copy, upgraded in place by `migrate_pool`.
+/// It still carries version 1, so it's told apart from `LiquidityPoolV1Borsh` by its
+/// discriminator and length.
+///
+/// It lacks the linked accounts and the LP mint bump, so it's shorter than a
+/// `LiquidityPool` and doesn't load as one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct LiquidityPoolV1 {
+    pub k_last: u128,
+    pub pmm_oracle_price: u128,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub admin: Pubkey,
+    pub oracle: Pubkey,
+    pub creator: Pubkey,
+    pub payer: Pubkey,
+    pub token_a_reserves: u64,
+    pub token_b_reserves: u64,
+    pub amp_initial: u64,
+    pub amp_target: u64,
+    pub amp_ramp_start_ts: i64,
+    pub amp_ramp_stop_ts: i64,
+    pub token_a_precision_multiplier: u64,
+    pub token_b_precision_multiplier: u64,
+    pub oracle_max_age: u64,
+    pub pmm_k: u64,
+    pub token_a_target: u64,
+    pub token_b_target: u64,
+    pub volatility_half_life: i64,
+    pub volatility_accumulator: u64,
+    pub volatility_updated_at: i64,
+    pub trading_starts_at: i64,
+    pub launch_fee_duration: i64,
+    pub launch_max_buy: u64,
+    pub lbp_start_ts: i64,
+    pub lbp_end_ts: i64,
+    pub creator_fee_lp_tokens: u64,
+    pub weight_a: u16,
+    pub weight_b: u16,
+    pub price_band_bps: u16,
+    pub oracle_max_confidence_bps: u16,
+    pub max_oracle_deviation_bps: u16,
+    pub volatility_fee_factor_bps: u16,
+    pub max_swap_fee_bps: u16,
+    pub launch_fee_bps: u16,
+    pub lbp_end_weight_a: u16,
+    pub creator_fee_bps: u16,
+    pub deposit_fee_bps: u16,
+    pub bump: u8,
+    pub curve_type: u8,
+    pub deposit_fee_to_protocol: u8,
+    pub paused: u8,
+    pub version: u8,
+    pub padding: [u8; 13],
+    pub reserved: [u8; 64],
+}
+
+impl LiquidityPoolV1 {
+    /// Layout version these accounts were written with
+    pub const VERSION: u8 = 1;
+    /// Account size, discriminator included
+    pub const LEN: usize = LiquidityPool::DISCRIMINATOR.len() + std::mem::size_of::<Self>();
+
+    /// Decodes a version 1 pool account.
+    ///
+    /// # Errors
+    /// * `AmmError::InvalidPoolLayout` - If `data` isn't a pool account of this layout
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() == Self::LEN && data.starts_with(LiquidityPool::DISCRIMINATOR),
+            AmmError::InvalidPoolLayout
+        );
+        let pool: Self = bytemuck::pod_read_unaligned(&data[LiquidityPool::DISCRIMINATOR.len()..]);
+        require_eq!(pool.version, Self::VERSION, AmmError::InvalidPoolLayout);
+        Ok(pool)
+    }
+
+    /// The current layout of this pool. Fields the old layout lacked are zero; the
+    /// caller fills in the linked accounts.
+    pub fn migrate(self) -> LiquidityPool {
+        LiquidityPool {
+            k_last: self.k_last,
+            pmm_oracle_price: self.pmm_oracle_price,
+            token_a_mint: self.token_a_mint,
+            token_b_mint: self.token_b_mint,
+            admin: self.admin,
+            oracle: self.oracle,
+            creator: self.creator,
+            payer: self.payer,
+            token_a_reserves: self.token_a_reserves,
+            token_b_reserves: self.token_b_reserves,
+            amp_initial: self.amp_initial,
+            amp_target: self.amp_target,
+            amp_ramp_start_ts: self.amp_ramp_start_ts,
+            amp_ramp_stop_ts: self.amp_ramp_stop_ts,
+            token_a_precision_multiplier: self.token_a_precision_multiplier,
+            token_b_precision_multiplier: self.token_b_precision_multiplier,
+            oracle_max_age: self.oracle_max_age,
+            pmm_k: self.pmm_k,
+            token_a_target: self.token_a_target,
+            token_b_target: self.token_b_target,
+            volatility_half_life: self.volatility_half_life,
+            volatility_accumulator: self.volatility_accumulator,
+            volatility_updated_at: self.volatility_updated_at,
+            trading_starts_at: self.trading_starts_at,
+            launch_fee_duration: self.launch_fee_duration,
+            launch_max_buy: self.launch_max_buy,
+            lbp_start_ts: self.lbp_start_ts,
+            lbp_end_ts: self.lbp_end_ts,
+            creator_fee_lp_tokens: self.creator_fee_lp_tokens,
+            weight_a: self.weight_a,
+            weight_b: self.weight_b,
+            price_band_bps: self.price_band_bps,
+            oracle_max_confidence_bps: self.oracle_max_confidence_bps,
+            max_oracle_deviation_bps: self.max_oracle_deviation_bps,
+            volatility_fee_factor_bps: self.volatility_fee_factor_bps,
+            max_swap_fee_bps: self.max_swap_fee_bps,
+            launch_fee_bps: self.launch_fee_bps,
+            lbp_end_weight_a: self.lbp_end_weight_a,
+            creator_fee_bps: self.creator_fee_bps,
+            deposit_fee_bps: self.deposit_fee_bps,
+            bump: self.bump,
+            curve_type: self.curve_type,
+            deposit_fee_to_protocol: self.deposit_fee_to_protocol,
+            paused: self.paused,
+            version: LIQUIDITY_POOL_VERSION,
+            ..Zeroable::zeroed()
+        }
+    }
+}
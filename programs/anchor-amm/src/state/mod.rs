@@ -1,2 +1,14 @@
+pub mod concentrated_pool;
+pub mod global_state;
 pub mod liquidity_pool;
-pub use liquidity_pool::*;
\ No newline at end of file
+pub mod multi_asset_pool;
+pub mod pool_stats;
+pub mod position;
+pub mod tick_array;
+pub use concentrated_pool::*;
+pub use global_state::*;
+pub use liquidity_pool::*;
+pub use multi_asset_pool::*;
+pub use pool_stats::*;
+pub use position::*;
+pub use tick_array::*;
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{error::AmmError, MAX_MULTI_ASSET_TOKENS};
+
+/// A Balancer-style weighted pool holding between 3 and `MAX_MULTI_ASSET_TOKENS` tokens.
+///
+/// Only the first `token_count` entries of each array are in use. The pool PDA
+/// owns every vault and is the authority of its LP mint.
+#[account(discriminator = 6)]
+#[derive(InitSpace)]
+pub struct MultiAssetPool {
+    pub lp_token_mint: Pubkey,
+    pub token_count: u8,
+    pub mints: [Pubkey; MAX_MULTI_ASSET_TOKENS],
+    pub vaults: [Pubkey; MAX_MULTI_ASSET_TOKENS],
+    pub reserves: [u64; MAX_MULTI_ASSET_TOKENS], // not normalized, like `LiquidityPool`
+    /// Basis points, summing to `WEIGHT_DENOMINATOR` over the tokens in use
+    pub weights: [u16; MAX_MULTI_ASSET_TOKENS],
+    pub bump: u8,
+}
+
+/// Accounts for one pool token, passed through `remaining_accounts` as
+/// `[mint, signer token account, vault]` in pool order.
+pub struct MultiAssetTokenAccounts<'info> {
+    pub mint: Account<'info, Mint>,
+    pub signer_token_account: Account<'info, TokenAccount>,
+    pub vault: Account<'info, TokenAccount>,
+}
+
+impl MultiAssetPool {
+    pub fn mints(&self) -> &[Pubkey] {
+        &self.mints[..self.token_count as usize]
+    }
+
+    pub fn reserves(&self) -> &[u64] {
+        &self.reserves[..self.token_count as usize]
+    }
+
+    pub fn weights(&self) -> &[u16] {
+        &self.weights[..self.token_count as usize]
+    }
+
+    /// Position of `mint` in the pool.
+    ///
+    /// # Errors
+    /// * `AmmError::MintMismatch` - If `mint` isn't one of the pool's tokens
+    pub fn token_index(&self, mint: &Pubkey) -> Result<usize> {
+        self.mints()
+            .iter()
+            .position(|pool_mint| pool_mint == mint)
+            .ok_or(AmmError::MintMismatch.into())
+    }
+
+    /// Deserializes and validates one `[mint, signer token account, vault]` triple per pool token.
+    ///
+    /// # Errors
+    /// * `AmmError::InvalidRemainingAccounts` - If the count, mints or vaults don't match the pool
+    pub fn load_token_accounts<'info>(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<MultiAssetTokenAccounts<'info>>> {
+        require_eq!(
+            remaining_accounts.len(),
+            self.token_count as usize * 3,
+            AmmError::InvalidRemainingAccounts
+        );
+        remaining_accounts
+            .chunks(3)
+            .enumerate()
+            .map(|(i, infos)| {
+                require_keys_eq!(
+                    infos[0].key(),
+                    self.mints[i],
+                    AmmError::InvalidRemainingAccounts
+                );
+                require_keys_eq!(
+                    infos[2].key(),
+                    self.vaults[i],
+                    AmmError::InvalidRemainingAccounts
+                );
+                Ok(MultiAssetTokenAccounts {
+                    mint: Account::try_from(&infos[0])?,
+                    signer_token_account: Account::try_from(&infos[1])?,
+                    vault: Account::try_from(&infos[2])?,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MathError;
+
+/// Cumulative trading statistics for a single `LiquidityPool`.
+///
+/// Lets frontends and other programs read volume and fee data on-chain
+/// without running an indexer. Volumes and fees are in each token's native units.
+#[account(discriminator = 2)]
+#[derive(InitSpace)]
+pub struct PoolStats {
+    pub liquidity_pool: Pubkey,
+    pub token_a_volume: u128,
+    pub token_b_volume: u128,
+    /// Swap fees left to LPs, net of the protocol and creator shares
+    pub token_a_lp_fees: u128,
+    pub token_b_lp_fees: u128,
+    pub protocol_fee_lp_tokens: u128, // protocol fees are minted as LP tokens
+    pub swap_count: u64,
+    pub deposit_count: u64,
+    pub withdraw_count: u64,
+    pub last_trade_slot: u64,
+    pub bump: u8,
+}
+
+impl PoolStats {
+    /// Records a swap. Both sides count towards volume; the LP fee is charged on the input token.
+    ///
+    /// `lp_fee` excludes the protocol and creator shares, see `LiquidityPool::lp_fee`;
+    /// the protocol's is recorded by `record_protocol_fee` once minted.
+    pub fn record_swap(
+        &mut self,
+        is_token_a_in: bool,
+        amount_in: u64,
+        amount_out: u64,
+        lp_fee: u64,
+        slot: u64,
+    ) -> Result<()> {
+        let (token_a_amount, token_b_amount) = if is_token_a_in {
+            (amount_in, amount_out)
+        } else {
+            (amount_out, amount_in)
+        };
+        self.token_a_volume = self
+            .token_a_volume
+            .checked_add(token_a_amount as u128)
+            .ok_or(MathError::Overflow)?;
+        self.token_b_volume = self
+            .token_b_volume
+            .checked_add(token_b_amount as u128)
+            .ok_or(MathError::Overflow)?;
+        if is_token_a_in {
+            self.token_a_lp_fees = self
+                .token_a_lp_fees
+                .checked_add(lp_fee as u128)
+                .ok_or(MathError::Overflow)?;
+        } else {
+            self.token_b_lp_fees = self
+                .token_b_lp_fees
+                .checked_add(lp_fee as u128)
+                .ok_or(MathError::Overflow)?;
+        }
+        self.swap_count = self.swap_count.checked_add(1).ok_or(MathError::Overflow)?;
+        self.last_trade_slot = slot;
+        Ok(())
+    }
+
+    pub fn record_deposit(&mut self) -> Result<()> {
+        self.deposit_count = self
+            .deposit_count
+            .checked_add(1)
+            .ok_or(MathError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_withdraw(&mut self) -> Result<()> {
+        self.withdraw_count = self
+            .withdraw_count
+            .checked_add(1)
+            .ok_or(MathError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_protocol_fee(&mut self, lp_tokens_minted: u64) -> Result<()> {
+        self.protocol_fee_lp_tokens = self
+            .protocol_fee_lp_tokens
+            .checked_add(lp_tokens_minted as u128)
+            .ok_or(MathError::Overflow)?;
+        Ok(())
+    }
+}
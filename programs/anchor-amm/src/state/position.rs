@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MathError,
+    helpers::{mul_div, Q64},
+};
+
+/// A liquidity position in a `ConcentratedPool` between two ticks.
+///
+/// PDA seeds: `["position", concentrated_pool, owner, tick_lower_index, tick_upper_index]`
+#[account(discriminator = 5)]
+#[derive(InitSpace)]
+pub struct Position {
+    pub concentrated_pool: Pubkey,
+    pub owner: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub fee_growth_inside_a_last_x64: u128,
+    pub fee_growth_inside_b_last_x64: u128,
+    pub fees_owed_a: u64,
+    pub fees_owed_b: u64,
+    pub bump: u8,
+}
+
+impl Position {
+    /// Accrues fees earned since the last update and snapshots the current inside fee growth.
+    ///
+    /// Formula: `fees_owed += liquidity * (fee_growth_inside - fee_growth_inside_last)`
+    pub fn update_fees(
+        &mut self,
+        fee_growth_inside_a_x64: u128,
+        fee_growth_inside_b_x64: u128,
+    ) -> Result<()> {
+        let fees_a = mul_div(
+            fee_growth_inside_a_x64.wrapping_sub(self.fee_growth_inside_a_last_x64),
+            self.liquidity,
+            Q64,
+            false,
+        )?;
+        let fees_b = mul_div(
+            fee_growth_inside_b_x64.wrapping_sub(self.fee_growth_inside_b_last_x64),
+            self.liquidity,
+            Q64,
+            false,
+        )?;
+        self.fees_owed_a = self
+            .fees_owed_a
+            .checked_add(fees_a.try_into().map_err(|_| MathError::Overflow)?)
+            .ok_or(MathError::Overflow)?;
+        self.fees_owed_b = self
+            .fees_owed_b
+            .checked_add(fees_b.try_into().map_err(|_| MathError::Overflow)?)
+            .ok_or(MathError::Overflow)?;
+        self.fee_growth_inside_a_last_x64 = fee_growth_inside_a_x64;
+        self.fee_growth_inside_b_last_x64 = fee_growth_inside_b_x64;
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::{AmmError, MathError},
+    MAX_TICK_INDEX, MIN_TICK_INDEX, TICK_ARRAY_SIZE,
+};
+
+/// Per-tick liquidity and fee accounting for a concentrated pool.
+///
+/// Packed so the tick array can be loaded zero-copy behind a 1-byte discriminator.
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct Tick {
+    pub initialized: u8,
+    /// Liquidity added when the price crosses this tick upwards (removed when crossing downwards)
+    pub liquidity_net: i128,
+    /// Total liquidity referencing this tick, used to know when it can be cleared
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a_x64: u128,
+    pub fee_growth_outside_b_x64: u128,
+}
+
+impl Tick {
+    /// Applies a liquidity change from a position using this tick as a boundary.
+    ///
+    /// On first initialization, all fee growth is assumed to have happened below the
+    /// tick if the price is currently at or above it (the Uniswap V3 convention).
+    /// Ticks left without liquidity are not cleared here, since their fee growth is
+    /// still needed to settle the position; see `clear_if_unused`.
+    ///
+    /// # Arguments
+    /// * `tick_index` - Index of this tick
+    /// * `tick_current_index` - Pool's current tick
+    /// * `liquidity_delta` - Signed liquidity change of the position
+    /// * `fee_growth_global_a_x64` / `fee_growth_global_b_x64` - Pool's global fee growth
+    /// * `is_upper` - Whether this tick is the position's upper bound
+    pub fn update(
+        &mut self,
+        tick_index: i32,
+        tick_current_index: i32,
+        liquidity_delta: i128,
+        fee_growth_global_a_x64: u128,
+        fee_growth_global_b_x64: u128,
+        is_upper: bool,
+    ) -> Result<()> {
+        let liquidity_gross_before = self.liquidity_gross;
+        let liquidity_gross_after = liquidity_gross_before
+            .checked_add_signed(liquidity_delta)
+            .ok_or(MathError::Overflow)?;
+
+        if liquidity_gross_before == 0 && liquidity_gross_after > 0 {
+            if tick_index <= tick_current_index {
+                self.fee_growth_outside_a_x64 = fee_growth_global_a_x64;
+                self.fee_growth_outside_b_x64 = fee_growth_global_b_x64;
+            }
+            self.initialized = 1;
+        }
+
+        let liquidity_net = self.liquidity_net;
+        self.liquidity_net = if is_upper {
+            liquidity_net.checked_sub(liquidity_delta)
+        } else {
+            liquidity_net.checked_add(liquidity_delta)
+        }
+        .ok_or(MathError::Overflow)?;
+        self.liquidity_gross = liquidity_gross_after;
+        Ok(())
+    }
+
+    /// Resets the tick once no position references it anymore.
+    pub fn clear_if_unused(&mut self) {
+        if self.liquidity_gross == 0 {
+            *self = Tick::default();
+        }
+    }
+
+    /// Flips the fee growth tracked outside this tick as the price crosses it.
+    ///
+    /// # Returns
+    /// The tick's `liquidity_net`, to be applied to the pool's active liquidity
+    pub fn cross(&mut self, fee_growth_global_a_x64: u128, fee_growth_global_b_x64: u128) -> i128 {
+        self.fee_growth_outside_a_x64 =
+            fee_growth_global_a_x64.wrapping_sub(self.fee_growth_outside_a_x64);
+        self.fee_growth_outside_b_x64 =
+            fee_growth_global_b_x64.wrapping_sub(self.fee_growth_outside_b_x64);
+        self.liquidity_net
+    }
+}
+
+/// A fixed-size window of `TICK_ARRAY_SIZE` ticks, starting at `start_tick_index`.
+///
+/// PDA seeds: `["tick_array", concentrated_pool, start_tick_index.to_le_bytes()]`
+#[account(zero_copy(unsafe), discriminator = 4)]
+#[repr(C, packed)]
+pub struct TickArray {
+    pub concentrated_pool: Pubkey,
+    pub start_tick_index: i32,
+    pub ticks: [Tick; TICK_ARRAY_SIZE],
+}
+
+impl TickArray {
+    /// Returns the start index of the tick array that contains `tick_index`.
+    pub fn start_tick_index_for(tick_index: i32, tick_spacing: u16) -> i32 {
+        let ticks_in_array = TICK_ARRAY_SIZE as i32 * tick_spacing as i32;
+        tick_index.div_euclid(ticks_in_array) * ticks_in_array
+    }
+
+    /// Checks that a tick index is usable as a position boundary.
+    pub fn validate_tick_index(tick_index: i32, tick_spacing: u16) -> Result<()> {
+        require!(
+            (MIN_TICK_INDEX..=MAX_TICK_INDEX).contains(&tick_index)
+                && tick_index % tick_spacing as i32 == 0,
+            AmmError::InvalidTickIndex
+        );
+        Ok(())
+    }
+
+    /// Returns the position of `tick_index` within `ticks`.
+    ///
+    /// # Errors
+    /// * `AmmError::InvalidTickArray` - If the tick is not covered by this array
+    pub fn tick_offset(&self, tick_index: i32, tick_spacing: u16) -> Result<usize> {
+        require_eq!(
+            Self::start_tick_index_for(tick_index, tick_spacing),
+            self.start_tick_index,
+            AmmError::InvalidTickArray
+        );
+        Ok(((tick_index - self.start_tick_index) / tick_spacing as i32) as usize)
+    }
+
+    pub fn tick(&self, tick_index: i32, tick_spacing: u16) -> Result<&Tick> {
+        let offset = self.tick_offset(tick_index, tick_spacing)?;
+        Ok(&self.ticks[offset])
+    }
+
+    pub fn tick_mut(&mut self, tick_index: i32, tick_spacing: u16) -> Result<&mut Tick> {
+        let offset = self.tick_offset(tick_index, tick_spacing)?;
+        Ok(&mut self.ticks[offset])
+    }
+}
@@ -0,0 +1,372 @@
+//! Accounts for the mollusk tests, which run the program built with `cargo build-sbf`
+//! next to the SPL Token program dumped as `spl_token.so`, e.g.
+//! `solana program dump TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA target/deploy/spl_token.so`.
+
+#![allow(dead_code)]
+
+use {
+    anchor_amm::{
+        GlobalState, LiquidityPool, PoolStats, GLOBAL_STATE_SEED, LIQUIDITY_POOL_SEED,
+        POOL_STATS_SEED,
+    },
+    anchor_lang::{
+        prelude::Pubkey, solana_program::instruction::Instruction, AccountSerialize, Discriminator,
+        InstructionData, ToAccountMetas,
+    },
+    anchor_spl::{
+        associated_token::{self, get_associated_token_address},
+        token::spl_token::{
+            self,
+            solana_program::{program_option::COption, program_pack::Pack},
+            state::{Account as TokenAccount, AccountState, Mint},
+        },
+    },
+    mollusk_svm::{
+        program::{
+            create_program_account_loader_v3, keyed_account_for_system_program, loader_keys,
+        },
+        Mollusk,
+    },
+    solana_account::Account,
+};
+
+/// Of each token held by the signer of `PoolKeys::swap` and `PoolKeys::swap_v2`
+pub const SIGNER_TOKENS: u64 = 1_000_000_000;
+
+/// The program with SPL Token loaded alongside it.
+pub fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&anchor_amm::ID, "anchor_amm");
+    mollusk.add_program(&spl_token::ID, "spl_token", &loader_keys::LOADER_V3);
+    mollusk
+}
+
+pub fn rent_exempt(mollusk: &Mollusk, data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: mollusk.sysvars.rent.minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn mint_account(mollusk: &Mollusk, supply: u64) -> Account {
+    mint_account_with_authority(mollusk, COption::None, supply)
+}
+
+/// A mint that `mint_authority` can mint from, such as the LP mint, its own authority.
+pub fn mint_account_with_authority(
+    mollusk: &Mollusk,
+    mint_authority: COption<Pubkey>,
+    supply: u64,
+) -> Account {
+    let mut data = vec![0; Mint::LEN];
+    Mint {
+        mint_authority,
+        supply,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    rent_exempt(mollusk, data, spl_token::ID)
+}
+
+pub fn token_account(mollusk: &Mollusk, mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount {
+        mint,
+        owner,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    rent_exempt(mollusk, data, spl_token::ID)
+}
+
+pub fn token_amount(account: &Account) -> u64 {
+    TokenAccount::unpack(&account.data).unwrap().amount
+}
+
+pub fn anchor_account<T: AccountSerialize>(mollusk: &Mollusk, account: &T) -> Account {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    rent_exempt(mollusk, data, anchor_amm::ID)
+}
+
+pub fn pool_account(mollusk: &Mollusk, pool: &LiquidityPool) -> Account {
+    let mut data = LiquidityPool::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(pool));
+    rent_exempt(mollusk, data, anchor_amm::ID)
+}
+
+pub fn read_pool(account: &Account) -> LiquidityPool {
+    bytemuck::pod_read_unaligned(&account.data[LiquidityPool::DISCRIMINATOR.len()..])
+}
+
+/// Addresses of a pool between two fresh mints and of the accounts around it.
+pub struct PoolKeys {
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub bump: u8,
+    pub pool_stats: Pubkey,
+    pub pool_stats_bump: u8,
+    pub global_state: Pubkey,
+    pub global_state_bump: u8,
+    pub event_authority: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub lp_token_mint_bump: u8,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub fee_lp_token_account: Pubkey,
+}
+
+impl PoolKeys {
+    pub fn new() -> Self {
+        let (token_a_mint, token_b_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (liquidity_pool, bump) = Pubkey::find_program_address(
+            &[
+                LIQUIDITY_POOL_SEED.as_bytes(),
+                token_a_mint.as_ref(),
+                token_b_mint.as_ref(),
+            ],
+            &anchor_amm::ID,
+        );
+        let (pool_stats, pool_stats_bump) = Pubkey::find_program_address(
+            &[POOL_STATS_SEED.as_bytes(), liquidity_pool.as_ref()],
+            &anchor_amm::ID,
+        );
+        let (global_state, global_state_bump) =
+            Pubkey::find_program_address(&[GLOBAL_STATE_SEED.as_bytes()], &anchor_amm::ID);
+        let (event_authority, _) =
+            Pubkey::find_program_address(&[b"__event_authority"], &anchor_amm::ID);
+        let (lp_token_mint, lp_token_mint_bump) = Pubkey::find_program_address(
+            &[
+                b"lp_token_mint",
+                token_a_mint.as_ref(),
+                token_b_mint.as_ref(),
+            ],
+            &anchor_amm::ID,
+        );
+        PoolKeys {
+            token_a_mint,
+            token_b_mint,
+            liquidity_pool,
+            bump,
+            pool_stats,
+            pool_stats_bump,
+            global_state,
+            global_state_bump,
+            event_authority,
+            lp_token_mint,
+            lp_token_mint_bump,
+            token_a_vault: get_associated_token_address(&liquidity_pool, &token_a_mint),
+            token_b_vault: get_associated_token_address(&liquidity_pool, &token_b_mint),
+            fee_lp_token_account: get_associated_token_address(&liquidity_pool, &lp_token_mint),
+        }
+    }
+
+    /// An empty constant-product pool with `signer` as admin and creator, linked to
+    /// these accounts.
+    pub fn pool(&self, signer: Pubkey) -> LiquidityPool {
+        let mut pool = LiquidityPool::new(self.token_a_mint, self.token_b_mint, self.bump, signer);
+        pool.lp_mint = self.lp_token_mint;
+        pool.lp_mint_bump = self.lp_token_mint_bump;
+        pool.token_a_vault = self.token_a_vault;
+        pool.token_b_vault = self.token_b_vault;
+        pool.fee_lp_token_account = self.fee_lp_token_account;
+        pool
+    }
+
+    pub fn pool_stats_account(&self, mollusk: &Mollusk) -> Account {
+        anchor_account(
+            mollusk,
+            &PoolStats {
+                liquidity_pool: self.liquidity_pool,
+                token_a_volume: 0,
+                token_b_volume: 0,
+                token_a_lp_fees: 0,
+                token_b_lp_fees: 0,
+                protocol_fee_lp_tokens: 0,
+                swap_count: 0,
+                deposit_count: 0,
+                withdraw_count: 0,
+                last_trade_slot: 0,
+                bump: self.pool_stats_bump,
+            },
+        )
+    }
+
+    pub fn global_state_account(&self, mollusk: &Mollusk, admin: Pubkey) -> Account {
+        anchor_account(
+            mollusk,
+            &GlobalState {
+                admin,
+                paused: 0,
+                bump: self.global_state_bump,
+            },
+        )
+    }
+
+    /// Accounts a swap by `signer` runs against: vaults holding `pool`'s reserves and
+    /// `SIGNER_TOKENS` of each token in the signer's accounts.
+    fn swap_accounts(
+        &self,
+        mollusk: &Mollusk,
+        pool: &LiquidityPool,
+        signer: Pubkey,
+    ) -> Vec<(Pubkey, Account)> {
+        vec![
+            (signer, Account::new(1_000_000_000, 0, &Pubkey::default())),
+            (
+                get_associated_token_address(&signer, &self.token_a_mint),
+                token_account(mollusk, self.token_a_mint, signer, SIGNER_TOKENS),
+            ),
+            (
+                get_associated_token_address(&signer, &self.token_b_mint),
+                token_account(mollusk, self.token_b_mint, signer, SIGNER_TOKENS),
+            ),
+            (
+                self.token_a_vault,
+                token_account(
+                    mollusk,
+                    self.token_a_mint,
+                    self.liquidity_pool,
+                    pool.token_a_reserves,
+                ),
+            ),
+            (
+                self.token_b_vault,
+                token_account(
+                    mollusk,
+                    self.token_b_mint,
+                    self.liquidity_pool,
+                    pool.token_b_reserves,
+                ),
+            ),
+            (
+                self.token_a_mint,
+                mint_account(mollusk, SIGNER_TOKENS + pool.token_a_reserves),
+            ),
+            (
+                self.token_b_mint,
+                mint_account(mollusk, SIGNER_TOKENS + pool.token_b_reserves),
+            ),
+            (self.lp_token_mint, mint_account(mollusk, 0)),
+            (self.liquidity_pool, pool_account(mollusk, pool)),
+            (self.pool_stats, self.pool_stats_account(mollusk)),
+            (
+                self.global_state,
+                self.global_state_account(mollusk, signer),
+            ),
+            (
+                spl_token::ID,
+                create_program_account_loader_v3(&spl_token::ID),
+            ),
+            (
+                associated_token::ID,
+                create_program_account_loader_v3(&associated_token::ID),
+            ),
+            keyed_account_for_system_program(),
+            (self.event_authority, Account::default()),
+            (
+                anchor_amm::ID,
+                create_program_account_loader_v3(&anchor_amm::ID),
+            ),
+        ]
+    }
+
+    /// A `swap` of token A for token B by `signer` and the accounts it runs against,
+    /// see `swap_accounts`. A `referrer_token_account` is created empty, in token A.
+    pub fn swap(
+        &self,
+        mollusk: &Mollusk,
+        pool: &LiquidityPool,
+        signer: Pubkey,
+        args: anchor_amm::instruction::Swap,
+        referrer_token_account: Option<Pubkey>,
+    ) -> (Instruction, Vec<(Pubkey, Account)>) {
+        let instruction = Instruction::new_with_bytes(
+            anchor_amm::ID,
+            &args.data(),
+            anchor_amm::accounts::Swap {
+                signer,
+                token_0_signer_token_account: get_associated_token_address(
+                    &signer,
+                    &self.token_a_mint,
+                ),
+                token_1_signer_token_account: get_associated_token_address(
+                    &signer,
+                    &self.token_b_mint,
+                ),
+                token_0_vault: self.token_a_vault,
+                token_1_vault: self.token_b_vault,
+                token_0_mint: self.token_a_mint,
+                token_1_mint: self.token_b_mint,
+                lp_token_mint: self.lp_token_mint,
+                liquidity_pool: self.liquidity_pool,
+                pool_stats: self.pool_stats,
+                global_state: self.global_state,
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: anchor_lang::system_program::ID,
+                referrer_token_account,
+                event_authority: self.event_authority,
+                program: anchor_amm::ID,
+            }
+            .to_account_metas(None),
+        );
+        let mut accounts = self.swap_accounts(mollusk, pool, signer);
+        if let Some(referrer_token_account) = referrer_token_account {
+            accounts.push((
+                referrer_token_account,
+                token_account(mollusk, self.token_a_mint, Pubkey::new_unique(), 0),
+            ));
+        }
+        (instruction, accounts)
+    }
+
+    /// A `swap_v2` by `signer` and the accounts it runs against, see `swap_accounts`.
+    pub fn swap_v2(
+        &self,
+        mollusk: &Mollusk,
+        pool: &LiquidityPool,
+        signer: Pubkey,
+        args: anchor_amm::instruction::SwapV2,
+    ) -> (Instruction, Vec<(Pubkey, Account)>) {
+        let instruction = Instruction::new_with_bytes(
+            anchor_amm::ID,
+            &args.data(),
+            anchor_amm::accounts::SwapV2 {
+                signer,
+                token_a_signer_token_account: get_associated_token_address(
+                    &signer,
+                    &self.token_a_mint,
+                ),
+                token_b_signer_token_account: get_associated_token_address(
+                    &signer,
+                    &self.token_b_mint,
+                ),
+                token_a_vault: self.token_a_vault,
+                token_b_vault: self.token_b_vault,
+                token_a_mint: self.token_a_mint,
+                token_b_mint: self.token_b_mint,
+                liquidity_pool: self.liquidity_pool,
+                pool_stats: self.pool_stats,
+                global_state: self.global_state,
+                token_program: spl_token::ID,
+                referrer_token_account: None,
+                instructions_sysvar: None,
+                event_authority: self.event_authority,
+                program: anchor_amm::ID,
+            }
+            .to_account_metas(None),
+        );
+        (instruction, self.swap_accounts(mollusk, pool, signer))
+    }
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "test-sbf")]
+
+//! `claim_creator_fees` run end to end; see `common` for what the tests need.
+//! Run with `cargo test-sbf --features test-sbf --test test_claim_creator_fees`.
+
+mod common;
+
+use {
+    anchor_amm::{error::AmmError, CurveType, LiquidityPool},
+    anchor_lang::{
+        prelude::Pubkey,
+        solana_program::{instruction::Instruction, program_error::ProgramError},
+        InstructionData, ToAccountMetas,
+    },
+    anchor_spl::{
+        associated_token::{self, get_associated_token_address},
+        token::spl_token,
+    },
+    common::{mint_account, pool_account, read_pool, token_account, token_amount, PoolKeys},
+    mollusk_svm::{
+        program::{create_program_account_loader_v3, keyed_account_for_system_program},
+        result::Check,
+        Mollusk,
+    },
+    solana_account::Account,
+};
+
+const FEE_ACCOUNT_LP_TOKENS: u64 = 1_500;
+const CREATOR_LP_TOKENS: u64 = 500;
+
+/// A `claim_creator_fees` by `creator` and the accounts it runs against.
+fn claim(
+    mollusk: &Mollusk,
+    keys: &PoolKeys,
+    pool: &LiquidityPool,
+    creator: Pubkey,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let lp_token_creator_token_account =
+        get_associated_token_address(&creator, &keys.lp_token_mint);
+    let instruction = Instruction::new_with_bytes(
+        anchor_amm::ID,
+        &anchor_amm::instruction::ClaimCreatorFees {}.data(),
+        anchor_amm::accounts::ClaimCreatorFees {
+            creator,
+            liquidity_pool: keys.liquidity_pool,
+            lp_token_mint: keys.lp_token_mint,
+            fee_lp_token_account: keys.fee_lp_token_account,
+            lp_token_creator_token_account,
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: keys.event_authority,
+            program: anchor_amm::ID,
+        }
+        .to_account_metas(None),
+    );
+    let accounts = vec![
+        (creator, Account::new(1_000_000_000, 0, &Pubkey::default())),
+        (keys.liquidity_pool, pool_account(mollusk, pool)),
+        (
+            keys.lp_token_mint,
+            mint_account(mollusk, FEE_ACCOUNT_LP_TOKENS),
+        ),
+        (
+            keys.fee_lp_token_account,
+            token_account(
+                mollusk,
+                keys.lp_token_mint,
+                keys.liquidity_pool,
+                FEE_ACCOUNT_LP_TOKENS,
+            ),
+        ),
+        (
+            lp_token_creator_token_account,
+            token_account(mollusk, keys.lp_token_mint, creator, 0),
+        ),
+        (
+            spl_token::ID,
+            create_program_account_loader_v3(&spl_token::ID),
+        ),
+        (
+            associated_token::ID,
+            create_program_account_loader_v3(&associated_token::ID),
+        ),
+        keyed_account_for_system_program(),
+        (keys.event_authority, Account::default()),
+        (
+            anchor_amm::ID,
+            create_program_account_loader_v3(&anchor_amm::ID),
+        ),
+    ];
+    (instruction, accounts)
+}
+
+#[test]
+fn test_claim_creator_fees() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let creator = Pubkey::new_unique();
+    let mut pool = keys.pool(creator);
+    pool.creator_fee_lp_tokens = CREATOR_LP_TOKENS;
+    let (instruction, accounts) = claim(&mollusk, &keys, &pool, creator);
+
+    let result =
+        mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+    let creator_token_account = get_associated_token_address(&creator, &keys.lp_token_mint);
+    assert_eq!(
+        token_amount(result.get_account(&creator_token_account).unwrap()),
+        CREATOR_LP_TOKENS
+    );
+    assert_eq!(
+        token_amount(result.get_account(&keys.fee_lp_token_account).unwrap()),
+        FEE_ACCOUNT_LP_TOKENS - CREATOR_LP_TOKENS
+    );
+    let pool = read_pool(result.get_account(&keys.liquidity_pool).unwrap());
+    assert_eq!(pool.creator_fee_lp_tokens, 0);
+    assert!(!pool.is_locked());
+}
+
+#[test]
+fn test_reject_claim_by_other_signer() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let mut pool = keys.pool(Pubkey::new_unique());
+    pool.creator_fee_lp_tokens = CREATOR_LP_TOKENS;
+    let (instruction, accounts) = claim(&mollusk, &keys, &pool, Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::Unauthorized.into(),
+        ))],
+    );
+}
+
+#[test]
+fn test_reject_claim_on_pmm_pool() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let creator = Pubkey::new_unique();
+    // PMM pools initialized before they stopped recording a creator still name one
+    let mut pool = keys.pool(creator);
+    pool.curve_type = CurveType::OraclePmm as u8;
+    pool.creator_fee_lp_tokens = CREATOR_LP_TOKENS;
+    let (instruction, accounts) = claim(&mollusk, &keys, &pool, creator);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::UnsupportedCurveOperation.into(),
+        ))],
+    );
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "test-sbf")]
+
+//! `close_pool` run end to end; see `common` for what the tests need.
+//! Run with `cargo test-sbf --features test-sbf --test test_close_pool`.
+
+mod common;
+
+use {
+    anchor_amm::{error::AmmError, LiquidityPool, MAX_CLOSE_POOL_DUST},
+    anchor_lang::{
+        prelude::Pubkey,
+        solana_program::{instruction::Instruction, program_error::ProgramError},
+        InstructionData, ToAccountMetas,
+    },
+    anchor_spl::token::spl_token,
+    common::{mint_account, pool_account, token_account, PoolKeys},
+    mollusk_svm::{program::create_program_account_loader_v3, result::Check, Mollusk},
+    solana_account::Account,
+};
+
+const PAYER_LAMPORTS: u64 = 1_000_000_000;
+/// LP tokens the first deposit locks for good
+const LOCKED_LP_TOKENS: u64 = 1_000;
+
+/// A `close_pool` by `payer` and the accounts it runs against, with `dust` of each
+/// token left in the vaults and `lp_supply` LP tokens outstanding.
+fn close_pool(
+    mollusk: &Mollusk,
+    keys: &PoolKeys,
+    pool: &LiquidityPool,
+    payer: Pubkey,
+    dust: u64,
+    lp_supply: u64,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let instruction = Instruction::new_with_bytes(
+        anchor_amm::ID,
+        &anchor_amm::instruction::ClosePool {}.data(),
+        anchor_amm::accounts::ClosePool {
+            payer,
+            liquidity_pool: keys.liquidity_pool,
+            pool_stats: keys.pool_stats,
+            token_a_mint: keys.token_a_mint,
+            token_b_mint: keys.token_b_mint,
+            token_a_vault: keys.token_a_vault,
+            token_b_vault: keys.token_b_vault,
+            lp_token_mint: keys.lp_token_mint,
+            fee_lp_token_account: keys.fee_lp_token_account,
+            token_program: spl_token::ID,
+            event_authority: keys.event_authority,
+            program: anchor_amm::ID,
+        }
+        .to_account_metas(None),
+    );
+    let accounts = vec![
+        (payer, Account::new(PAYER_LAMPORTS, 0, &Pubkey::default())),
+        (keys.liquidity_pool, pool_account(mollusk, pool)),
+        (keys.pool_stats, keys.pool_stats_account(mollusk)),
+        (keys.token_a_mint, mint_account(mollusk, dust)),
+        (keys.token_b_mint, mint_account(mollusk, dust)),
+        (
+            keys.token_a_vault,
+            token_account(mollusk, keys.token_a_mint, keys.liquidity_pool, dust),
+        ),
+        (
+            keys.token_b_vault,
+            token_account(mollusk, keys.token_b_mint, keys.liquidity_pool, dust),
+        ),
+        (keys.lp_token_mint, mint_account(mollusk, lp_supply)),
+        (
+            keys.fee_lp_token_account,
+            token_account(mollusk, keys.lp_token_mint, keys.liquidity_pool, 0),
+        ),
+        (
+            spl_token::ID,
+            create_program_account_loader_v3(&spl_token::ID),
+        ),
+        (keys.event_authority, Account::default()),
+        (
+            anchor_amm::ID,
+            create_program_account_loader_v3(&anchor_amm::ID),
+        ),
+    ];
+    (instruction, accounts)
+}
+
+#[test]
+fn test_close_pool_burns_dust_and_refunds_the_payer() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let payer = Pubkey::new_unique();
+    let (instruction, accounts) = close_pool(
+        &mollusk,
+        &keys,
+        &keys.pool(payer),
+        payer,
+        MAX_CLOSE_POOL_DUST,
+        LOCKED_LP_TOKENS,
+    );
+    let refund: u64 = accounts
+        .iter()
+        .filter(|(key, _)| {
+            [
+                keys.liquidity_pool,
+                keys.pool_stats,
+                keys.token_a_vault,
+                keys.token_b_vault,
+                keys.fee_lp_token_account,
+            ]
+            .contains(key)
+        })
+        .map(|(_, account)| account.lamports)
+        .sum();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&keys.liquidity_pool).closed().build(),
+            Check::account(&keys.pool_stats).closed().build(),
+            Check::account(&keys.token_a_vault).closed().build(),
+            Check::account(&keys.token_b_vault).closed().build(),
+            Check::account(&keys.fee_lp_token_account).closed().build(),
+            Check::account(&payer)
+                .lamports(PAYER_LAMPORTS + refund)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn test_reject_closing_a_pool_with_liquidity() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let payer = Pubkey::new_unique();
+    let pool = keys.pool(payer);
+
+    // LPs other than the locked minimum
+    let (instruction, accounts) =
+        close_pool(&mollusk, &keys, &pool, payer, 0, LOCKED_LP_TOKENS + 1);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::PoolNotEmpty.into(),
+        ))],
+    );
+
+    // More than dust in the vaults
+    let (instruction, accounts) = close_pool(
+        &mollusk,
+        &keys,
+        &pool,
+        payer,
+        MAX_CLOSE_POOL_DUST + 1,
+        LOCKED_LP_TOKENS,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::PoolDustExceeded.into(),
+        ))],
+    );
+}
+
+#[test]
+fn test_reject_close_by_other_signer() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let pool = keys.pool(Pubkey::new_unique());
+    let (instruction, accounts) = close_pool(
+        &mollusk,
+        &keys,
+        &pool,
+        Pubkey::new_unique(),
+        0,
+        LOCKED_LP_TOKENS,
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::Unauthorized.into(),
+        ))],
+    );
+}
@@ -0,0 +1,88 @@
+use {
+    anchor_amm::{CurveType, LiquidityPool, MAX_CREATOR_FEE_BPS},
+    anchor_lang::prelude::Pubkey,
+};
+
+const RESERVES: u64 = 1_000_000_000;
+const LP_SUPPLY: u64 = 1_000_000_000;
+
+/// A seeded constant-product pool whose creator takes `creator_fee_bps` of swap fees.
+fn pool(creator_fee_bps: u16) -> LiquidityPool {
+    let mut pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    pool.creator_fee_bps = creator_fee_bps;
+    pool.token_a_reserves = RESERVES;
+    pool.token_b_reserves = RESERVES;
+    pool.k_last = pool.invariant(0).unwrap();
+    pool
+}
+
+/// Applies a quoted swap of `amount_in` token A to the reserves.
+fn swap(pool: &mut LiquidityPool, amount_in: u64) {
+    let quote = pool.quote_swap(amount_in, true, 0, 0).unwrap();
+    pool.token_a_reserves += quote.amount_in;
+    pool.token_b_reserves -= quote.amount_out;
+}
+
+#[test]
+fn test_creator_fee_accrues_from_swaps() {
+    let mut pool = pool(MAX_CREATOR_FEE_BPS);
+    assert_eq!(
+        pool.accrued_fee_lp_tokens(LP_SUPPLY, 0).unwrap(),
+        Default::default()
+    );
+
+    for _ in 0..10 {
+        swap(&mut pool, 10_000_000);
+    }
+    let fee_mint = pool.accrued_fee_lp_tokens(LP_SUPPLY, 0).unwrap();
+    assert!(fee_mint.protocol_lp_tokens > 0);
+    // At 25% of swap fees the creator gets 1.5 times the protocol's 1/6, give or take rounding
+    let expected = fee_mint.protocol_lp_tokens * 3 / 2;
+    assert!(fee_mint.creator_lp_tokens.abs_diff(expected) <= 1);
+
+    // A deposit or withdrawal mints them and resets k_last, so nothing accrues twice
+    pool.creator_fee_lp_tokens += fee_mint.creator_lp_tokens;
+    pool.k_last = pool.invariant(0).unwrap();
+    assert_eq!(
+        pool.accrued_fee_lp_tokens(LP_SUPPLY, 0).unwrap(),
+        Default::default()
+    );
+    assert_eq!(pool.creator_fee_lp_tokens, fee_mint.creator_lp_tokens);
+}
+
+#[test]
+fn test_no_creator_fee_without_a_share() {
+    let mut pool = pool(0);
+    for _ in 0..10 {
+        swap(&mut pool, 10_000_000);
+    }
+    let fee_mint = pool.accrued_fee_lp_tokens(LP_SUPPLY, 0).unwrap();
+    assert!(fee_mint.protocol_lp_tokens > 0);
+    assert_eq!(fee_mint.creator_lp_tokens, 0);
+}
+
+#[test]
+fn test_creator_share_leaves_less_for_lps() {
+    let fee = 1_000_000;
+    assert!(pool(MAX_CREATOR_FEE_BPS).lp_fee(fee) < pool(0).lp_fee(fee));
+}
+
+#[test]
+fn test_pmm_pool_accrues_no_fees() {
+    let mut pool = pool(0);
+    pool.curve_type = CurveType::OraclePmm as u8;
+    pool.k_last = pool.invariant(0).unwrap();
+    assert_eq!(pool.k_last, 0);
+
+    pool.token_a_reserves += 10_000_000;
+    pool.token_b_reserves -= 9_000_000;
+    assert_eq!(
+        pool.accrued_fee_lp_tokens(LP_SUPPLY, 0).unwrap(),
+        Default::default()
+    );
+}
@@ -0,0 +1,239 @@
+#![cfg(feature = "test-sbf")]
+
+//! `deposit` run end to end; see `common` for what the tests need.
+//! Run with `cargo test-sbf --features test-sbf --test test_deposit`.
+
+mod common;
+
+use {
+    anchor_amm::{error::AmmError, LiquidityPool},
+    anchor_lang::{
+        prelude::Pubkey,
+        solana_program::{instruction::Instruction, program_error::ProgramError},
+        InstructionData, ToAccountMetas,
+    },
+    anchor_spl::{
+        associated_token::{self, get_associated_token_address},
+        token::spl_token::{self, solana_program::program_option::COption},
+    },
+    common::{
+        mint_account, mint_account_with_authority, pool_account, read_pool, token_account,
+        token_amount, PoolKeys,
+    },
+    mollusk_svm::{
+        program::{create_program_account_loader_v3, keyed_account_for_system_program},
+        result::Check,
+        Mollusk,
+    },
+    solana_account::Account,
+};
+
+const RESERVES: u64 = 1_000_000_000;
+const DEPOSIT: u64 = 1_000_000;
+const DEPOSIT_FEE_BPS: u16 = 100;
+
+/// A `deposit` of `DEPOSIT` of each token by `signer` and the accounts it runs
+/// against, with `lp_supply` LP tokens outstanding.
+fn deposit(
+    mollusk: &Mollusk,
+    keys: &PoolKeys,
+    pool: &LiquidityPool,
+    signer: Pubkey,
+    lp_supply: u64,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let token_a_signer_token_account = get_associated_token_address(&signer, &keys.token_a_mint);
+    let token_b_signer_token_account = get_associated_token_address(&signer, &keys.token_b_mint);
+    let lp_token_signer_token_account = get_associated_token_address(&signer, &keys.lp_token_mint);
+    let lp_token_system_program_token_account =
+        get_associated_token_address(&anchor_lang::system_program::ID, &keys.lp_token_mint);
+    let instruction = Instruction::new_with_bytes(
+        anchor_amm::ID,
+        &anchor_amm::instruction::Deposit {
+            token_a_amount_desired: DEPOSIT,
+            token_b_amount_desired: DEPOSIT,
+            token_a_amount_min: 0,
+            token_b_amount_min: 0,
+            expiration: i64::MAX,
+        }
+        .data(),
+        anchor_amm::accounts::Deposit {
+            signer,
+            lp_token_signer_token_account,
+            token_a_signer_token_account,
+            token_b_signer_token_account,
+            token_a_vault: keys.token_a_vault,
+            token_b_vault: keys.token_b_vault,
+            token_a_mint: keys.token_a_mint,
+            token_b_mint: keys.token_b_mint,
+            lp_token_mint: keys.lp_token_mint,
+            liquidity_pool: keys.liquidity_pool,
+            pool_stats: keys.pool_stats,
+            global_state: keys.global_state,
+            fee_lp_token_account: keys.fee_lp_token_account,
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+            lp_token_system_program_token_account,
+            event_authority: keys.event_authority,
+            program: anchor_amm::ID,
+        }
+        .to_account_metas(None),
+    );
+    let accounts = vec![
+        (signer, Account::new(1_000_000_000, 0, &Pubkey::default())),
+        (
+            lp_token_signer_token_account,
+            token_account(mollusk, keys.lp_token_mint, signer, 0),
+        ),
+        (
+            token_a_signer_token_account,
+            token_account(mollusk, keys.token_a_mint, signer, DEPOSIT),
+        ),
+        (
+            token_b_signer_token_account,
+            token_account(mollusk, keys.token_b_mint, signer, DEPOSIT),
+        ),
+        (
+            keys.token_a_vault,
+            token_account(
+                mollusk,
+                keys.token_a_mint,
+                keys.liquidity_pool,
+                pool.token_a_reserves,
+            ),
+        ),
+        (
+            keys.token_b_vault,
+            token_account(
+                mollusk,
+                keys.token_b_mint,
+                keys.liquidity_pool,
+                pool.token_b_reserves,
+            ),
+        ),
+        (
+            keys.token_a_mint,
+            mint_account(mollusk, DEPOSIT + pool.token_a_reserves),
+        ),
+        (
+            keys.token_b_mint,
+            mint_account(mollusk, DEPOSIT + pool.token_b_reserves),
+        ),
+        (
+            keys.lp_token_mint,
+            mint_account_with_authority(mollusk, COption::Some(keys.lp_token_mint), lp_supply),
+        ),
+        (keys.liquidity_pool, pool_account(mollusk, pool)),
+        (keys.pool_stats, keys.pool_stats_account(mollusk)),
+        (
+            keys.global_state,
+            keys.global_state_account(mollusk, signer),
+        ),
+        (
+            keys.fee_lp_token_account,
+            token_account(mollusk, keys.lp_token_mint, keys.liquidity_pool, 0),
+        ),
+        (
+            spl_token::ID,
+            create_program_account_loader_v3(&spl_token::ID),
+        ),
+        (
+            associated_token::ID,
+            create_program_account_loader_v3(&associated_token::ID),
+        ),
+        keyed_account_for_system_program(),
+        (
+            lp_token_system_program_token_account,
+            token_account(
+                mollusk,
+                keys.lp_token_mint,
+                anchor_lang::system_program::ID,
+                0,
+            ),
+        ),
+        (keys.event_authority, Account::default()),
+        (
+            anchor_amm::ID,
+            create_program_account_loader_v3(&anchor_amm::ID),
+        ),
+    ];
+    (instruction, accounts)
+}
+
+/// A pool charging `DEPOSIT_FEE_BPS` on deposits, for the protocol if `to_protocol`.
+fn pool(keys: &PoolKeys, signer: Pubkey, to_protocol: bool) -> LiquidityPool {
+    let mut pool = keys.pool(signer);
+    pool.set_deposit_fee(DEPOSIT_FEE_BPS, to_protocol).unwrap();
+    pool
+}
+
+#[test]
+fn test_first_deposit_pays_no_fee() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let (instruction, accounts) = deposit(&mollusk, &keys, &pool(&keys, signer, true), signer, 0);
+
+    let result =
+        mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+    // sqrt(DEPOSIT * DEPOSIT), less the locked minimum
+    let lp_token_signer_token_account = get_associated_token_address(&signer, &keys.lp_token_mint);
+    assert_eq!(
+        token_amount(result.get_account(&lp_token_signer_token_account).unwrap()),
+        DEPOSIT - 1_000
+    );
+    assert_eq!(
+        token_amount(result.get_account(&keys.fee_lp_token_account).unwrap()),
+        0
+    );
+}
+
+#[test]
+fn test_later_deposit_pays_the_fee() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let lp_token_signer_token_account = get_associated_token_address(&signer, &keys.lp_token_mint);
+    let deposit_fee = DEPOSIT * DEPOSIT_FEE_BPS as u64 / 10_000;
+
+    for to_protocol in [true, false] {
+        let mut pool = pool(&keys, signer, to_protocol);
+        pool.token_a_reserves = RESERVES;
+        pool.token_b_reserves = RESERVES;
+        pool.k_last = pool.invariant(0).unwrap();
+        let (instruction, accounts) = deposit(&mollusk, &keys, &pool, signer, RESERVES);
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+        assert_eq!(
+            token_amount(result.get_account(&lp_token_signer_token_account).unwrap()),
+            DEPOSIT - deposit_fee
+        );
+        // Left unminted, the fee goes to existing LPs instead
+        assert_eq!(
+            token_amount(result.get_account(&keys.fee_lp_token_account).unwrap()),
+            if to_protocol { deposit_fee } else { 0 }
+        );
+        let pool = read_pool(result.get_account(&keys.liquidity_pool).unwrap());
+        assert_eq!(pool.token_a_reserves, RESERVES + DEPOSIT);
+        assert!(!pool.is_locked());
+    }
+}
+
+#[test]
+fn test_reject_deposit_into_locked_pool() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let mut pool = pool(&keys, signer, true);
+    pool.locked = 1;
+    let (instruction, accounts) = deposit(&mollusk, &keys, &pool, signer, 0);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::PoolLocked.into(),
+        ))],
+    );
+}
@@ -0,0 +1,36 @@
+use {
+    anchor_amm::{error::AmmError, LiquidityPool, MAX_DEPOSIT_FEE_BPS},
+    anchor_lang::prelude::Pubkey,
+};
+
+fn pool() -> LiquidityPool {
+    LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    )
+}
+
+#[test]
+fn test_deposit_fee() {
+    let mut pool = pool();
+    assert_eq!(pool.deposit_fee(1_000_000), 0);
+
+    pool.set_deposit_fee(MAX_DEPOSIT_FEE_BPS, true).unwrap();
+    assert_eq!(pool.deposit_fee(1_000_000), 10_000);
+    // Rounded in the pool's favor
+    assert_eq!(pool.deposit_fee(1), 1);
+    assert_eq!(pool.deposit_fee_to_protocol, 1);
+}
+
+#[test]
+fn test_reject_deposit_fee_over_the_cap() {
+    let mut pool = pool();
+    assert_eq!(
+        pool.set_deposit_fee(MAX_DEPOSIT_FEE_BPS + 1, true)
+            .unwrap_err(),
+        AmmError::InvalidDepositFee.into()
+    );
+    assert_eq!(pool.deposit_fee_bps, 0);
+}
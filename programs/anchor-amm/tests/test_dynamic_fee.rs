@@ -0,0 +1,76 @@
+use {
+    anchor_amm::{error::AmmError, CurveType, LiquidityPool, MAX_SWAP_FEE_BPS, SWAP_FEE_BPS},
+    anchor_lang::prelude::Pubkey,
+};
+
+const HALF_LIFE: i64 = 60;
+
+fn pool() -> LiquidityPool {
+    let mut pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    pool.token_a_reserves = 1_000_000_000;
+    pool.token_b_reserves = 1_000_000_000;
+    pool
+}
+
+#[test]
+fn test_dynamic_fee_follows_volatility() {
+    let mut pool = pool();
+    // Half the accumulator on top of the base fee, up to 1%
+    pool.set_dynamic_fee(5_000, 100, HALF_LIFE, 0).unwrap();
+    assert_eq!(pool.swap_fee_bps(0), SWAP_FEE_BPS);
+
+    // A 2% move adds 1% of fee, capped at 1%
+    pool.record_price_move(10_000, 10_200, 0, 1).unwrap();
+    assert_eq!(pool.decayed_volatility(0), 200);
+    assert_eq!(pool.swap_fee_bps(0), 100);
+    assert_eq!(pool.quote_swap(1_000_000, true, 0, 0).unwrap().fee_bps, 100);
+
+    // Each half life halves the variable part
+    assert_eq!(pool.swap_fee_bps(HALF_LIFE), SWAP_FEE_BPS + 50);
+    assert_eq!(pool.swap_fee_bps(2 * HALF_LIFE), SWAP_FEE_BPS + 25);
+    assert_eq!(pool.swap_fee_bps(64 * HALF_LIFE), SWAP_FEE_BPS);
+
+    // Reconfiguring starts from a calm market
+    pool.set_dynamic_fee(5_000, 100, HALF_LIFE, 0).unwrap();
+    assert_eq!(pool.swap_fee_bps(0), SWAP_FEE_BPS);
+}
+
+#[test]
+fn test_static_fee_ignores_volatility() {
+    let mut pool = pool();
+    pool.record_price_move(10_000, 10_200, 0, 1).unwrap();
+    assert_eq!(pool.swap_fee_bps(0), SWAP_FEE_BPS);
+
+    pool.set_dynamic_fee(5_000, 100, HALF_LIFE, 0).unwrap();
+    pool.set_dynamic_fee(0, 0, 0, 0).unwrap();
+    pool.record_price_move(10_000, 10_200, 0, 1).unwrap();
+    assert_eq!(pool.swap_fee_bps(0), SWAP_FEE_BPS);
+}
+
+#[test]
+fn test_reject_invalid_dynamic_fee() {
+    let mut pool = pool();
+    for (max_swap_fee_bps, volatility_half_life) in [
+        (SWAP_FEE_BPS as u16 - 1, HALF_LIFE),
+        (MAX_SWAP_FEE_BPS + 1, HALF_LIFE),
+        (100, 0),
+    ] {
+        assert_eq!(
+            pool.set_dynamic_fee(5_000, max_swap_fee_bps, volatility_half_life, 0)
+                .unwrap_err(),
+            AmmError::InvalidDynamicFee.into()
+        );
+    }
+    assert!(!pool.has_dynamic_fee());
+
+    pool.curve_type = CurveType::OraclePmm as u8;
+    assert_eq!(
+        pool.set_dynamic_fee(5_000, 100, HALF_LIFE, 0).unwrap_err(),
+        AmmError::UnsupportedCurveOperation.into()
+    );
+}
@@ -0,0 +1,66 @@
+use {
+    anchor_amm::{error::AmmError, LiquidityPool, SWAP_FEE_BPS},
+    anchor_lang::prelude::Pubkey,
+};
+
+const TRADING_STARTS_AT: i64 = 1_000;
+const LAUNCH_FEE_DURATION: i64 = 100;
+const LAUNCH_MAX_BUY: u64 = 1_000_000;
+
+/// A pool launching at `TRADING_STARTS_AT` with a 10% fee decaying over
+/// `LAUNCH_FEE_DURATION` seconds.
+fn pool() -> LiquidityPool {
+    let mut pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    pool.trading_starts_at = TRADING_STARTS_AT;
+    pool.launch_fee_bps = 1_000;
+    pool.launch_fee_duration = LAUNCH_FEE_DURATION;
+    pool.launch_max_buy = LAUNCH_MAX_BUY;
+    pool
+}
+
+#[test]
+fn test_launch_fee_decays_to_the_swap_fee() {
+    let pool = pool();
+    assert_eq!(pool.swap_fee_bps(TRADING_STARTS_AT), 1_000);
+    assert_eq!(
+        pool.swap_fee_bps(TRADING_STARTS_AT + LAUNCH_FEE_DURATION / 2),
+        (1_000 + SWAP_FEE_BPS) / 2
+    );
+    assert_eq!(
+        pool.swap_fee_bps(TRADING_STARTS_AT + LAUNCH_FEE_DURATION),
+        SWAP_FEE_BPS
+    );
+}
+
+#[test]
+fn test_launch_limits() {
+    let pool = pool();
+    pool.check_launch_limits(LAUNCH_MAX_BUY, TRADING_STARTS_AT)
+        .unwrap();
+    // The max buy lifts with the launch window
+    pool.check_launch_limits(LAUNCH_MAX_BUY + 1, TRADING_STARTS_AT + LAUNCH_FEE_DURATION)
+        .unwrap();
+}
+
+#[test]
+fn test_reject_swaps_before_trading_starts_or_over_the_max_buy() {
+    let pool = pool();
+    assert_eq!(
+        pool.check_launch_limits(1, TRADING_STARTS_AT - 1)
+            .unwrap_err(),
+        AmmError::TradingNotStarted.into()
+    );
+    assert_eq!(
+        pool.check_launch_limits(
+            LAUNCH_MAX_BUY + 1,
+            TRADING_STARTS_AT + LAUNCH_FEE_DURATION - 1
+        )
+        .unwrap_err(),
+        AmmError::LaunchMaxBuyExceeded.into()
+    );
+}
@@ -0,0 +1,84 @@
+use {
+    anchor_amm::{error::AmmError, CurveType, LiquidityPool, WEIGHT_DENOMINATOR},
+    anchor_lang::prelude::Pubkey,
+};
+
+const START_TS: i64 = 1_000;
+const END_TS: i64 = 2_000;
+
+/// A 90/10 to 50/50 liquidity bootstrapping pool created by `creator`.
+fn lbp(creator: Pubkey) -> LiquidityPool {
+    let mut pool = LiquidityPool::new(Pubkey::new_unique(), Pubkey::new_unique(), 255, creator);
+    pool.curve_type = CurveType::LiquidityBootstrapping as u8;
+    pool.weight_a = 9_000;
+    pool.weight_b = WEIGHT_DENOMINATOR - 9_000;
+    pool.lbp_end_weight_a = 5_000;
+    pool.lbp_start_ts = START_TS;
+    pool.lbp_end_ts = END_TS;
+    pool.trading_starts_at = START_TS;
+    pool.token_a_reserves = 9_000_000_000;
+    pool.token_b_reserves = 1_000_000_000;
+    pool
+}
+
+#[test]
+fn test_only_creator_changes_lbp_liquidity() {
+    let admin = Pubkey::new_unique();
+    let mut pool = lbp(admin);
+    assert!(pool
+        .check_lbp_liquidity_change(&admin, false, START_TS)
+        .is_ok());
+
+    // After a creator transfer the new creator, not the admin, holds the liquidity
+    let new_creator = Pubkey::new_unique();
+    pool.creator = new_creator;
+    assert_eq!(
+        pool.check_lbp_liquidity_change(&admin, false, START_TS)
+            .unwrap_err(),
+        AmmError::Unauthorized.into()
+    );
+    assert!(pool
+        .check_lbp_liquidity_change(&new_creator, false, START_TS)
+        .is_ok());
+    assert!(pool
+        .check_lbp_liquidity_change(&new_creator, true, END_TS)
+        .is_ok());
+}
+
+#[test]
+fn test_lbp_withdrawals_wait_for_the_end() {
+    let creator = Pubkey::new_unique();
+    let pool = lbp(creator);
+    assert_eq!(
+        pool.check_lbp_liquidity_change(&creator, true, END_TS - 1)
+            .unwrap_err(),
+        AmmError::LbpNotEnded.into()
+    );
+    assert!(pool
+        .check_lbp_liquidity_change(&creator, true, END_TS)
+        .is_ok());
+}
+
+#[test]
+fn test_lbp_round_trip_never_profits() {
+    let pool = lbp(Pubkey::new_unique());
+    for now in [START_TS, (START_TS + END_TS) / 2, END_TS] {
+        for amount_in in [1_000, 1_000_007, 100_000_000] {
+            let quote = pool.quote_swap(amount_in, false, 0, now).unwrap();
+            let mut after = pool;
+            after.token_b_reserves += amount_in;
+            after.token_a_reserves -= quote.amount_out;
+            let back = after.quote_swap(quote.amount_out, true, 0, now).unwrap();
+            assert!(back.amount_out <= amount_in, "{now}: {amount_in}");
+        }
+    }
+}
+
+#[test]
+fn test_lbp_price_of_token_a_falls_over_time() {
+    let pool = lbp(Pubkey::new_unique());
+    let start = pool.spot_price(START_TS).unwrap();
+    let middle = pool.spot_price((START_TS + END_TS) / 2).unwrap();
+    let end = pool.spot_price(END_TS).unwrap();
+    assert!(start > middle && middle > end);
+}
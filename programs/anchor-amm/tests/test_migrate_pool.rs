@@ -0,0 +1,375 @@
+use {
+    anchor_amm::{
+        error::AmmError, CurveType, LiquidityPool, LiquidityPoolV0, LiquidityPoolV1,
+        LiquidityPoolV1Borsh, LIQUIDITY_POOL_VERSION,
+    },
+    anchor_lang::{prelude::Pubkey, AnchorSerialize, Discriminator},
+    bytemuck::Zeroable,
+};
+
+/// A pool account in the current zero-copy layout.
+fn current_account_data(pool: &LiquidityPool) -> Vec<u8> {
+    let mut data = LiquidityPool::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(pool));
+    data
+}
+
+/// A pool account in the Borsh-encoded version 1 layout.
+fn v1_borsh_account_data(pool: &LiquidityPoolV1Borsh) -> Vec<u8> {
+    let mut data = LiquidityPoolV0::DISCRIMINATOR.to_vec();
+    pool.serialize(&mut data).unwrap();
+    data
+}
+
+/// A weighted pool as written in the Borsh-encoded version 1 layout.
+fn v1_borsh_pool(admin: Pubkey, creator: Pubkey) -> LiquidityPoolV1Borsh {
+    LiquidityPoolV1Borsh {
+        token_a_mint: Pubkey::new_unique(),
+        token_b_mint: Pubkey::new_unique(),
+        token_a_reserves: 1_000,
+        token_b_reserves: 4_000,
+        k_last: 4_000_000,
+        bump: 252,
+        admin,
+        amp_initial: 0,
+        amp_target: 0,
+        amp_ramp_start_ts: 0,
+        amp_ramp_stop_ts: 0,
+        token_a_precision_multiplier: 1,
+        token_b_precision_multiplier: 1,
+        weight_a: 8_000,
+        weight_b: 2_000,
+        curve_type: CurveType::Weighted,
+        price_band_bps: 0,
+        oracle: Pubkey::new_unique(),
+        oracle_max_age: 60,
+        oracle_max_confidence_bps: 100,
+        max_oracle_deviation_bps: 200,
+        pmm_k: 0,
+        pmm_oracle_price: 0,
+        token_a_target: 0,
+        token_b_target: 0,
+        volatility_fee_factor_bps: 0,
+        max_swap_fee_bps: 0,
+        volatility_half_life: 0,
+        volatility_accumulator: 0,
+        volatility_updated_at: 0,
+        trading_starts_at: 1_700_000_000,
+        launch_fee_bps: 0,
+        launch_fee_duration: 0,
+        launch_max_buy: 0,
+        lbp_end_weight_a: 0,
+        lbp_start_ts: 0,
+        lbp_end_ts: 0,
+        creator,
+        creator_fee_bps: 250,
+        creator_fee_lp_tokens: 3,
+        deposit_fee_bps: 10,
+        deposit_fee_to_protocol: true,
+        paused: 2,
+        payer: admin,
+        version: LiquidityPoolV1::VERSION,
+        reserved: [0; 64],
+    }
+}
+
+/// A pool account in the version 1 zero-copy layout.
+fn v1_account_data(pool: &LiquidityPoolV1) -> Vec<u8> {
+    let mut data = LiquidityPool::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(pool));
+    data
+}
+
+/// A stable pool as written in the version 1 layout.
+fn v1_pool(admin: Pubkey, creator: Pubkey) -> LiquidityPoolV1 {
+    LiquidityPoolV1 {
+        k_last: 4_000_000,
+        token_a_mint: Pubkey::new_unique(),
+        token_b_mint: Pubkey::new_unique(),
+        admin,
+        creator,
+        payer: admin,
+        token_a_reserves: 1_000,
+        token_b_reserves: 4_000,
+        amp_initial: 100,
+        amp_target: 200,
+        token_a_precision_multiplier: 1_000,
+        token_b_precision_multiplier: 1,
+        creator_fee_lp_tokens: 7,
+        creator_fee_bps: 500,
+        deposit_fee_bps: 30,
+        bump: 253,
+        curve_type: CurveType::Stable as u8,
+        deposit_fee_to_protocol: 1,
+        paused: 1,
+        version: LiquidityPoolV1::VERSION,
+        ..Zeroable::zeroed()
+    }
+}
+
+/// A pool account as written before versioning: discriminator, mints, reserves, k_last, bump.
+fn v0_account_data(
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    token_a_reserves: u64,
+    token_b_reserves: u64,
+    k_last: u128,
+    bump: u8,
+) -> Vec<u8> {
+    let mut data = LiquidityPoolV0::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(token_a_mint.as_ref());
+    data.extend_from_slice(token_b_mint.as_ref());
+    data.extend_from_slice(&token_a_reserves.to_le_bytes());
+    data.extend_from_slice(&token_b_reserves.to_le_bytes());
+    data.extend_from_slice(&k_last.to_le_bytes());
+    data.push(bump);
+    data
+}
+
+#[test]
+fn test_decode_v0_pool() {
+    let token_a_mint = Pubkey::new_unique();
+    let token_b_mint = Pubkey::new_unique();
+    let data = v0_account_data(token_a_mint, token_b_mint, 1_000, 4_000, 4_000_000, 254);
+    assert_eq!(data.len(), LiquidityPoolV0::LEN);
+
+    let pool = LiquidityPoolV0::try_from_account_data(&data).unwrap();
+    assert_eq!(
+        pool,
+        LiquidityPoolV0 {
+            token_a_mint,
+            token_b_mint,
+            token_a_reserves: 1_000,
+            token_b_reserves: 4_000,
+            k_last: 4_000_000,
+            bump: 254,
+        }
+    );
+}
+
+#[test]
+fn test_v0_pool_does_not_decode_as_current_layout() {
+    let data = v0_account_data(Pubkey::new_unique(), Pubkey::new_unique(), 1, 1, 1, 255);
+    assert_ne!(&data[..8], LiquidityPool::DISCRIMINATOR);
+    assert!(data.len() < LiquidityPool::LEN);
+}
+
+#[test]
+fn test_migrate_v0_pool() {
+    let token_a_mint = Pubkey::new_unique();
+    let token_b_mint = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let data = v0_account_data(token_a_mint, token_b_mint, 1_000, 4_000, 4_000_000, 254);
+
+    let pool = LiquidityPoolV0::try_from_account_data(&data)
+        .unwrap()
+        .migrate(admin);
+    let migrated = current_account_data(&pool);
+    assert_eq!(migrated.len(), LiquidityPool::LEN);
+
+    let pool: LiquidityPool = bytemuck::pod_read_unaligned(&migrated[8..]);
+    assert_eq!(pool.version, LIQUIDITY_POOL_VERSION);
+    assert_eq!(pool.token_a_mint, token_a_mint);
+    assert_eq!(pool.token_b_mint, token_b_mint);
+    assert_eq!(pool.token_a_reserves, 1_000);
+    assert_eq!(pool.token_b_reserves, 4_000);
+    assert_eq!(pool.k_last, 4_000_000);
+    assert_eq!(pool.bump, 254);
+    assert!(pool.curve_type() == CurveType::ConstantProduct);
+    assert_eq!(pool.admin, admin);
+    assert_eq!(pool.creator, admin);
+    assert_eq!(pool.payer, admin);
+    assert_eq!(pool.paused, 0);
+    assert_eq!(pool.reserved, [0; 40]);
+}
+
+#[test]
+fn test_decode_v1_pool() {
+    let pool = v1_pool(Pubkey::new_unique(), Pubkey::new_unique());
+    let data = v1_account_data(&pool);
+    assert_eq!(data.len(), LiquidityPoolV1::LEN);
+    assert!(data.len() < LiquidityPool::LEN);
+
+    assert_eq!(LiquidityPoolV1::try_from_account_data(&data).unwrap(), pool);
+}
+
+#[test]
+fn test_migrate_v1_pool() {
+    let admin = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let old_pool = v1_pool(admin, creator);
+    let data = v1_account_data(&old_pool);
+
+    let pool = LiquidityPoolV1::try_from_account_data(&data)
+        .unwrap()
+        .migrate();
+    let migrated = current_account_data(&pool);
+    assert_eq!(migrated.len(), LiquidityPool::LEN);
+
+    let pool: LiquidityPool = bytemuck::pod_read_unaligned(&migrated[8..]);
+    assert_eq!(pool.version, LIQUIDITY_POOL_VERSION);
+    assert_eq!(pool.token_a_mint, old_pool.token_a_mint);
+    assert_eq!(pool.token_b_mint, old_pool.token_b_mint);
+    assert_eq!(pool.token_a_reserves, 1_000);
+    assert_eq!(pool.token_b_reserves, 4_000);
+    assert_eq!(pool.k_last, 4_000_000);
+    assert_eq!(pool.bump, 253);
+    assert!(pool.curve_type() == CurveType::Stable);
+    assert_eq!(pool.current_amp(0), 200);
+    assert_eq!(pool.token_a_precision_multiplier, 1_000);
+    assert_eq!(pool.admin, admin);
+    assert_eq!(pool.creator, creator);
+    assert_eq!(pool.creator_fee_bps, 500);
+    assert_eq!(pool.creator_fee_lp_tokens, 7);
+    assert_eq!(pool.deposit_fee_bps, 30);
+    assert_eq!(pool.deposit_fee_to_protocol, 1);
+    assert_eq!(pool.paused, 1);
+    assert_eq!(pool.lp_mint, Pubkey::default());
+    assert_eq!(pool.reserved, [0; 40]);
+}
+
+#[test]
+fn test_reject_v1_pool_with_other_version() {
+    let mut pool = v1_pool(Pubkey::new_unique(), Pubkey::new_unique());
+    pool.version = LIQUIDITY_POOL_VERSION;
+    let data = v1_account_data(&pool);
+
+    assert_eq!(
+        LiquidityPoolV1::try_from_account_data(&data).unwrap_err(),
+        AmmError::InvalidPoolLayout.into()
+    );
+}
+
+#[test]
+fn test_current_pool_does_not_decode_as_v1() {
+    let pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    let data = current_account_data(&pool);
+
+    assert_eq!(
+        LiquidityPoolV1::try_from_account_data(&data).unwrap_err(),
+        AmmError::InvalidPoolLayout.into()
+    );
+}
+
+#[test]
+fn test_reject_non_v0_layouts() {
+    let pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    let current = current_account_data(&pool);
+    let v1 = v1_account_data(&v1_pool(Pubkey::new_unique(), Pubkey::new_unique()));
+    let mut wrong_discriminator =
+        v0_account_data(Pubkey::new_unique(), Pubkey::new_unique(), 1, 1, 1, 255);
+    wrong_discriminator[0] = 2;
+
+    for data in [current, v1, wrong_discriminator, Vec::new()] {
+        assert_eq!(
+            LiquidityPoolV0::try_from_account_data(&data).unwrap_err(),
+            AmmError::InvalidPoolLayout.into()
+        );
+    }
+}
+
+#[test]
+fn test_later_fields_keep_the_version_2_layout() {
+    // Fields added to version 2 take their bytes from the 64 reserved ones at its end
+    assert_eq!(LiquidityPool::LEN, 632);
+    assert_eq!(
+        std::mem::offset_of!(LiquidityPool, locked) + 64,
+        std::mem::size_of::<LiquidityPool>()
+    );
+}
+
+#[test]
+fn test_decode_v1_borsh_pool() {
+    let pool = v1_borsh_pool(Pubkey::new_unique(), Pubkey::new_unique());
+    let data = v1_borsh_account_data(&pool);
+    assert_eq!(data.len(), LiquidityPoolV1Borsh::LEN);
+
+    assert_eq!(
+        LiquidityPoolV1Borsh::try_from_account_data(&data).unwrap(),
+        pool
+    );
+}
+
+#[test]
+fn test_reject_v1_borsh_pool_with_other_version() {
+    let mut pool = v1_borsh_pool(Pubkey::new_unique(), Pubkey::new_unique());
+    pool.version = 0;
+    let data = v1_borsh_account_data(&pool);
+
+    assert_eq!(
+        LiquidityPoolV1Borsh::try_from_account_data(&data).unwrap_err(),
+        AmmError::InvalidPoolLayout.into()
+    );
+}
+
+#[test]
+fn test_migrate_v1_borsh_pool() {
+    let admin = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let old_pool = v1_borsh_pool(admin, creator);
+    let data = v1_borsh_account_data(&old_pool);
+
+    let pool = LiquidityPool::migrate_account_data(&data, Pubkey::new_unique()).unwrap();
+    assert_eq!(pool.version, LIQUIDITY_POOL_VERSION);
+    assert_eq!(pool.token_a_mint, old_pool.token_a_mint);
+    assert_eq!(pool.token_b_mint, old_pool.token_b_mint);
+    assert_eq!(pool.token_a_reserves, 1_000);
+    assert_eq!(pool.token_b_reserves, 4_000);
+    assert_eq!(pool.k_last, 4_000_000);
+    assert_eq!(pool.bump, 252);
+    assert!(pool.curve_type() == CurveType::Weighted);
+    assert_eq!((pool.weight_a, pool.weight_b), (8_000, 2_000));
+    assert_eq!(pool.oracle, old_pool.oracle);
+    assert_eq!(pool.max_oracle_deviation_bps, 200);
+    assert_eq!(pool.trading_starts_at, 1_700_000_000);
+    assert_eq!(pool.admin, admin);
+    assert_eq!(pool.creator, creator);
+    assert_eq!(pool.creator_fee_bps, 250);
+    assert_eq!(pool.creator_fee_lp_tokens, 3);
+    assert_eq!(pool.deposit_fee_to_protocol, 1);
+    assert_eq!(pool.paused, 2);
+}
+
+#[test]
+fn test_migrate_every_older_layout() {
+    let admin = Pubkey::new_unique();
+    let v0 = v0_account_data(Pubkey::new_unique(), Pubkey::new_unique(), 1, 1, 1, 255);
+    let v1_borsh = v1_borsh_account_data(&v1_borsh_pool(admin, admin));
+    let v1 = v1_account_data(&v1_pool(admin, admin));
+
+    for data in [v0, v1_borsh, v1] {
+        let pool = LiquidityPool::migrate_account_data(&data, admin).unwrap();
+        assert_eq!(pool.version, LIQUIDITY_POOL_VERSION);
+        assert_eq!(pool.admin, admin);
+    }
+}
+
+#[test]
+fn test_reject_migrating_current_layout() {
+    let pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    let data = current_account_data(&pool);
+    let mut truncated = data.clone();
+    truncated.truncate(LiquidityPoolV1::LEN);
+
+    for data in [data, truncated, Vec::new()] {
+        assert_eq!(
+            LiquidityPool::migrate_account_data(&data, Pubkey::new_unique()).err(),
+            Some(AmmError::InvalidPoolLayout.into())
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use {
+    anchor_amm::{
+        error::AmmError, GlobalState, LiquidityPool, PAUSE_ALL, PAUSE_DEPOSIT, PAUSE_SKIM,
+        PAUSE_SWAP, PAUSE_WITHDRAW,
+    },
+    anchor_lang::prelude::Pubkey,
+};
+
+fn pool() -> LiquidityPool {
+    LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    )
+}
+
+fn global_state(paused: u8) -> GlobalState {
+    GlobalState {
+        admin: Pubkey::new_unique(),
+        paused,
+        bump: 255,
+    }
+}
+
+#[test]
+fn test_pool_pause_flags() {
+    let mut pool = pool();
+    let global_state = global_state(0);
+    for flag in [PAUSE_SWAP, PAUSE_DEPOSIT, PAUSE_WITHDRAW, PAUSE_SKIM] {
+        pool.check_not_paused(&global_state, flag).unwrap();
+    }
+
+    // Each flag halts only its own operation
+    pool.set_paused(PAUSE_SWAP | PAUSE_SKIM).unwrap();
+    for flag in [PAUSE_SWAP, PAUSE_SKIM] {
+        assert_eq!(
+            pool.check_not_paused(&global_state, flag).unwrap_err(),
+            AmmError::Paused.into()
+        );
+    }
+    for flag in [PAUSE_DEPOSIT, PAUSE_WITHDRAW] {
+        pool.check_not_paused(&global_state, flag).unwrap();
+    }
+
+    pool.set_paused(0).unwrap();
+    pool.check_not_paused(&global_state, PAUSE_SWAP).unwrap();
+}
+
+#[test]
+fn test_global_pause_applies_to_every_pool() {
+    let pool = pool();
+    let global_state = global_state(PAUSE_WITHDRAW);
+    assert_eq!(
+        pool.check_not_paused(&global_state, PAUSE_WITHDRAW)
+            .unwrap_err(),
+        AmmError::Paused.into()
+    );
+    pool.check_not_paused(&global_state, PAUSE_SWAP).unwrap();
+}
+
+#[test]
+fn test_reject_invalid_pool_pause_flags() {
+    let mut pool = pool();
+    assert_eq!(
+        pool.set_paused(PAUSE_WITHDRAW).unwrap_err(),
+        AmmError::PoolWithdrawPause.into()
+    );
+    assert_eq!(
+        pool.set_paused(!PAUSE_ALL).unwrap_err(),
+        AmmError::InvalidPauseFlags.into()
+    );
+    assert_eq!(pool.paused, 0);
+}
@@ -0,0 +1,43 @@
+use {
+    anchor_amm::{LiquidityPool, SwapQuote, SWAP_FEE_BPS},
+    anchor_lang::prelude::Pubkey,
+};
+
+fn pool() -> LiquidityPool {
+    let mut pool = LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    );
+    pool.token_a_reserves = 1_000_000_000;
+    pool.token_b_reserves = 1_000_000_000;
+    pool
+}
+
+#[test]
+fn test_referral_fee_comes_off_the_input() {
+    let pool = pool();
+    let quote = pool.quote_swap(1_000_000, true, 100, 0).unwrap();
+    assert_eq!(quote.referral_fee, 10_000);
+    // The swap fee applies to what reaches the pool
+    assert_eq!(quote.fee, 990_000 * SWAP_FEE_BPS / 10_000);
+    assert_eq!(
+        quote.amount_out,
+        pool.quote_swap(990_000, true, 0, 0).unwrap().amount_out
+    );
+}
+
+#[test]
+fn test_no_referral_fee_without_a_share() {
+    let pool = pool();
+    let SwapQuote {
+        amount_in,
+        referral_fee,
+        fee,
+        ..
+    } = pool.quote_swap(1_000_000, false, 0, 0).unwrap();
+    assert_eq!(amount_in, 1_000_000);
+    assert_eq!(referral_fee, 0);
+    assert_eq!(fee, 1_000_000 * SWAP_FEE_BPS / 10_000);
+}
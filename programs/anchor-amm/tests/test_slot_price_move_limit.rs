@@ -0,0 +1,42 @@
+use {
+    anchor_amm::{error::AmmError, CurveType, LiquidityPool},
+    anchor_lang::prelude::Pubkey,
+};
+
+fn pool() -> LiquidityPool {
+    LiquidityPool::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        255,
+        Pubkey::new_unique(),
+    )
+}
+
+#[test]
+fn test_set_slot_price_move_limit() {
+    let mut pool = pool();
+    pool.slot_price_move_bps = 700;
+    pool.set_slot_price_move_limit(500).unwrap();
+    assert_eq!(pool.max_slot_price_move_bps, 500);
+    assert_eq!(pool.slot_price_move_bps, 0);
+
+    // 3% then 3% in the same slot breaks a 5% limit, in either direction
+    pool.record_price_move(10_000, 10_300, 0, 1).unwrap();
+    assert_eq!(
+        pool.record_price_move(10_300, 9_991, 0, 1).unwrap_err(),
+        AmmError::SlotPriceMoveExceeded.into()
+    );
+    // A new slot starts from zero
+    pool.record_price_move(10_300, 9_991, 0, 2).unwrap();
+}
+
+#[test]
+fn test_reject_slot_price_move_limit_on_pmm() {
+    let mut pool = pool();
+    pool.curve_type = CurveType::OraclePmm as u8;
+    assert_eq!(
+        pool.set_slot_price_move_limit(500).unwrap_err(),
+        AmmError::UnsupportedCurveOperation.into()
+    );
+    assert_eq!(pool.max_slot_price_move_bps, 0);
+}
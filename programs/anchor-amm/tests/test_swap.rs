@@ -0,0 +1,131 @@
+#![cfg(feature = "test-sbf")]
+
+//! `swap` run end to end; see `common` for what the tests need.
+//! Run with `cargo test-sbf --features test-sbf --test test_swap`.
+
+mod common;
+
+use {
+    anchor_amm::{error::AmmError, LiquidityPool, MAX_REFERRAL_FEE_BPS, PAUSE_SWAP},
+    anchor_lang::{prelude::Pubkey, solana_program::program_error::ProgramError},
+    common::{token_amount, PoolKeys},
+    mollusk_svm::result::Check,
+};
+
+const RESERVES: u64 = 1_000_000_000;
+const AMOUNT_IN: u64 = 1_000_000;
+
+fn pool(keys: &PoolKeys, signer: Pubkey) -> LiquidityPool {
+    let mut pool = keys.pool(signer);
+    pool.token_a_reserves = RESERVES;
+    pool.token_b_reserves = RESERVES;
+    pool
+}
+
+fn args(referral_fee_bps: u16) -> anchor_amm::instruction::Swap {
+    anchor_amm::instruction::Swap {
+        token_0_amount: AMOUNT_IN,
+        token_1_min_amount: 0,
+        expiration: i64::MAX,
+        referral_fee_bps,
+    }
+}
+
+#[test]
+fn test_swap_pays_the_referrer() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let pool = pool(&keys, signer);
+    let referrer_token_account = Pubkey::new_unique();
+    let (instruction, accounts) = keys.swap(
+        &mollusk,
+        &pool,
+        signer,
+        args(MAX_REFERRAL_FEE_BPS),
+        Some(referrer_token_account),
+    );
+
+    let result =
+        mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+    let referral_fee = AMOUNT_IN * MAX_REFERRAL_FEE_BPS as u64 / 10_000;
+    assert_eq!(
+        token_amount(result.get_account(&referrer_token_account).unwrap()),
+        referral_fee
+    );
+    assert_eq!(
+        token_amount(result.get_account(&keys.token_a_vault).unwrap()),
+        RESERVES + AMOUNT_IN - referral_fee
+    );
+    let pool = common::read_pool(result.get_account(&keys.liquidity_pool).unwrap());
+    assert_eq!(pool.token_a_reserves, RESERVES + AMOUNT_IN - referral_fee);
+    assert!(!pool.is_locked());
+}
+
+#[test]
+fn test_reject_invalid_referral_fee() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let pool = pool(&keys, signer);
+    // Over the cap
+    let (instruction, accounts) = keys.swap(
+        &mollusk,
+        &pool,
+        signer,
+        args(MAX_REFERRAL_FEE_BPS + 1),
+        Some(Pubkey::new_unique()),
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::InvalidReferralFee.into(),
+        ))],
+    );
+
+    // Nobody to pay it to
+    let (instruction, accounts) =
+        keys.swap(&mollusk, &pool, signer, args(MAX_REFERRAL_FEE_BPS), None);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::InvalidReferralFee.into(),
+        ))],
+    );
+}
+
+#[test]
+fn test_reject_swap_on_paused_pool() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let mut pool = pool(&keys, signer);
+    pool.set_paused(PAUSE_SWAP).unwrap();
+    let (instruction, accounts) = keys.swap(&mollusk, &pool, signer, args(0), None);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(AmmError::Paused.into()))],
+    );
+}
+
+#[test]
+fn test_reject_swap_on_locked_pool() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let mut pool = pool(&keys, signer);
+    pool.locked = 1;
+    let (instruction, accounts) = keys.swap(&mollusk, &pool, signer, args(0), None);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::PoolLocked.into(),
+        ))],
+    );
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "test-sbf")]
+
+//! Compute units used by `swap` on a constant-product pool; see `common` for what
+//! the test needs. Run with
+//! `cargo test-sbf --features test-sbf --test test_swap_compute_units -- --nocapture`.
+//!
+//! To compare against the Borsh pool account, run this swap on commit 6a2c6c3, the
+//! last one before the zero-copy `LiquidityPool`, writing the pool with
+//! `AccountSerialize` instead of `pool_account`.
+
+mod common;
+
+use {anchor_lang::prelude::Pubkey, common::PoolKeys, mollusk_svm::result::Check};
+
+const RESERVES: u64 = 1_000_000_000;
+
+/// Budget for a plain constant-product swap, so a regression fails the test rather
+/// than only showing up in the printed number. A loose upper bound until the swap is
+/// measured; tighten it to just above the printed number.
+const MAX_SWAP_COMPUTE_UNITS: u64 = 60_000;
+
+#[test]
+fn test_swap_compute_units() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let mut pool = keys.pool(signer);
+    pool.token_a_reserves = RESERVES;
+    pool.token_b_reserves = RESERVES;
+    let (instruction, accounts) = keys.swap(
+        &mollusk,
+        &pool,
+        signer,
+        anchor_amm::instruction::Swap {
+            token_0_amount: 1_000_000,
+            token_1_min_amount: 0,
+            expiration: i64::MAX,
+            referral_fee_bps: 0,
+        },
+        None,
+    );
+
+    let result =
+        mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+    println!("swap: {} compute units", result.compute_units_consumed);
+    assert!(
+        result.compute_units_consumed <= MAX_SWAP_COMPUTE_UNITS,
+        "swap used {} compute units, over the {MAX_SWAP_COMPUTE_UNITS} budget",
+        result.compute_units_consumed
+    );
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "test-sbf")]
+
+//! `swap_v2` run end to end; see `common` for what the tests need.
+//! Run with `cargo test-sbf --features test-sbf --test test_swap_v2`.
+
+mod common;
+
+use {
+    anchor_amm::{error::AmmError, LiquidityPool},
+    anchor_lang::{error::ErrorCode, prelude::Pubkey, solana_program::program_error::ProgramError},
+    anchor_spl::associated_token::get_associated_token_address,
+    common::{token_account, token_amount, PoolKeys, SIGNER_TOKENS},
+    mollusk_svm::result::Check,
+};
+
+const RESERVES: u64 = 1_000_000_000;
+const AMOUNT_IN: u64 = 1_000_000;
+
+fn pool(keys: &PoolKeys, signer: Pubkey) -> LiquidityPool {
+    let mut pool = keys.pool(signer);
+    pool.token_a_reserves = RESERVES;
+    pool.token_b_reserves = RESERVES;
+    pool
+}
+
+fn args(is_token_a_in: bool, min_amount_out: u64) -> anchor_amm::instruction::SwapV2 {
+    anchor_amm::instruction::SwapV2 {
+        amount_in: AMOUNT_IN,
+        min_amount_out,
+        is_token_a_in,
+        expiration: i64::MAX,
+        referral_fee_bps: 0,
+    }
+}
+
+#[test]
+fn test_swap_v2_token_b_for_token_a() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let pool = pool(&keys, signer);
+    let amount_out = pool.quote_swap(AMOUNT_IN, false, 0, 0).unwrap().amount_out;
+    let (instruction, accounts) = keys.swap_v2(&mollusk, &pool, signer, args(false, amount_out));
+
+    let result =
+        mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+    let token_a_signer_token_account = get_associated_token_address(&signer, &keys.token_a_mint);
+    assert_eq!(
+        token_amount(result.get_account(&token_a_signer_token_account).unwrap()),
+        SIGNER_TOKENS + amount_out
+    );
+    let pool = common::read_pool(result.get_account(&keys.liquidity_pool).unwrap());
+    assert_eq!(pool.token_a_reserves, RESERVES - amount_out);
+    assert_eq!(pool.token_b_reserves, RESERVES + AMOUNT_IN);
+    assert!(!pool.is_locked());
+}
+
+#[test]
+fn test_reject_swap_v2_below_min_amount_out() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let pool = pool(&keys, signer);
+    let amount_out = pool.quote_swap(AMOUNT_IN, true, 0, 0).unwrap().amount_out;
+    let (instruction, accounts) = keys.swap_v2(&mollusk, &pool, signer, args(true, amount_out + 1));
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::SlippageExceeded.into(),
+        ))],
+    );
+}
+
+#[test]
+fn test_reject_swap_v2_with_foreign_vault() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let pool = pool(&keys, signer);
+    let (mut instruction, mut accounts) = keys.swap_v2(&mollusk, &pool, signer, args(true, 0));
+
+    // A token B account the pool doesn't own in place of its vault
+    let foreign_vault = Pubkey::new_unique();
+    instruction.accounts[4].pubkey = foreign_vault;
+    accounts.push((
+        foreign_vault,
+        token_account(&mollusk, keys.token_b_mint, signer, RESERVES),
+    ));
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            ErrorCode::ConstraintHasOne.into(),
+        ))],
+    );
+}
+
+#[test]
+fn test_reject_swap_v2_on_locked_pool() {
+    let mollusk = common::mollusk();
+    let keys = PoolKeys::new();
+    let signer = Pubkey::new_unique();
+    let mut pool = pool(&keys, signer);
+    pool.locked = 1;
+    let (instruction, accounts) = keys.swap_v2(&mollusk, &pool, signer, args(true, 0));
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            AmmError::PoolLocked.into(),
+        ))],
+    );
+}